@@ -3,8 +3,10 @@ fn main() -> anyhow::Result<()> {
         /** 包定义 */
         package com.test;
 
+        /** 导入 */
         import java.lang.System;
 
+        /** 示例程序 */
         public class HelloWorld {
             /**
              * 主函数。
@@ -0,0 +1,15 @@
+#![no_main]
+
+use java_lang::{parse, parse_str_recovering, ParseOptions, TokenStream};
+use libfuzzer_sys::fuzz_target;
+
+// 任意输入都只能得到语法树或错误，不能panic
+fuzz_target!(|source: &str| {
+    if let Ok((_, tokens)) = TokenStream::from_str(source) {
+        if let Ok((_, unit)) = parse(tokens) {
+            let _ = unit.to_string();
+        }
+    }
+    let _ = parse_str_recovering(source, ParseOptions::default());
+    let _ = parse_str_recovering(source, ParseOptions::preview());
+});
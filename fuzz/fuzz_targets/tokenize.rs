@@ -0,0 +1,9 @@
+#![no_main]
+
+use java_lang::TokenStream;
+use libfuzzer_sys::fuzz_target;
+
+// 任意输入都只能得到标记流或错误，不能panic
+fuzz_target!(|source: &str| {
+    let _ = TokenStream::from_str(source);
+});
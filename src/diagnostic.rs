@@ -0,0 +1,445 @@
+use crate::{ParseError, ParseErrorKind};
+use std::{fmt::Write, ops::Range};
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const BLUE: &str = "\x1b[1;34m";
+
+/// Severity表示诊断信息的严重程度。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// 错误。
+    Error,
+    /// 警告。
+    Warning,
+    /// 提示。
+    Note,
+}
+
+impl Severity {
+    /// 获取严重程度的名称，和rustc一样使用`error`、`warning`和`note`，便于其他工具识别。
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::Note => "note",
+        }
+    }
+
+    fn color(self) -> &'static str {
+        match self {
+            Self::Error => "\x1b[1;31m",
+            Self::Warning => "\x1b[1;33m",
+            Self::Note => "\x1b[1;32m",
+        }
+    }
+}
+
+/// Label表示诊断信息中标注的一段源代码。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Label {
+    /// 标注的源代码的字节范围。
+    pub span: Range<usize>,
+    /// 标注的说明，可以为空。
+    pub message: String,
+    /// 是否为主要标注，主要标注用`^`标出，次要标注用`-`标出。
+    pub primary: bool,
+}
+
+impl Label {
+    /// 创建一个主要标注。
+    pub fn primary(span: Range<usize>, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            primary: true,
+        }
+    }
+
+    /// 创建一个次要标注。
+    pub fn secondary(span: Range<usize>, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+            primary: false,
+        }
+    }
+}
+
+/// Diagnostic表示一条可以输出给用户的诊断信息，包括标题、源代码上的标注和附加的说明。
+///
+/// 词法错误和语法错误（[`ParseError`]）都可以转换成诊断信息，再由[`Renderer`]输出。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    /// 严重程度。
+    pub severity: Severity,
+    /// 标题。
+    pub message: String,
+    /// 源代码上的标注。
+    pub labels: Vec<Label>,
+    /// 附加的说明，输出在标注之后。
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    /// 创建一条严重程度为`severity`的诊断信息。
+    pub fn new(severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            severity,
+            message: message.into(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    /// 创建一条错误。
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, message)
+    }
+
+    /// 创建一条警告。
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, message)
+    }
+
+    /// 添加一个标注。
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    /// 添加一条说明。
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// 获取主要标注，没有主要标注时返回第一个标注。
+    fn primary(&self) -> Option<&Label> {
+        self.labels
+            .iter()
+            .find(|l| l.primary)
+            .or(self.labels.first())
+    }
+}
+
+impl From<&ParseError> for Diagnostic {
+    fn from(error: &ParseError) -> Self {
+        let label = match (error.kind(), error.expected()) {
+            (ParseErrorKind::Lexical, _) => "无法识别的字符".to_string(),
+            (ParseErrorKind::Limit(_), _) => "超过限制的位置".to_string(),
+            (ParseErrorKind::Literal(_), _) => "无效的字面量".to_string(),
+            (_, []) if error.found().is_none() => "输入在这里结束".to_string(),
+            (_, []) => "意外的标记".to_string(),
+            (_, [expected]) => format!("期望{}", expected),
+            (_, [init @ .., last]) => format!("期望{}或{}", init.join("、"), last),
+        };
+        Self::error(error.message()).with_label(Label::primary(error.span(), label))
+    }
+}
+
+impl From<ParseError> for Diagnostic {
+    fn from(error: ParseError) -> Self {
+        Self::from(&error)
+    }
+}
+
+/// Renderer把诊断信息连同源代码片段输出成文本，格式和rustc的错误信息类似：
+///
+/// ```text
+/// error: 期望`,`或`;`，但遇到了`}`
+///  --> A.java:3:1
+///   |
+/// 3 | }
+///   | ^ 期望`,`或`;`
+/// ```
+///
+/// 也可以用[`Renderer::render_plain`]输出每条信息一行的`文件:行:列: error: 标题`格式，便于其他工具处理。
+///
+/// # 示例
+///
+/// ```rust
+/// use java_lang::{parse_str, Renderer};
+/// let source = "class A {\n    int a = 1\n}";
+/// let error = parse_str(source).unwrap_err();
+/// let renderer = Renderer::new("A.java", source);
+/// assert_eq!(
+///     renderer.render(&error.clone().into()),
+///     "error: 期望`,`或`;`，但遇到了`}`\n --> A.java:3:1\n  |\n3 | }\n  | ^ 期望`,`或`;`\n"
+/// );
+/// assert_eq!(
+///     renderer.render_plain(&error.into()),
+///     "A.java:3:1: error: 期望`,`或`;`，但遇到了`}`\n"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Renderer<'s> {
+    name: &'s str,
+    source: &'s str,
+    colored: bool,
+}
+
+impl<'s> Renderer<'s> {
+    /// 创建一个渲染器，`name`是输出的文件名，`source`是诊断信息中的范围所指向的源代码。
+    pub fn new(name: &'s str, source: &'s str) -> Self {
+        Self {
+            name,
+            source,
+            colored: false,
+        }
+    }
+
+    /// 设置是否使用ANSI转义序列输出颜色，默认不使用。
+    pub fn colored(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+
+    /// 输出带有源代码片段的诊断信息。
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        let mut out = String::new();
+        let severity = diagnostic.severity;
+        let _ = writeln!(
+            out,
+            "{}{}: {}{}",
+            self.paint(severity.color(), severity.name()),
+            self.paint(BOLD, ""),
+            diagnostic.message,
+            self.paint(RESET, "")
+        );
+
+        let mut labels: Vec<_> = diagnostic
+            .labels
+            .iter()
+            .map(|l| (self.location(l.span.start), l))
+            .collect();
+        labels.sort_by_key(|((line, column), _)| (*line, *column));
+        let width = labels
+            .iter()
+            .map(|((line, _), _)| line.to_string().len())
+            .max()
+            .unwrap_or(0);
+        let pad = " ".repeat(width);
+        let gutter = format!("{} {}", pad, self.paint(BLUE, "|"));
+
+        if let Some(primary) = diagnostic.primary() {
+            let (line, column) = self.location(primary.span.start);
+            let _ = writeln!(
+                out,
+                "{}{} {}:{}:{}",
+                pad,
+                self.paint(BLUE, "-->"),
+                self.name,
+                line,
+                column
+            );
+            let _ = writeln!(out, "{}", gutter);
+        }
+        let mut previous = None;
+        for (i, ((line, _), label)) in labels.iter().enumerate() {
+            let range = self.line_range(label.span.start);
+            if previous != Some(*line) {
+                if previous.is_some_and(|p| p + 1 < *line) {
+                    let _ = writeln!(out, "{}", self.paint(BLUE, "..."));
+                }
+                let number = format!("{:>width$} |", line, width = width);
+                let text = expand_tabs(&self.source[range.clone()]);
+                let _ = writeln!(out, "{} {}", self.paint(BLUE, &number), text);
+                previous = Some(*line);
+            }
+            let start = self.clamp(label.span.start);
+            let end = self.clamp(label.span.end).clamp(start, range.end);
+            let indent = display_width(&self.source[range.start..start]);
+            let length = display_width(&self.source[start..end]).max(1);
+            let (mark, color) = match label.primary {
+                true => ("^", severity.color()),
+                false => ("-", BLUE),
+            };
+            let marks = mark.repeat(length);
+            let text = match label.message.is_empty() {
+                true => marks,
+                false => format!("{} {}", marks, label.message),
+            };
+            let _ = writeln!(
+                out,
+                "{} {}{}",
+                gutter,
+                " ".repeat(indent),
+                self.paint(color, &text)
+            );
+            let last = i + 1 == labels.len();
+            if last && !diagnostic.notes.is_empty() {
+                let _ = writeln!(out, "{}", gutter);
+            }
+        }
+        for note in &diagnostic.notes {
+            let _ = writeln!(
+                out,
+                "{} {} {}: {}",
+                pad,
+                self.paint(BLUE, "="),
+                self.paint(BOLD, "note"),
+                note
+            );
+        }
+
+        out
+    }
+
+    /// 输出每条信息一行的纯文本格式：`文件:行:列: 严重程度: 内容`，次要标注和说明输出为`note`。
+    pub fn render_plain(&self, diagnostic: &Diagnostic) -> String {
+        let mut out = String::new();
+        let location = |span: &Range<usize>| {
+            let (line, column) = self.location(span.start);
+            format!("{}:{}:{}", self.name, line, column)
+        };
+        let primary = diagnostic.primary();
+        let head = primary.map_or_else(|| self.name.to_string(), |l| location(&l.span));
+        let _ = writeln!(
+            out,
+            "{}: {}: {}",
+            head,
+            diagnostic.severity.name(),
+            diagnostic.message
+        );
+        for label in diagnostic.labels.iter().filter(|l| !l.primary) {
+            if !label.message.is_empty() {
+                let _ = writeln!(out, "{}: note: {}", location(&label.span), label.message);
+            }
+        }
+        for note in &diagnostic.notes {
+            let _ = writeln!(out, "{}: note: {}", head, note);
+        }
+
+        out
+    }
+
+    /// 输出多条诊断信息，每条之间空一行。
+    pub fn render_all<'d>(&self, diagnostics: impl IntoIterator<Item = &'d Diagnostic>) -> String {
+        diagnostics
+            .into_iter()
+            .map(|d| self.render(d))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn paint(&self, color: &str, text: &str) -> String {
+        match self.colored {
+            true if text.is_empty() => color.to_string(),
+            true => format!("{}{}{}", color, text, RESET),
+            false => text.to_string(),
+        }
+    }
+
+    /// 把字节偏移限制在源代码范围内并对齐到字符边界。
+    fn clamp(&self, offset: usize) -> usize {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+
+    /// 获取`offset`所在行（不包括换行符）的字节范围。
+    fn line_range(&self, offset: usize) -> Range<usize> {
+        let offset = self.clamp(offset);
+        let start = self.source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let end = self.source[offset..]
+            .find('\n')
+            .map_or(self.source.len(), |i| offset + i);
+        let end = match self.source[start..end].ends_with('\r') {
+            true => end - 1,
+            false => end,
+        };
+        start..end.max(start)
+    }
+
+    /// 获取`offset`所在的行号和列号（都从1开始，列号按字符计数）。
+    fn location(&self, offset: usize) -> (usize, usize) {
+        let before = &self.source[..self.clamp(offset)];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+        (line, column)
+    }
+}
+
+/// 把制表符展开成4个空格。
+fn expand_tabs(text: &str) -> String {
+    text.replace('\t', "    ")
+}
+
+/// 计算文本在终端中的显示宽度，制表符按4列、全角字符按2列计算。
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c {
+            '\t' => 4,
+            '\u{1100}'..='\u{115F}'
+            | '\u{2E80}'..='\u{A4CF}'
+            | '\u{AC00}'..='\u{D7A3}'
+            | '\u{F900}'..='\u{FAFF}'
+            | '\u{FE30}'..='\u{FE4F}'
+            | '\u{FF00}'..='\u{FF60}'
+            | '\u{FFE0}'..='\u{FFE6}'
+            | '\u{1F300}'..='\u{1F64F}'
+            | '\u{20000}'..='\u{3FFFD}' => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_str, parse_str_recovering, TokenStream};
+
+    #[test]
+    fn test_renderer() {
+        let source = "class A {\n\tString s = \"类\"; # }";
+        let error = TokenStream::from_source(source).unwrap_err();
+        let renderer = Renderer::new("A.java", source);
+        assert_eq!(
+            renderer.render(&error.into()),
+            "error: 无法识别的字符`#`\n --> A.java:2:18\n  |\n2 |     String s = \"类\"; # }\n  |                      ^ 无法识别的字符\n"
+        );
+
+        let source = "class A {\n    void f() {\n        int a = 1\n    }\n}\n";
+        let diagnostic = Diagnostic::from(parse_str(source).unwrap_err())
+            .with_label(Label::secondary(19..20, "方法从这里开始"))
+            .with_note("每条语句都以`;`结尾");
+        let renderer = Renderer::new("A.java", source);
+        assert_eq!(
+            renderer.render(&diagnostic),
+            "\
+error: 期望`,`或`;`，但遇到了`}`
+ --> A.java:4:5
+  |
+2 |     void f() {
+  |          - 方法从这里开始
+...
+4 |     }
+  |     ^ 期望`,`或`;`
+  |
+  = note: 每条语句都以`;`结尾
+"
+        );
+        assert_eq!(
+            renderer.render_plain(&diagnostic),
+            "\
+A.java:4:5: error: 期望`,`或`;`，但遇到了`}`
+A.java:2:10: note: 方法从这里开始
+A.java:4:5: note: 每条语句都以`;`结尾
+"
+        );
+        let colored = renderer.colored(true).render(&diagnostic);
+        assert!(colored.starts_with("\x1b[1;31merror\x1b[0m\x1b[1m: 期望"));
+
+        // 输入结束时标注在最后一个字符之后
+        let source = "class A {";
+        let result = parse_str_recovering(source, Default::default());
+        let diagnostics: Vec<Diagnostic> = result.diagnostics.iter().map(Into::into).collect();
+        assert_eq!(
+            Renderer::new("A.java", source).render_all(&diagnostics),
+            "error: 期望`}`，但遇到了输入结束\n --> A.java:1:10\n  |\n1 | class A {\n  |          ^ 期望`}`\n"
+        );
+    }
+}
@@ -0,0 +1,430 @@
+use crate::{one_token, CompilationUnitDeclaration, MissingToken, Token, TokenInfo};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Range,
+};
+
+/// ParseErrorKind表示解析错误的种类。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// 词法错误，源代码中有无法识别的字符。
+    Lexical,
+    /// 语法错误，标记序列不符合Java语法。
+    Syntax,
+    /// 超过了解析选项（[`crate::ParseOptions`]）中的限制。
+    Limit(ParseLimit),
+    /// 字面量的值超出了范围或格式不正确。
+    Literal(LiteralError),
+}
+
+/// LiteralError表示字面量错误的原因。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LiteralError {
+    /// 数值超出了字面量类型的范围，例如`2147483648`不是一元`-`的操作数。
+    OutOfRange,
+    /// 字符字面量中不是恰好一个字符或转义序列，例如`'ab'`。
+    InvalidChar,
+}
+
+/// ParseLimit表示被超过的解析限制和它的值。
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseLimit {
+    /// 嵌套层数超过了[`crate::ParseOptions::max_depth`]。
+    Depth(usize),
+    /// 标记数量超过了[`crate::ParseOptions::max_tokens`]。
+    Tokens(usize),
+    /// 源代码大小超过了[`crate::ParseOptions::max_source_len`]。
+    SourceLen(usize),
+}
+
+/// ParseError表示解析源代码失败的原因和位置。
+///
+/// 错误信息包括期望的内容、实际遇到的内容以及出错的位置，例如：
+/// ``第3行第22列：期望`.`或`;`，但遇到了`class` ``。
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    kind: ParseErrorKind,
+    expected: Vec<String>,
+    found: Option<String>,
+    span: Range<usize>,
+    line: usize,
+    column: usize,
+}
+
+impl ParseError {
+    /// 创建一个词法错误，`offset`是无法识别的字符在源代码中的字节偏移。
+    pub(crate) fn lexical(source: &str, offset: usize) -> Self {
+        let found = source[offset..].chars().next();
+        let end = offset + found.map_or(0, char::len_utf8);
+        Self::new(
+            source,
+            ParseErrorKind::Lexical,
+            Vec::new(),
+            found.map(|c| format!("`{}`", c)),
+            offset..end,
+        )
+    }
+
+    /// 创建一个语法错误，`found`是出错位置上的标记和它的位置信息，`None`表示输入已经结束。
+    pub(crate) fn syntax(
+        source: &str,
+        expected: Vec<String>,
+        found: Option<(Token, Option<TokenInfo>)>,
+    ) -> Self {
+        let end = source.trim_end().len();
+        let (found, span) = match found {
+            // 恢复模式补上的标记在源代码中不存在（范围为空），改为报告它后面实际遇到的标记
+            Some((_, Some(info))) if info.span.is_empty() => {
+                let rest = source.get(info.span.start..).unwrap_or_default();
+                let start = source.len() - rest.trim_start().len();
+                match one_token(rest) {
+                    Ok((remaining, token)) => (
+                        Some(format!("`{}`", token)),
+                        start..source.len() - remaining.len(),
+                    ),
+                    Err(_) => (None, end..end),
+                }
+            }
+            Some((token, info)) => (
+                Some(format!("`{}`", token)),
+                info.map_or(end..end, |i| i.span),
+            ),
+            None => (None, end..end),
+        };
+        Self::new(source, ParseErrorKind::Syntax, expected, found, span)
+    }
+
+    /// 创建一个超过限制的错误，`info`是超过限制的位置上的标记的位置信息，`None`表示输入的末尾。
+    pub(crate) fn limit(source: &str, limit: ParseLimit, info: Option<TokenInfo>) -> Self {
+        Self::fatal(source, ParseErrorKind::Limit(limit), info)
+    }
+
+    /// 创建一个字面量错误，`span`是字面量在源代码中的字节范围。
+    pub(crate) fn literal(source: &str, error: LiteralError, span: Range<usize>) -> Self {
+        let found = source.get(span.clone()).map(|s| format!("`{}`", s));
+        Self::new(
+            source,
+            ParseErrorKind::Literal(error),
+            Vec::new(),
+            found,
+            span,
+        )
+    }
+
+    /// 创建一个终止解析的错误（超过限制或字面量错误），`info`是出错的标记的位置信息，`None`表示输入的末尾。
+    pub(crate) fn fatal(source: &str, kind: ParseErrorKind, info: Option<TokenInfo>) -> Self {
+        let end = source.trim_end().len();
+        let span = info.map_or(end..end, |i| i.span);
+        let found = match kind {
+            ParseErrorKind::Literal(_) => source.get(span.clone()).map(|s| format!("`{}`", s)),
+            _ => None,
+        };
+        Self::new(source, kind, Vec::new(), found, span)
+    }
+
+    /// 把在`source[offset..]`中得到的错误换算到`source`中的位置。
+    pub(crate) fn relocate(self, source: &str, offset: usize) -> Self {
+        let span = self.span.start + offset..self.span.end + offset;
+        Self::new(source, self.kind, self.expected, self.found, span)
+    }
+
+    fn new(
+        source: &str,
+        kind: ParseErrorKind,
+        expected: Vec<String>,
+        found: Option<String>,
+        span: Range<usize>,
+    ) -> Self {
+        let before = &source[..span.start.min(source.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+        Self {
+            kind,
+            expected,
+            found,
+            span,
+            line,
+            column,
+        }
+    }
+
+    /// 获取错误的种类。
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// 获取出错位置上期望的内容，例如`` `;` ``或`标识符`，可能为空。
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+
+    /// 获取出错位置上实际遇到的内容，`None`表示输入已经结束。
+    pub fn found(&self) -> Option<&str> {
+        self.found.as_deref()
+    }
+
+    /// 获取出错位置在源代码中的字节范围。
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// 获取出错位置的行号（从1开始）。
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// 获取出错位置的列号（从1开始，按字符计数）。
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// 获取不带位置的错误信息。
+    pub fn message(&self) -> String {
+        let found = self.found.as_deref().unwrap_or("输入结束");
+        match self.kind {
+            ParseErrorKind::Lexical => return format!("无法识别的字符{}", found),
+            ParseErrorKind::Limit(ParseLimit::Depth(n)) => {
+                return format!("嵌套层数超过了限制（{}）", n)
+            }
+            ParseErrorKind::Limit(ParseLimit::Tokens(n)) => {
+                return format!("标记数量超过了限制（{}）", n)
+            }
+            ParseErrorKind::Limit(ParseLimit::SourceLen(n)) => {
+                return format!("源代码大小超过了限制（{}字节）", n)
+            }
+            ParseErrorKind::Literal(LiteralError::OutOfRange) => {
+                return format!("字面量{}超出了范围", found)
+            }
+            ParseErrorKind::Literal(LiteralError::InvalidChar) => {
+                return format!("字符字面量{}中必须恰好有一个字符或转义序列", found)
+            }
+            ParseErrorKind::Syntax => {}
+        }
+        match self.expected.as_slice() {
+            [] => format!("意外的{}", found),
+            [expected] => format!("期望{}，但遇到了{}", expected, found),
+            [init @ .., last] => format!("期望{}或{}，但遇到了{}", init.join("、"), last, found),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "第{}行第{}列：{}",
+            self.line,
+            self.column,
+            self.message()
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// RecoveredParse表示恢复模式下的解析结果，见[`crate::parse_str_recovering`]。
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecoveredParse<'a> {
+    /// 尽可能解析出的编译单元，无法解析的部分用错误节点（[`crate::ErrorNode`]）代替。
+    pub unit: CompilationUnitDeclaration<'a>,
+    /// 按出现位置排序的所有错误，没有错误时为空。
+    pub diagnostics: Vec<ParseError>,
+    /// 按出现位置排序的补上的缺失标记，每个都对应[`RecoveredParse::diagnostics`]中的一个语法错误，
+    /// 输入末尾同时缺少的多个结尾标记对应同一个语法错误。
+    pub missing: Vec<MissingToken>,
+}
+
+impl<'a> RecoveredParse<'a> {
+    /// 是否没有任何错误。
+    pub fn is_ok(&self) -> bool {
+        self.diagnostics.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_str, parse_str_recovering, parse_str_with_options, ParseOptions};
+
+    #[test]
+    fn test_parse_error() {
+        let error = parse_str("class A {\n    int a = 1\n}").unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::Syntax);
+        assert_eq!(error.expected(), ["`,`", "`;`"]);
+        assert_eq!(error.found(), Some("`}`"));
+        assert_eq!((error.line(), error.column()), (3, 1));
+        assert_eq!(error.span(), 24..25);
+
+        let error = parse_str("class A {").unwrap_err();
+        assert_eq!(error.found(), None);
+        assert_eq!(error.to_string(), "第1行第10列：期望`}`，但遇到了输入结束");
+
+        let error = parse_str("// 类\nclass A { String s = \"类\"; # }").unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::Lexical);
+        assert_eq!((error.line(), error.column()), (2, 27));
+        let error: Box<dyn Error> = Box::new(error);
+        assert_eq!(error.to_string(), "第2行第27列：无法识别的字符`#`");
+    }
+
+    #[test]
+    fn test_limits() {
+        // 默认的限制保证深度嵌套的输入不会栈溢出
+        let source = format!(
+            "class A {{ int a = {}1{}; }}",
+            "(".repeat(10000),
+            ")".repeat(10000)
+        );
+        let error = parse_str(&source).unwrap_err();
+        assert!(
+            matches!(error.kind(), ParseErrorKind::Limit(_)),
+            "{}",
+            error
+        );
+        assert_eq!(error.line(), 1);
+        let result = parse_str_recovering(&source, Default::default());
+        assert_eq!(result.diagnostics, [error]);
+
+        let source = "class A {".repeat(10000);
+        let error = parse_str(&source).unwrap_err();
+        assert!(
+            matches!(error.kind(), ParseErrorKind::Limit(_)),
+            "{}",
+            error
+        );
+
+        let options = ParseOptions {
+            max_tokens: 4,
+            ..Default::default()
+        };
+        assert!(parse_str_with_options("class A {}", options).is_ok());
+        let error =
+            parse_str_with_options("// 注释不计入\nclass A { }\nclass B {}", options).unwrap_err();
+        assert_eq!(error.kind(), ParseErrorKind::Limit(ParseLimit::Tokens(4)));
+        assert_eq!((error.line(), error.column()), (3, 1));
+        assert_eq!(error.to_string(), "第3行第1列：标记数量超过了限制（4）");
+    }
+
+    #[test]
+    fn test_default_limits() {
+        // 默认选项下正常的代码不会因为嵌套而被拒绝，未优化的构建中也不会栈溢出
+        let source = format!(
+            "class A {{ void f(int a) {{ if (a == 0) {{}} {}else {{}} }} }}",
+            (1..500)
+                .map(|i| format!("else if (a == {}) {{}} ", i))
+                .collect::<String>()
+        );
+        assert!(parse_str(&source).is_ok());
+        for open in ["(", "g("] {
+            let source = format!(
+                "class A {{ void f() {{ int a = {}1{}; }} }}",
+                open.repeat(200),
+                ")".repeat(200)
+            );
+            assert!(parse_str(&source).is_ok(), "{}", source);
+        }
+        let source = format!(
+            "class A {{ void f() {{ {}{} }} }}",
+            "{".repeat(200),
+            "}".repeat(200)
+        );
+        assert!(parse_str(&source).is_ok());
+    }
+
+    #[test]
+    fn test_no_panic() {
+        // 这些输入曾经导致词法分析器panic，现在应当返回错误
+        for source in [
+            "class A { char c = ''; }",
+            "class A { int a = 99999999999999999999; }",
+            "class A { String s = \"",
+            "class A { /** 未结束",
+            "'",
+            "",
+        ] {
+            let _ = parse_str(source);
+            let _ = parse_str_recovering(source, Default::default());
+        }
+        let error = parse_str("class A { char c = ''; }").unwrap_err();
+        assert_eq!(
+            error.kind(),
+            ParseErrorKind::Literal(LiteralError::InvalidChar)
+        );
+        assert_eq!((error.line(), error.column()), (1, 20));
+        let error = parse_str("class A { int a = 99999999999999999999; }").unwrap_err();
+        assert_eq!(
+            error.kind(),
+            ParseErrorKind::Literal(LiteralError::OutOfRange)
+        );
+        assert_eq!((error.line(), error.column()), (1, 19));
+        assert_eq!(parse_str("'").unwrap_err().kind(), ParseErrorKind::Lexical);
+    }
+
+    #[test]
+    fn test_literal_errors() {
+        assert!(
+            parse_str("class A { int a = -2147483648; long b = -9223372036854775808L; }").is_ok()
+        );
+        assert!(parse_str("class A { int a = 2147483647 + 0x7fffffff + 0b1 + 0777; }").is_ok());
+
+        // 只有一元`-`的操作数可以是`2147483648`
+        for (source, column) in [
+            ("class A { int a = 2147483648; }", 19),
+            ("class A { int a = 1 - 2147483648; }", 23),
+            ("class A { long a = 9223372036854775808L; }", 20),
+        ] {
+            let error = parse_str(source).unwrap_err();
+            assert_eq!(
+                error.kind(),
+                ParseErrorKind::Literal(LiteralError::OutOfRange),
+                "{}",
+                source
+            );
+            assert_eq!(error.column(), column, "{}", source);
+        }
+        let error = parse_str("class A { int a = 2147483648; }").unwrap_err();
+        assert_eq!(error.span(), 18..28);
+        assert_eq!(
+            error.to_string(),
+            "第1行第19列：字面量`2147483648`超出了范围"
+        );
+
+        // 词法分析时就能确定超出范围的字面量
+        for (source, span) in [
+            ("class A { int a = -2147483649; }", 19..29),
+            ("class A { int a = 0x1_0000_0000; }", 18..31),
+            ("class A { int a = 040000000000; }", 18..30),
+            (
+                "class A { int a = 0b1_0000_0000_0000_0000_0000_0000_0000_0000; }",
+                18..61,
+            ),
+        ] {
+            let error = parse_str(source).unwrap_err();
+            assert_eq!(
+                error.kind(),
+                ParseErrorKind::Literal(LiteralError::OutOfRange),
+                "{}",
+                source
+            );
+            assert_eq!(error.span(), span, "{}", source);
+        }
+
+        // 恢复模式下跳过有错误的字面量并继续解析
+        let result = parse_str_recovering(
+            "class A { int a = 4294967296; char c = 'ab'; }",
+            Default::default(),
+        );
+        assert_eq!(
+            result.diagnostics[0].to_string(),
+            "第1行第19列：字面量`4294967296`超出了范围"
+        );
+        assert_eq!(
+            result.diagnostics[1].to_string(),
+            "第1行第29列：期望标识符、`(`或表达式，但遇到了`;`"
+        );
+        assert_eq!(
+            result.diagnostics[2].to_string(),
+            "第1行第40列：字符字面量`'ab'`中必须恰好有一个字符或转义序列"
+        );
+    }
+}
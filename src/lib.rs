@@ -1,6 +1,9 @@
 extern crate core;
 
+mod diagnostic;
+mod error;
+mod syntax;
 mod tokenizer;
 mod tree;
 
-pub use {tokenizer::*, tree::*};
+pub use {diagnostic::*, error::*, syntax::*, tokenizer::*, tree::*};
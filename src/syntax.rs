@@ -0,0 +1,8 @@
+mod ast;
+mod green;
+mod kind;
+mod parser;
+mod red;
+
+pub(crate) use kind::NodeKind;
+pub use {ast::*, green::*, kind::SyntaxKind, parser::*, red::*};
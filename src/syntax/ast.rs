@@ -0,0 +1,489 @@
+use super::{SyntaxKind, SyntaxNode, SyntaxToken};
+use crate::{
+    block, block_statement, class_body_declaration, constructor_body, expression,
+    import_declaration, package_declaration, parse_tokens, r#type, variable_declarator, Block,
+    ClassBodyDeclaration, CompilationUnitDeclaration, ConstructorBody, ConstructorDeclaration,
+    Expression, FieldDeclaration, ImportDeclaration, MethodDeclaration, PackageDeclaration,
+    ParseError, Statement, Token, TokenStream, Type, VariableDeclarator,
+};
+use nom::{combinator::map_opt, IResult, Parser};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// AstNode是具体语法树节点的类型化视图，每种视图对应一种或几种[`SyntaxKind`]。
+///
+/// 视图只是包装了[`SyntaxNode`]，创建和克隆都不会复制语法树。
+pub trait AstNode: Sized {
+    /// 种类为`kind`的节点能否转换成该视图。
+    fn can_cast(kind: SyntaxKind) -> bool;
+
+    /// 把节点转换成该视图，种类不匹配时返回`None`。
+    fn cast(node: SyntaxNode) -> Option<Self>;
+
+    /// 获取视图包装的节点。
+    fn syntax(&self) -> &SyntaxNode;
+}
+
+macro_rules! ast_node {
+    ($(#[$meta:meta])* $name:ident: $($kind:ident)|+) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+        pub struct $name(SyntaxNode);
+
+        impl AstNode for $name {
+            fn can_cast(kind: SyntaxKind) -> bool {
+                matches!(kind, $(SyntaxKind::$kind)|+)
+            }
+
+            fn cast(node: SyntaxNode) -> Option<Self> {
+                Self::can_cast(node.kind()).then_some(Self(node))
+            }
+
+            fn syntax(&self) -> &SyntaxNode {
+                &self.0
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+                Display::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+/// 获取`node`的子节点中第一个能转换成`T`的节点。
+fn child<T: AstNode>(node: &SyntaxNode) -> Option<T> {
+    node.children().find_map(T::cast)
+}
+
+/// 获取`node`的子节点中所有能转换成`T`的节点。
+fn children<T: AstNode + 'static>(node: &SyntaxNode) -> impl Iterator<Item = T> + '_ {
+    node.children().filter_map(T::cast)
+}
+
+/// 获取`node`的直接子标记中第`n`个标识符。
+fn identifier(node: &SyntaxNode, n: usize) -> Option<SyntaxToken> {
+    node.children_with_tokens()
+        .filter_map(|c| c.into_token())
+        .filter(|t| matches!(t.token(), Some(Token::Identifier(_))))
+        .nth(n)
+}
+
+/// 用`parser`和构建语法树时的解析选项重新解析节点的文本，得到对应的语法树节点。
+///
+/// 错误的位置换算成在整个源代码中的位置。
+fn reparse<T>(
+    node: &SyntaxNode,
+    parser: impl FnOnce(TokenStream) -> IResult<TokenStream, T>,
+) -> Result<T, ParseError> {
+    let text = node.text();
+    TokenStream::from_source(&text)
+        .and_then(|t| {
+            let tokens = t.with_options(*node.options()).without_comments();
+            parse_tokens(&text, tokens, parser)
+        })
+        .map_err(|e| e.relocate(&node.root().text(), node.text_range().start))
+}
+
+ast_node!(
+    /// 编译单元，语法树的根节点。
+    CompilationUnitSyntax: CompilationUnit
+);
+
+impl CompilationUnitSyntax {
+    /// 获取包声明。
+    pub fn package(&self) -> Option<PackageDeclarationSyntax> {
+        child(&self.0)
+    }
+
+    /// 获取所有导入声明。
+    pub fn imports(&self) -> impl Iterator<Item = ImportDeclarationSyntax> + '_ {
+        children(&self.0)
+    }
+
+    /// 获取所有顶层类型声明。
+    pub fn types(&self) -> impl Iterator<Item = TypeDeclarationSyntax> + '_ {
+        children(&self.0)
+    }
+
+    /// 获取隐式声明类的编译单元中不属于任何类型声明的顶层成员。
+    pub fn members(&self) -> impl Iterator<Item = MemberSyntax> + '_ {
+        self.0
+            .children()
+            .filter(|n| !TypeDeclarationSyntax::can_cast(n.kind()))
+            .filter_map(MemberSyntax::cast)
+    }
+
+    /// 重新解析得到编译单元，普通注释会关联到声明和语句上。
+    pub fn to_ast(&self) -> Result<CompilationUnitDeclaration<'static>, ParseError> {
+        reparse(&self.0, crate::parse)
+    }
+}
+
+ast_node!(
+    /// 包声明`package a.b;`。
+    PackageDeclarationSyntax: PackageDeclaration
+);
+
+impl PackageDeclarationSyntax {
+    /// 重新解析得到包声明。
+    pub fn to_ast(&self) -> Result<PackageDeclaration<'static>, ParseError> {
+        reparse(&self.0, package_declaration)
+    }
+}
+
+ast_node!(
+    /// 导入声明`import a.b.C;`。
+    ImportDeclarationSyntax: ImportDeclaration
+);
+
+impl ImportDeclarationSyntax {
+    /// 重新解析得到导入声明。
+    pub fn to_ast(&self) -> Result<ImportDeclaration<'static>, ParseError> {
+        reparse(&self.0, import_declaration)
+    }
+}
+
+ast_node!(
+    /// 类、接口、枚举、记录或注解接口声明。
+    TypeDeclarationSyntax:
+        ClassDeclaration
+            | InterfaceDeclaration
+            | EnumDeclaration
+            | RecordDeclaration
+            | AnnotationDeclaration
+);
+
+impl TypeDeclarationSyntax {
+    /// 获取类型的名称。
+    pub fn name(&self) -> Option<SyntaxToken> {
+        // 记录声明的`record`也是标识符
+        let n = usize::from(self.0.kind() == SyntaxKind::RecordDeclaration);
+        identifier(&self.0, n)
+    }
+
+    /// 获取类体，枚举声明没有单独的类体节点，成员直接属于枚举声明。
+    pub fn body(&self) -> Option<ClassBodySyntax> {
+        child(&self.0)
+    }
+
+    /// 获取所有成员，包括枚举声明中常量之后的成员。
+    pub fn members(&self) -> impl Iterator<Item = MemberSyntax> {
+        let node = match self.body() {
+            Some(body) => body.0,
+            None => self.0.clone(),
+        };
+        let is_body = node != self.0;
+        node.children()
+            .filter(move |n| is_body || !matches!(n.kind(), SyntaxKind::EnumConstant))
+            .filter_map(MemberSyntax::cast)
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    /// 重新解析得到类型声明。
+    pub fn to_ast(&self) -> Result<ClassBodyDeclaration<'static>, ParseError> {
+        reparse(&self.0, class_body_declaration)
+    }
+}
+
+ast_node!(
+    /// 类体`{ ... }`。
+    ClassBodySyntax: ClassBody
+);
+
+impl ClassBodySyntax {
+    /// 获取所有成员。
+    pub fn members(&self) -> impl Iterator<Item = MemberSyntax> + '_ {
+        children(&self.0)
+    }
+}
+
+ast_node!(
+    /// 类体中的成员声明，包括成员类型。
+    MemberSyntax:
+        FieldDeclaration
+            | MethodDeclaration
+            | ConstructorDeclaration
+            | Initializer
+            | AnnotationMethod
+            | ClassDeclaration
+            | InterfaceDeclaration
+            | EnumDeclaration
+            | RecordDeclaration
+            | AnnotationDeclaration
+            | Error
+);
+
+impl MemberSyntax {
+    /// 重新解析得到成员声明。
+    pub fn to_ast(&self) -> Result<ClassBodyDeclaration<'static>, ParseError> {
+        reparse(&self.0, class_body_declaration)
+    }
+}
+
+ast_node!(
+    /// 方法声明。
+    MethodDeclarationSyntax: MethodDeclaration
+);
+
+impl MethodDeclarationSyntax {
+    /// 获取方法的名称。
+    pub fn name(&self) -> Option<SyntaxToken> {
+        identifier(&self.0, 0)
+    }
+
+    /// 获取返回类型，`void`方法没有返回类型。
+    pub fn return_type(&self) -> Option<TypeSyntax> {
+        child(&self.0)
+    }
+
+    /// 获取所有形式参数。
+    pub fn parameters(&self) -> impl Iterator<Item = FormalParameterSyntax> {
+        parameters(&self.0)
+    }
+
+    /// 获取方法体，抽象方法没有方法体。
+    pub fn body(&self) -> Option<BlockSyntax> {
+        child(&self.0)
+    }
+
+    /// 重新解析得到方法声明。
+    pub fn to_ast(&self) -> Result<MethodDeclaration<'static>, ParseError> {
+        reparse(&self.0, |t| {
+            map_opt(class_body_declaration, |d| match d {
+                ClassBodyDeclaration::Method(m) => Some(m),
+                _ => None,
+            })
+            .parse(t)
+        })
+    }
+}
+
+/// 获取方法或构造函数的所有形式参数。
+fn parameters(node: &SyntaxNode) -> impl Iterator<Item = FormalParameterSyntax> {
+    node.children()
+        .find(|n| n.kind() == SyntaxKind::ParameterList)
+        .into_iter()
+        .flat_map(|n| {
+            n.children()
+                .filter_map(FormalParameterSyntax::cast)
+                .collect::<Vec<_>>()
+        })
+}
+
+ast_node!(
+    /// 构造函数声明。
+    ConstructorDeclarationSyntax: ConstructorDeclaration
+);
+
+impl ConstructorDeclarationSyntax {
+    /// 获取构造函数的名称。
+    pub fn name(&self) -> Option<SyntaxToken> {
+        identifier(&self.0, 0)
+    }
+
+    /// 获取所有形式参数，记录类的紧凑构造函数没有形式参数。
+    pub fn parameters(&self) -> impl Iterator<Item = FormalParameterSyntax> {
+        parameters(&self.0)
+    }
+
+    /// 获取构造函数体。
+    pub fn body(&self) -> Option<ConstructorBodySyntax> {
+        child(&self.0)
+    }
+
+    /// 重新解析得到构造函数声明。
+    pub fn to_ast(&self) -> Result<ConstructorDeclaration<'static>, ParseError> {
+        reparse(&self.0, |t| {
+            map_opt(class_body_declaration, |d| match d {
+                ClassBodyDeclaration::Constructor(c) => Some(c),
+                _ => None,
+            })
+            .parse(t)
+        })
+    }
+}
+
+ast_node!(
+    /// 字段声明。
+    FieldDeclarationSyntax: FieldDeclaration
+);
+
+impl FieldDeclarationSyntax {
+    /// 获取字段的类型。
+    pub fn r#type(&self) -> Option<TypeSyntax> {
+        child(&self.0)
+    }
+
+    /// 获取所有变量声明符。
+    pub fn declarators(&self) -> impl Iterator<Item = VariableDeclaratorSyntax> + '_ {
+        children(&self.0)
+    }
+
+    /// 重新解析得到字段声明。
+    pub fn to_ast(&self) -> Result<FieldDeclaration<'static>, ParseError> {
+        reparse(&self.0, |t| {
+            map_opt(class_body_declaration, |d| match d {
+                ClassBodyDeclaration::Field(f) => Some(f),
+                _ => None,
+            })
+            .parse(t)
+        })
+    }
+}
+
+ast_node!(
+    /// 变量声明符`a = 1`或`b[]`。
+    VariableDeclaratorSyntax: VariableDeclarator
+);
+
+impl VariableDeclaratorSyntax {
+    /// 获取变量的名称。
+    pub fn name(&self) -> Option<SyntaxToken> {
+        identifier(&self.0, 0)
+    }
+
+    /// 获取变量的初始值，数组初始化器不是表达式，需要通过子节点获取。
+    pub fn initializer(&self) -> Option<ExpressionSyntax> {
+        child(&self.0)
+    }
+
+    /// 重新解析得到变量声明符。
+    pub fn to_ast(&self) -> Result<VariableDeclarator<'static>, ParseError> {
+        reparse(&self.0, variable_declarator)
+    }
+}
+
+ast_node!(
+    /// 形式参数。
+    FormalParameterSyntax: FormalParameter
+);
+
+impl FormalParameterSyntax {
+    /// 获取参数的名称。
+    pub fn name(&self) -> Option<SyntaxToken> {
+        identifier(&self.0, 0)
+    }
+
+    /// 获取参数的类型。
+    pub fn r#type(&self) -> Option<TypeSyntax> {
+        child(&self.0)
+    }
+}
+
+ast_node!(
+    /// 代码块`{ ... }`。
+    BlockSyntax: Block
+);
+
+impl BlockSyntax {
+    /// 获取所有语句，局部类声明不是语句，需要通过子节点获取。
+    pub fn statements(&self) -> impl Iterator<Item = StatementSyntax> + '_ {
+        children(&self.0)
+    }
+
+    /// 重新解析得到代码块。
+    pub fn to_ast(&self) -> Result<Block<'static>, ParseError> {
+        reparse(&self.0, block)
+    }
+}
+
+ast_node!(
+    /// 构造函数体，可以以显式构造函数调用开始。
+    ConstructorBodySyntax: ConstructorBody
+);
+
+impl ConstructorBodySyntax {
+    /// 获取显式构造函数调用之后的所有语句。
+    pub fn statements(&self) -> impl Iterator<Item = StatementSyntax> + '_ {
+        children(&self.0)
+    }
+
+    /// 重新解析得到构造函数体。
+    pub fn to_ast(&self) -> Result<ConstructorBody<'static>, ParseError> {
+        reparse(&self.0, constructor_body)
+    }
+}
+
+ast_node!(
+    /// 语句，包括局部变量声明语句。
+    StatementSyntax:
+        Block
+            | LocalVariableStatement
+            | EmptyStatement
+            | LabeledStatement
+            | ExpressionStatement
+            | IfStatement
+            | WhileStatement
+            | DoStatement
+            | ForStatement
+            | BreakStatement
+            | ContinueStatement
+            | ReturnStatement
+            | ThrowStatement
+            | AssertStatement
+            | SynchronizedStatement
+            | SwitchStatement
+            | YieldStatement
+            | TryStatement
+            | Error
+);
+
+impl StatementSyntax {
+    /// 重新解析得到语句。
+    pub fn to_ast(&self) -> Result<Statement<'static>, ParseError> {
+        reparse(&self.0, block_statement)
+    }
+}
+
+ast_node!(
+    /// 表达式。
+    ExpressionSyntax:
+        Literal
+            | NameExpression
+            | ThisExpression
+            | FieldAccess
+            | SuperFieldAccess
+            | MethodInvocation
+            | SuperMethodInvocation
+            | ArrayAccess
+            | ClassLiteral
+            | ClassCreation
+            | ArrayCreation
+            | ParenthesizedExpression
+            | AssignmentExpression
+            | TernaryExpression
+            | BinaryExpression
+            | LambdaExpression
+            | MethodReference
+            | SwitchExpression
+            | InstanceOfExpression
+            | UnaryExpression
+            | PostfixExpression
+            | CastExpression
+);
+
+impl ExpressionSyntax {
+    /// 获取所有直接的子表达式，例如二元运算的两个操作数。
+    pub fn operands(&self) -> impl Iterator<Item = ExpressionSyntax> + '_ {
+        children(&self.0)
+    }
+
+    /// 重新解析得到表达式。
+    pub fn to_ast(&self) -> Result<Expression<'static>, ParseError> {
+        reparse(&self.0, expression)
+    }
+}
+
+ast_node!(
+    /// 类型。
+    TypeSyntax: PrimitiveType | ReferenceType | ArrayType
+);
+
+impl TypeSyntax {
+    /// 重新解析得到类型。
+    pub fn to_ast(&self) -> Result<Type<'static>, ParseError> {
+        reparse(&self.0, r#type)
+    }
+}
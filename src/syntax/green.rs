@@ -0,0 +1,165 @@
+use super::SyntaxKind;
+use crate::Token;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+/// GreenNode是具体语法树中不可变的节点，只记录种类、文本长度和子元素，不记录位置和父节点。
+///
+/// 克隆只增加引用计数，相同的子树可以在多棵语法树之间共享。
+#[derive(Clone, Debug, PartialEq)]
+pub struct GreenNode(Arc<GreenNodeData>);
+
+#[derive(Debug, PartialEq)]
+struct GreenNodeData {
+    kind: SyntaxKind,
+    text_len: usize,
+    children: Vec<GreenElement>,
+}
+
+impl GreenNode {
+    /// 用子元素创建一个节点。
+    pub fn new(kind: SyntaxKind, children: Vec<GreenElement>) -> Self {
+        let text_len = children.iter().map(GreenElement::text_len).sum();
+        Self(Arc::new(GreenNodeData {
+            kind,
+            text_len,
+            children,
+        }))
+    }
+
+    /// 获取节点的种类。
+    #[inline]
+    pub fn kind(&self) -> SyntaxKind {
+        self.0.kind
+    }
+
+    /// 获取节点文本的字节长度。
+    #[inline]
+    pub fn text_len(&self) -> usize {
+        self.0.text_len
+    }
+
+    /// 获取所有子元素。
+    #[inline]
+    pub fn children(&self) -> &[GreenElement] {
+        &self.0.children
+    }
+
+    /// 两个节点是否是同一个节点（而不只是内容相同）。
+    #[inline]
+    pub(crate) fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// 节点数据的地址，用于计算哈希值。
+    #[inline]
+    pub(crate) fn addr(&self) -> usize {
+        Arc::as_ptr(&self.0) as usize
+    }
+}
+
+impl Display for GreenNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for i in self.children() {
+            match i {
+                GreenElement::Node(n) => Display::fmt(n, f)?,
+                GreenElement::Token(t) => Display::fmt(t, f)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// GreenToken是具体语法树中不可变的标记，保存标记在源代码中的原始文本。
+#[derive(Clone, Debug, PartialEq)]
+pub struct GreenToken(Arc<GreenTokenData>);
+
+#[derive(Debug, PartialEq)]
+struct GreenTokenData {
+    kind: SyntaxKind,
+    text: String,
+    token: Option<Token>,
+}
+
+impl GreenToken {
+    /// 创建一个标记，`token`是词法分析得到的标记，空白和普通注释没有对应的标记。
+    pub fn new(kind: SyntaxKind, text: impl Into<String>, token: Option<Token>) -> Self {
+        Self(Arc::new(GreenTokenData {
+            kind,
+            text: text.into(),
+            token,
+        }))
+    }
+
+    /// 获取标记的种类。
+    #[inline]
+    pub fn kind(&self) -> SyntaxKind {
+        self.0.kind
+    }
+
+    /// 获取标记的原始文本。
+    #[inline]
+    pub fn text(&self) -> &str {
+        &self.0.text
+    }
+
+    /// 获取词法分析得到的标记。
+    #[inline]
+    pub fn token(&self) -> Option<&Token> {
+        self.0.token.as_ref()
+    }
+
+    /// 获取标记文本的字节长度。
+    #[inline]
+    pub fn text_len(&self) -> usize {
+        self.0.text.len()
+    }
+}
+
+impl Display for GreenToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.text())
+    }
+}
+
+/// GreenElement是节点或标记。
+#[derive(Clone, Debug, PartialEq)]
+pub enum GreenElement {
+    /// 节点。
+    Node(GreenNode),
+    /// 标记。
+    Token(GreenToken),
+}
+
+impl GreenElement {
+    /// 获取元素的种类。
+    pub fn kind(&self) -> SyntaxKind {
+        match self {
+            Self::Node(n) => n.kind(),
+            Self::Token(t) => t.kind(),
+        }
+    }
+
+    /// 获取元素文本的字节长度。
+    pub fn text_len(&self) -> usize {
+        match self {
+            Self::Node(n) => n.text_len(),
+            Self::Token(t) => t.text_len(),
+        }
+    }
+}
+
+impl From<GreenNode> for GreenElement {
+    fn from(node: GreenNode) -> Self {
+        Self::Node(node)
+    }
+}
+
+impl From<GreenToken> for GreenElement {
+    fn from(token: GreenToken) -> Self {
+        Self::Token(token)
+    }
+}
@@ -0,0 +1,337 @@
+use crate::{
+    ClassBodyDeclaration, ElementValue, Expression, Pattern, Statement,
+    TopLevelClassOrInterfaceDeclaration, Type, TypeArgument,
+};
+
+/// SyntaxKind表示具体语法树中节点或标记的种类。
+///
+/// 标记分为三种：语法分析器使用的[`SyntaxKind::Token`]，以及不参与语法分析的空白和注释（统称为琐碎内容）。
+/// 其余种类都是节点，大多和语法树中的节点类型一一对应，表达式和语句按照变体细分。
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum SyntaxKind {
+    /// 词法分析得到的标记，具体内容见[`crate::SyntaxToken::token`]。
+    Token,
+    /// 空白。
+    Whitespace,
+    /// 普通注释或文档注释。
+    Comment,
+
+    /// 编译单元，总是语法树的根节点。
+    CompilationUnit,
+    /// 包声明`package a.b;`。
+    PackageDeclaration,
+    /// 导入声明`import a.b.C;`。
+    ImportDeclaration,
+    /// 模块声明`module a.b { ... }`。
+    ModuleDeclaration,
+    /// 模块声明中的指令，例如`requires a;`。
+    ModuleDirective,
+    /// 类声明。
+    ClassDeclaration,
+    /// 接口声明。
+    InterfaceDeclaration,
+    /// 枚举声明。
+    EnumDeclaration,
+    /// 记录声明。
+    RecordDeclaration,
+    /// 注解接口声明。
+    AnnotationDeclaration,
+    /// 注解和修饰符，例如`@Override public static`。
+    Modifiers,
+    /// 类型参数列表`<T, U extends V>`。
+    TypeParameters,
+    /// 类型参数`U extends V`。
+    TypeParameter,
+    /// 类体`{ ... }`。
+    ClassBody,
+    /// 枚举常量。
+    EnumConstant,
+    /// 记录组件。
+    RecordComponent,
+
+    /// 字段声明。
+    FieldDeclaration,
+    /// 方法声明。
+    MethodDeclaration,
+    /// 构造函数声明。
+    ConstructorDeclaration,
+    /// 实例或静态初始化块。
+    Initializer,
+    /// 注解接口的元素声明。
+    AnnotationMethod,
+    /// 形式参数列表`(int a, String... b)`。
+    ParameterList,
+    /// 形式参数。
+    FormalParameter,
+    /// 变量声明符`a = 1`或`b[]`。
+    VariableDeclarator,
+    /// 数组初始化器`{1, 2}`。
+    ArrayInitializer,
+    /// 代码块`{ ... }`。
+    Block,
+    /// 构造函数体。
+    ConstructorBody,
+    /// 显式构造函数调用`this(...)`或`super(...)`。
+    ExplicitConstructorInvocation,
+
+    /// 不带分号的局部变量声明，例如`for`循环初始化部分中的`int i = 0`。
+    LocalVariableDeclaration,
+    /// 局部变量声明语句`int a = 1;`。
+    LocalVariableStatement,
+    /// 空语句`;`。
+    EmptyStatement,
+    /// 带标签的语句。
+    LabeledStatement,
+    /// 表达式语句。
+    ExpressionStatement,
+    /// `if`语句。
+    IfStatement,
+    /// `while`语句。
+    WhileStatement,
+    /// `do`语句。
+    DoStatement,
+    /// `for`语句，包括增强的`for`语句。
+    ForStatement,
+    /// `break`语句。
+    BreakStatement,
+    /// `continue`语句。
+    ContinueStatement,
+    /// `return`语句。
+    ReturnStatement,
+    /// `throw`语句。
+    ThrowStatement,
+    /// `assert`语句。
+    AssertStatement,
+    /// `synchronized`语句。
+    SynchronizedStatement,
+    /// `switch`语句。
+    SwitchStatement,
+    /// `yield`语句。
+    YieldStatement,
+    /// `try`语句。
+    TryStatement,
+    /// `catch`子句。
+    CatchClause,
+    /// 带资源的`try`语句中的一个资源。
+    TryResource,
+    /// 恢复解析时跳过的无法解析的内容。
+    Error,
+    /// 箭头形式的`switch`规则`case X -> ...`。
+    SwitchRule,
+    /// 旧式的`switch`语句组`case X: ...`。
+    SwitchGroup,
+    /// `switch`标签，例如`case 1, 2`或`default`。
+    SwitchLabel,
+
+    /// 字面量。
+    Literal,
+    /// 表达式名称，可以是限定名称。
+    NameExpression,
+    /// `this`或限定的`this`。
+    ThisExpression,
+    /// 字段访问。
+    FieldAccess,
+    /// 父类字段访问。
+    SuperFieldAccess,
+    /// 方法调用。
+    MethodInvocation,
+    /// 父类方法调用。
+    SuperMethodInvocation,
+    /// 数组访问。
+    ArrayAccess,
+    /// 类字面量，例如`String.class`或`void.class`。
+    ClassLiteral,
+    /// 创建类的实例。
+    ClassCreation,
+    /// 创建数组。
+    ArrayCreation,
+    /// 括号表达式。
+    ParenthesizedExpression,
+    /// 赋值表达式。
+    AssignmentExpression,
+    /// 条件表达式。
+    TernaryExpression,
+    /// 二元运算。
+    BinaryExpression,
+    /// Lambda表达式。
+    LambdaExpression,
+    /// 方法引用。
+    MethodReference,
+    /// `switch`表达式。
+    SwitchExpression,
+    /// `instanceof`表达式。
+    InstanceOfExpression,
+    /// 前缀一元运算。
+    UnaryExpression,
+    /// 后缀自增或自减。
+    PostfixExpression,
+    /// 类型转换。
+    CastExpression,
+    /// 实际参数列表`(a, b)`。
+    ArgumentList,
+
+    /// 类型参数列表`<String, ?>`。
+    TypeArguments,
+    /// 类型参数，例如`String`或`? extends T`。
+    TypeArgument,
+    /// 基本类型。
+    PrimitiveType,
+    /// 引用类型。
+    ReferenceType,
+    /// 数组类型。
+    ArrayType,
+
+    /// 类型模式。
+    TypePattern,
+    /// 记录模式。
+    RecordPattern,
+    /// 未命名模式`_`。
+    UnnamedPattern,
+
+    /// 注解。
+    Annotation,
+    /// 注解元素的名称和值`name = value`。
+    ElementValuePair,
+    /// 数组形式的注解元素值`{a, b}`。
+    ElementValueArray,
+}
+
+impl SyntaxKind {
+    /// 是否是标记的种类。
+    pub fn is_token(self) -> bool {
+        matches!(self, Self::Token | Self::Whitespace | Self::Comment)
+    }
+
+    /// 是否是空白或普通注释。
+    pub fn is_trivia(self) -> bool {
+        matches!(self, Self::Whitespace | Self::Comment)
+    }
+}
+
+/// 获取语法树节点对应的具体语法树节点的种类，没有对应的种类时返回`None`。
+pub(crate) trait NodeKind {
+    fn syntax_kind(&self) -> Option<SyntaxKind>;
+}
+
+impl NodeKind for Expression<'_> {
+    fn syntax_kind(&self) -> Option<SyntaxKind> {
+        Some(match self {
+            Self::Literal(_) => SyntaxKind::Literal,
+            Self::Name(_) => SyntaxKind::NameExpression,
+            Self::This | Self::QualifiedThis(_) => SyntaxKind::ThisExpression,
+            Self::FieldAccess { .. } => SyntaxKind::FieldAccess,
+            Self::SuperFieldAccess { .. } => SyntaxKind::SuperFieldAccess,
+            Self::MethodInvocation { .. } => SyntaxKind::MethodInvocation,
+            Self::SuperMethodInvocation { .. } => SyntaxKind::SuperMethodInvocation,
+            Self::ArrayAccess { .. } => SyntaxKind::ArrayAccess,
+            Self::ClassLiteral(_) | Self::VoidClassLiteral => SyntaxKind::ClassLiteral,
+            Self::ClassCreation { .. } => SyntaxKind::ClassCreation,
+            Self::ArrayCreation { .. } => SyntaxKind::ArrayCreation,
+            Self::Parenthesized(_) => SyntaxKind::ParenthesizedExpression,
+            Self::Assignment { .. } => SyntaxKind::AssignmentExpression,
+            Self::Ternary { .. } => SyntaxKind::TernaryExpression,
+            Self::Binary { .. } => SyntaxKind::BinaryExpression,
+            Self::Lambda { .. } => SyntaxKind::LambdaExpression,
+            Self::MethodReference { .. } => SyntaxKind::MethodReference,
+            Self::Switch(_) => SyntaxKind::SwitchExpression,
+            Self::InstanceOf { .. } => SyntaxKind::InstanceOfExpression,
+            Self::Unary { .. } => SyntaxKind::UnaryExpression,
+            Self::Postfix { .. } => SyntaxKind::PostfixExpression,
+            Self::Cast { .. } => SyntaxKind::CastExpression,
+        })
+    }
+}
+
+impl NodeKind for Statement<'_> {
+    fn syntax_kind(&self) -> Option<SyntaxKind> {
+        Some(match self {
+            Self::Block(_) => SyntaxKind::Block,
+            Self::LocalVariable(_) => SyntaxKind::LocalVariableStatement,
+            Self::LocalClass(d) => return d.syntax_kind(),
+            Self::Empty => SyntaxKind::EmptyStatement,
+            Self::Labeled { .. } => SyntaxKind::LabeledStatement,
+            Self::Expression(_) => SyntaxKind::ExpressionStatement,
+            Self::If { .. } => SyntaxKind::IfStatement,
+            Self::While { .. } => SyntaxKind::WhileStatement,
+            Self::Do { .. } => SyntaxKind::DoStatement,
+            Self::For { .. } => SyntaxKind::ForStatement,
+            Self::Break(_) => SyntaxKind::BreakStatement,
+            Self::Continue(_) => SyntaxKind::ContinueStatement,
+            Self::Return(_) => SyntaxKind::ReturnStatement,
+            Self::Throw(_) => SyntaxKind::ThrowStatement,
+            Self::Assert { .. } => SyntaxKind::AssertStatement,
+            Self::Synchronized { .. } => SyntaxKind::SynchronizedStatement,
+            Self::Switch(_) => SyntaxKind::SwitchStatement,
+            Self::Yield(_) => SyntaxKind::YieldStatement,
+            Self::Try(_) => SyntaxKind::TryStatement,
+            Self::Error(_) => SyntaxKind::Error,
+        })
+    }
+}
+
+impl NodeKind for ClassBodyDeclaration<'_> {
+    fn syntax_kind(&self) -> Option<SyntaxKind> {
+        Some(match self {
+            Self::Field(_) => SyntaxKind::FieldDeclaration,
+            Self::Method(_) => SyntaxKind::MethodDeclaration,
+            Self::Constructor(_) => SyntaxKind::ConstructorDeclaration,
+            Self::Initializer(_) => SyntaxKind::Initializer,
+            Self::AnnotationMethod(_) => SyntaxKind::AnnotationMethod,
+            Self::Class(_) => SyntaxKind::ClassDeclaration,
+            Self::Interface(_) => SyntaxKind::InterfaceDeclaration,
+            Self::Enum(_) => SyntaxKind::EnumDeclaration,
+            Self::Record(_) => SyntaxKind::RecordDeclaration,
+            Self::Annotation(_) => SyntaxKind::AnnotationDeclaration,
+            Self::Error(_) => SyntaxKind::Error,
+        })
+    }
+}
+
+impl NodeKind for TopLevelClassOrInterfaceDeclaration<'_> {
+    fn syntax_kind(&self) -> Option<SyntaxKind> {
+        Some(match self {
+            Self::Class(_) => SyntaxKind::ClassDeclaration,
+            Self::Interface(_) => SyntaxKind::InterfaceDeclaration,
+            Self::Enum(_) => SyntaxKind::EnumDeclaration,
+            Self::Record(_) => SyntaxKind::RecordDeclaration,
+            Self::Annotation(_) => SyntaxKind::AnnotationDeclaration,
+            Self::Error(_) => SyntaxKind::Error,
+        })
+    }
+}
+
+impl NodeKind for Type<'_> {
+    fn syntax_kind(&self) -> Option<SyntaxKind> {
+        Some(match self {
+            Self::Basic(_) => SyntaxKind::PrimitiveType,
+            Self::Reference(_) => SyntaxKind::ReferenceType,
+            Self::Array(_) => SyntaxKind::ArrayType,
+        })
+    }
+}
+
+impl NodeKind for TypeArgument<'_> {
+    fn syntax_kind(&self) -> Option<SyntaxKind> {
+        Some(SyntaxKind::TypeArgument)
+    }
+}
+
+impl NodeKind for Pattern<'_> {
+    fn syntax_kind(&self) -> Option<SyntaxKind> {
+        Some(match self {
+            Self::Type { .. } => SyntaxKind::TypePattern,
+            Self::Record { .. } => SyntaxKind::RecordPattern,
+            Self::Unnamed => SyntaxKind::UnnamedPattern,
+        })
+    }
+}
+
+impl NodeKind for ElementValue<'_> {
+    fn syntax_kind(&self) -> Option<SyntaxKind> {
+        match self {
+            Self::Array(_) => Some(SyntaxKind::ElementValueArray),
+            _ => None,
+        }
+    }
+}
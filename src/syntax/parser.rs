@@ -0,0 +1,541 @@
+use super::{GreenElement, GreenNode, GreenToken, NodeKind, SyntaxKind, SyntaxNode};
+use crate::{
+    one_token, parse, parse_tokens, source_len_exceeded, NodeSink, ParseError, ParseOptions, Token,
+    TokenInfo, TokenStream,
+};
+use std::{
+    any::Any,
+    ops::Range,
+    sync::{Arc, Mutex},
+};
+
+/// 解析Java源代码，构建具体语法树
+///
+/// 和[`crate::parse_str`]不同，该函数返回的语法树保留了源代码中的每个字符（包括空白和普通注释），
+/// 可以从任何节点出发访问父节点、兄弟节点和祖先节点，也可以通过[`crate::AstNode`]以类型化的方式访问。
+///
+/// # 参数
+///
+/// * `source` - Java源代码。
+///
+/// # 返回值
+///
+/// 返回语法树的根节点，它的种类总是[`SyntaxKind::CompilationUnit`]，输出它会得到原始的源代码。
+///
+/// # 示例
+///
+/// ```rust
+/// use java_lang::{parse_syntax, AstNode, MethodDeclarationSyntax, SyntaxKind};
+///
+/// let source = "class A {\n    // 入口\n    void main() { run(1 + 2); }\n}\n";
+/// let root = parse_syntax(source).unwrap();
+/// assert_eq!(root.to_string(), source);
+///
+/// // 从表达式向上找到它所在的方法
+/// let sum = root
+///     .descendants()
+///     .find(|n| n.kind() == SyntaxKind::BinaryExpression)
+///     .unwrap();
+/// assert_eq!(sum.text(), "1 + 2");
+/// let method = sum.ancestors().find_map(MethodDeclarationSyntax::cast).unwrap();
+/// assert_eq!(method.name().unwrap().text(), "main");
+/// ```
+///
+/// # 错误处理
+///
+/// 如果源代码中有无法识别的字符或不符合Java语法，将返回一个解析错误。
+pub fn parse_syntax(source: &str) -> Result<SyntaxNode, ParseError> {
+    parse_syntax_with_options(source, Default::default())
+}
+
+/// 使用指定的解析选项解析Java源代码，构建具体语法树，参见[`parse_syntax`]。
+pub fn parse_syntax_with_options(
+    source: &str,
+    options: ParseOptions,
+) -> Result<SyntaxNode, ParseError> {
+    if source.len() > options.max_source_len {
+        return Err(source_len_exceeded(source, options));
+    }
+    let log = Arc::new(NodeLog::default());
+    let tokens = TokenStream::from_source(source)?
+        .with_options(options)
+        .recording(log.clone());
+    parse_tokens(source, tokens.clone(), parse)?;
+    let nodes = log.0.lock().map_or_else(|_| Vec::new(), |n| n.clone());
+    let green = build(source, &tokens, nodes);
+
+    Ok(SyntaxNode::new_root_with_options(green, options))
+}
+
+/// 解析时记录的语法节点的种类和字节范围，按结束位置排序。
+#[derive(Debug, Default)]
+struct NodeLog(Mutex<Vec<(SyntaxKind, Range<usize>)>>);
+
+impl NodeSink for NodeLog {
+    fn rewind(&self, offset: usize) {
+        if let Ok(mut nodes) = self.0.lock() {
+            while nodes.last().is_some_and(|(_, r)| r.end > offset) {
+                nodes.pop();
+            }
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl TokenStream {
+    /// 记录一个从字节偏移`start`开始、到该标记流开头结束的语法节点，空节点不会被记录。
+    ///
+    /// 只在构建具体语法树（见[`parse_syntax`]）时记录，
+    /// 结束位置更靠后的记录来自回溯前失败的尝试，会被丢弃。
+    pub(crate) fn finish_node(&self, start: usize, kind: SyntaxKind) {
+        let Some(log) = self.sink::<NodeLog>() else {
+            return;
+        };
+        let end = self.source_offset();
+        if start >= end {
+            return;
+        }
+        log.rewind(end);
+        if let Ok(mut nodes) = log.0.lock() {
+            nodes.push((kind, start..end));
+        }
+    }
+
+    /// 以`node`对应的种类记录一个语法节点（见[`TokenStream::finish_node`]），没有对应的种类时不记录。
+    pub(crate) fn finish_node_for(&self, start: usize, node: &impl NodeKind) {
+        if let Some(kind) = node.syntax_kind() {
+            self.finish_node(start, kind);
+        }
+    }
+}
+
+/// 具体语法树中的一个叶子：标记、空白或普通注释。
+struct Leaf {
+    kind: SyntaxKind,
+    span: Range<usize>,
+    token: Option<Token>,
+}
+
+/// 用源代码、词法分析得到的标记（包括普通注释）和解析时记录的语法节点构建具体语法树。
+fn build(source: &str, tokens: &TokenStream, nodes: Vec<(SyntaxKind, Range<usize>)>) -> GreenNode {
+    let mut boundaries: Vec<_> = nodes.iter().flat_map(|(_, r)| [r.start, r.end]).collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+    let leaves = leaves(source, tokens.as_slice(), tokens.as_info(), &boundaries);
+
+    // 语法节点对应的标记（不包括琐碎内容）的下标范围
+    let starts: Vec<_> = leaves
+        .iter()
+        .filter(|l| !l.kind.is_trivia())
+        .map(|l| l.span.start)
+        .collect();
+    let mut ranges: Vec<_> = nodes
+        .into_iter()
+        .enumerate()
+        .filter_map(|(order, (kind, range))| {
+            let first = starts.partition_point(|s| *s < range.start);
+            let last = starts.partition_point(|s| *s < range.end).checked_sub(1)?;
+            (first <= last).then_some((first, last, order, kind))
+        })
+        .collect();
+    // 开始位置相同时外层的节点在前，范围相同时后记录的节点在外层
+    ranges.sort_by(|a, b| (a.0, b.1, b.2).cmp(&(b.0, a.1, a.2)));
+    ranges.dedup_by(|b, a| (a.0, a.1, a.3) == (b.0, b.1, b.3));
+
+    let mut builder = Builder::default();
+    let mut ranges = ranges.into_iter().peekable();
+    let mut index = 0;
+    let mut trivia = Vec::new();
+    for leaf in leaves {
+        let token = GreenToken::new(leaf.kind, &source[leaf.span.clone()], leaf.token);
+        if leaf.kind.is_trivia() {
+            trivia.push(token);
+            continue;
+        }
+        builder.close_before(index);
+        builder.push_all(&mut trivia);
+        while let Some((_, last, _, kind)) = ranges.next_if(|r| r.0 == index) {
+            // 解析器按嵌套顺序记录节点，回溯的尝试记录的节点已经被丢弃，所以节点不会交叉
+            debug_assert!(
+                builder.stack.last().is_none_or(|n| n.last >= last),
+                "{kind:?} crosses the enclosing node"
+            );
+            builder.stack.push(PendingNode {
+                kind,
+                last,
+                children: Vec::new(),
+            });
+        }
+        builder.push(token.into());
+        index += 1;
+    }
+    builder.close_before(usize::MAX);
+    builder.push_all(&mut trivia);
+
+    GreenNode::new(SyntaxKind::CompilationUnit, builder.root)
+}
+
+/// 把源代码切分成叶子，标记之间的文本是空白。
+///
+/// 语法节点的边界落在一个标记内部时（例如`List<List<T>>`中的`>>`），这个标记会在边界处被拆开重新进行词法分析。
+fn leaves(source: &str, tokens: &[Token], info: &[TokenInfo], boundaries: &[usize]) -> Vec<Leaf> {
+    let mut leaves = Vec::new();
+    let mut end = 0;
+    for (token, info) in tokens.iter().zip(info) {
+        let span = info.span.clone();
+        if end < span.start {
+            leaves.push(Leaf {
+                kind: SyntaxKind::Whitespace,
+                span: end..span.start,
+                token: None,
+            });
+        }
+        end = span.end;
+        if token.is_comment() {
+            leaves.push(Leaf {
+                kind: SyntaxKind::Comment,
+                span,
+                token: Some(token.clone()),
+            });
+            continue;
+        }
+        let first = boundaries.partition_point(|b| *b <= span.start);
+        let last = boundaries.partition_point(|b| *b < span.end);
+        let splits = &boundaries[first..last];
+        if splits.is_empty() {
+            leaves.push(Leaf {
+                kind: SyntaxKind::Token,
+                span,
+                token: Some(token.clone()),
+            });
+            continue;
+        }
+        let mut start = span.start;
+        for end in splits.iter().copied().chain([span.end]) {
+            let token = one_token(&source[start..end]).ok().map(|(_, t)| t);
+            leaves.push(Leaf {
+                kind: SyntaxKind::Token,
+                span: start..end,
+                token,
+            });
+            start = end;
+        }
+    }
+    if end < source.len() {
+        leaves.push(Leaf {
+            kind: SyntaxKind::Whitespace,
+            span: end..source.len(),
+            token: None,
+        });
+    }
+
+    leaves
+}
+
+/// 还没有结束的节点。
+struct PendingNode {
+    kind: SyntaxKind,
+    /// 节点中最后一个标记（不包括琐碎内容）的下标。
+    last: usize,
+    children: Vec<GreenElement>,
+}
+
+/// 按顺序添加叶子、逐层构建节点。
+#[derive(Default)]
+struct Builder {
+    stack: Vec<PendingNode>,
+    root: Vec<GreenElement>,
+}
+
+impl Builder {
+    /// 把元素添加到最内层还没有结束的节点中。
+    fn push(&mut self, element: GreenElement) {
+        match self.stack.last_mut() {
+            Some(node) => node.children.push(element),
+            None => self.root.push(element),
+        }
+    }
+
+    fn push_all(&mut self, tokens: &mut Vec<GreenToken>) {
+        for token in tokens.drain(..) {
+            self.push(token.into());
+        }
+    }
+
+    /// 结束所有不包含第`index`个标记的节点。
+    fn close_before(&mut self, index: usize) {
+        while self.stack.last().is_some_and(|n| n.last < index) {
+            if let Some(node) = self.stack.pop() {
+                self.push(GreenNode::new(node.kind, node.children).into());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        AstNode, BlockSyntax, ClassBodyDeclaration, CompilationUnitSyntax, ExpressionSyntax,
+        ImportDeclarationSyntax, MethodDeclarationSyntax, SyntaxElement, TypeDeclarationSyntax,
+    };
+
+    const SOURCE: &str = r#"package a.b;
+
+import java.util.*; // 工具类
+
+/** 文档 */
+@Deprecated(since = "1")
+public class A<T extends List<Map<String, T>>> {
+    private int x = 1, y[] = {1, 2};
+
+    A(int x) { this(x, 2); }
+
+    /* 块注释 */
+    int f(int a) {
+        var l = (String s) -> s.length();
+        int r = switch (a) { case 1 -> 2; default -> { yield a >> 1; } };
+        if (o instanceof Point(var p, _)) return a.b.c(1)[0]++;
+        return new int[]{1}[0] > 0 ? x : y;
+    }
+}
+
+@interface Ann { int v() default 1; }
+
+enum E { X(1), Y { } }
+
+record R(int a) {}
+"#;
+
+    #[test]
+    fn test_lossless() -> anyhow::Result<()> {
+        let root = parse_syntax(SOURCE)?;
+        assert_eq!(root.kind(), SyntaxKind::CompilationUnit);
+        assert_eq!(root.to_string(), SOURCE);
+        assert_eq!(root.text_range(), 0..SOURCE.len());
+
+        // 每个叶子都和源代码中的文本对应，并且首尾相接
+        let mut offset = 0;
+        for e in root.descendants_with_tokens() {
+            if let SyntaxElement::Token(t) = e {
+                assert_eq!(t.text_range().start, offset);
+                assert_eq!(&SOURCE[t.text_range()], t.text());
+                offset = t.text_range().end;
+            }
+        }
+        assert_eq!(offset, SOURCE.len());
+
+        let module = "/* 模块 */\nopen module m.n {\n    requires transitive java.base;\n    exports p to q;\n}\n";
+        assert_eq!(parse_syntax(module)?.to_string(), module);
+        let root = parse_syntax(module)?;
+        let m = root.first_child().unwrap();
+        assert_eq!(m.kind(), SyntaxKind::ModuleDeclaration);
+        assert_eq!(m.children().count(), 2);
+
+        assert!(parse_syntax("class A { void f() { int } }").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_navigation() -> anyhow::Result<()> {
+        let root = parse_syntax(SOURCE)?;
+        let shift = root
+            .descendants()
+            .find(|n| n.kind() == SyntaxKind::BinaryExpression)
+            .unwrap();
+        assert_eq!(shift.text(), "a >> 1");
+        let kinds: Vec<_> = shift
+            .ancestors()
+            .skip(1)
+            .map(|n| n.kind())
+            .take(4)
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                SyntaxKind::YieldStatement,
+                SyntaxKind::Block,
+                SyntaxKind::SwitchRule,
+                SyntaxKind::SwitchExpression
+            ]
+        );
+        let method = shift
+            .ancestors()
+            .find_map(MethodDeclarationSyntax::cast)
+            .unwrap();
+        assert_eq!(method.name().unwrap().text(), "f");
+        assert_eq!(shift.root(), root);
+
+        // 兄弟节点
+        let field = root
+            .descendants()
+            .find(|n| n.kind() == SyntaxKind::FieldDeclaration)
+            .unwrap();
+        let constructor = field.next_sibling().unwrap();
+        assert_eq!(constructor.kind(), SyntaxKind::ConstructorDeclaration);
+        assert_eq!(constructor.prev_sibling().unwrap(), field);
+        assert_eq!(
+            constructor.next_sibling().unwrap().kind(),
+            SyntaxKind::MethodDeclaration
+        );
+        // 字段和构造函数之间是空白
+        let gap = field.next_sibling_or_token().unwrap();
+        assert_eq!(gap.kind(), SyntaxKind::Whitespace);
+
+        // 普通注释属于包含它两侧标记的最内层节点
+        let comment = root
+            .descendants_with_tokens()
+            .filter_map(SyntaxElement::into_token)
+            .find(|t| t.kind() == SyntaxKind::Comment && t.text() == "/* 块注释 */")
+            .unwrap();
+        assert_eq!(comment.parent().kind(), SyntaxKind::ClassBody);
+        // 相邻的标记包括空白
+        let next = comment.next_token().unwrap();
+        assert_eq!(next.kind(), SyntaxKind::Whitespace);
+        assert_eq!(next.next_token().unwrap().text(), "int");
+        let prev = comment.prev_token().unwrap().prev_token().unwrap();
+        assert_eq!(prev.text(), "}");
+
+        let offset = SOURCE.find("length").unwrap();
+        let token = root.token_at_offset(offset + 2).unwrap();
+        assert_eq!(token.text(), "length");
+        assert_eq!(token.token(), Some(&Token::Identifier("length".into())));
+        assert_eq!(token.parent().kind(), SyntaxKind::MethodInvocation);
+        let covering = root.covering_element(offset..offset + 8).unwrap();
+        assert_eq!(covering.kind(), SyntaxKind::MethodInvocation);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_shift() -> anyhow::Result<()> {
+        let source = "class A { Map<String, List<Integer>> m; }";
+        let root = parse_syntax(source)?;
+        assert_eq!(root.to_string(), source);
+        let inner = root
+            .descendants()
+            .filter(|n| n.kind() == SyntaxKind::TypeArguments)
+            .last()
+            .unwrap();
+        assert_eq!(inner.text(), "<Integer>");
+        let close = inner.last_token().unwrap();
+        assert_eq!(close.text(), ">");
+        assert_eq!(close.next_token().unwrap().text(), ">");
+        assert_eq!(
+            close.next_token().unwrap().parent().text(),
+            "<String, List<Integer>>"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ast_views() -> anyhow::Result<()> {
+        let root = parse_syntax(SOURCE)?;
+        let unit = crate::CompilationUnitSyntax::cast(root.clone()).unwrap();
+        assert_eq!(
+            unit.package().unwrap().to_ast()?.to_string().trim(),
+            "package a.b;"
+        );
+        assert_eq!(unit.imports().count(), 1);
+        let names: Vec<_> = unit
+            .types()
+            .filter_map(|t| t.name())
+            .map(|t| t.text().to_string())
+            .collect();
+        assert_eq!(names, ["A", "Ann", "E", "R"]);
+
+        let class = unit.types().next().unwrap();
+        assert_eq!(class.members().count(), 3);
+        assert!(matches!(class.to_ast()?, ClassBodyDeclaration::Class(_)));
+        let method = class
+            .members()
+            .find_map(|m| MethodDeclarationSyntax::cast(m.syntax().clone()))
+            .unwrap();
+        assert_eq!(method.return_type().unwrap().to_string(), "int");
+        let parameters: Vec<_> = method
+            .parameters()
+            .map(|p| p.name().unwrap().text().to_string())
+            .collect();
+        assert_eq!(parameters, ["a"]);
+        assert_eq!(method.body().unwrap().statements().count(), 4);
+        assert_eq!(method.to_ast()?.name.to_string(), "f");
+
+        // 注解类型的元素
+        let annotation = unit.types().nth(1).unwrap();
+        let element = annotation
+            .body()
+            .unwrap()
+            .syntax()
+            .children()
+            .next()
+            .unwrap();
+        assert_eq!(element.kind(), SyntaxKind::AnnotationMethod);
+        assert!(TypeDeclarationSyntax::cast(element).is_none());
+
+        // 克隆只复制指针
+        let copy = root.clone();
+        assert!(copy.green().ptr_eq(root.green()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reparse_options() -> anyhow::Result<()> {
+        let source = "import module java.base;\nvoid main() { if (o instanceof int i) {} }\n";
+        let root = parse_syntax_with_options(source, ParseOptions::preview())?;
+        assert!(root.options().preview);
+        let unit = CompilationUnitSyntax::cast(root.clone()).unwrap();
+        assert_eq!(
+            unit.to_ast()?.to_string().matches("import module").count(),
+            1
+        );
+        let import = root
+            .children()
+            .find_map(ImportDeclarationSyntax::cast)
+            .unwrap();
+        assert!(import
+            .to_ast()?
+            .to_string()
+            .starts_with("import module java.base;"));
+        let method = root
+            .children()
+            .find_map(MethodDeclarationSyntax::cast)
+            .unwrap();
+        assert_eq!(method.to_ast()?.name.to_string(), "main");
+        let pattern = root
+            .descendants()
+            .find(|n| n.kind() == SyntaxKind::InstanceOfExpression)
+            .and_then(ExpressionSyntax::cast)
+            .unwrap();
+        assert_eq!(pattern.to_ast()?.to_string(), "o instanceof int i");
+
+        // 错误的位置是在整个源代码中的位置
+        let block = GreenNode::new(
+            SyntaxKind::Block,
+            vec![GreenElement::Token(GreenToken::new(
+                SyntaxKind::Token,
+                "{ int }",
+                None,
+            ))],
+        );
+        let green = GreenNode::new(
+            SyntaxKind::CompilationUnit,
+            vec![
+                GreenElement::Token(GreenToken::new(SyntaxKind::Token, "class A\n\n", None)),
+                GreenElement::Node(block),
+            ],
+        );
+        let root = SyntaxNode::new_root(green);
+        let block = root.children().find_map(BlockSyntax::cast).unwrap();
+        let error = block.to_ast().unwrap_err();
+        assert_eq!((error.line(), error.column()), (3, 7));
+        assert_eq!(error.span(), 15..16);
+
+        Ok(())
+    }
+}
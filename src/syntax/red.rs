@@ -0,0 +1,537 @@
+use super::{GreenElement, GreenNode, GreenToken, SyntaxKind};
+use crate::{ParseOptions, Token};
+use std::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    hash::{Hash, Hasher},
+    iter::successors,
+    ops::Range,
+    sync::Arc,
+};
+
+/// SyntaxNode是具体语法树中的节点游标，在[`GreenNode`]之上记录了父节点和在源代码中的位置。
+///
+/// 克隆只增加引用计数。游标按需创建，从根节点向下访问时才会分配，所以可以从任何节点出发访问父节点、兄弟节点和祖先节点。
+/// 两个游标指向同一棵语法树中的同一个节点时相等。
+#[derive(Clone)]
+pub struct SyntaxNode(Arc<NodeData>);
+
+struct NodeData {
+    green: GreenNode,
+    parent: Option<SyntaxNode>,
+    /// 在父节点的子元素中的下标。
+    index: usize,
+    /// 在源代码中的字节偏移。
+    offset: usize,
+    /// 构建语法树时使用的解析选项。
+    options: ParseOptions,
+}
+
+impl SyntaxNode {
+    /// 以`green`为根节点创建语法树。
+    pub fn new_root(green: GreenNode) -> Self {
+        Self::new_root_with_options(green, Default::default())
+    }
+
+    /// 以`green`为根节点创建语法树，`options`是构建`green`时使用的解析选项。
+    pub fn new_root_with_options(green: GreenNode, options: ParseOptions) -> Self {
+        Self(Arc::new(NodeData {
+            green,
+            parent: None,
+            index: 0,
+            offset: 0,
+            options,
+        }))
+    }
+
+    /// 获取节点的种类。
+    #[inline]
+    pub fn kind(&self) -> SyntaxKind {
+        self.0.green.kind()
+    }
+
+    /// 获取节点在源代码中的字节范围。
+    #[inline]
+    pub fn text_range(&self) -> Range<usize> {
+        self.0.offset..self.0.offset + self.0.green.text_len()
+    }
+
+    /// 获取构建语法树时使用的解析选项，类型化视图重新解析节点时使用同样的选项。
+    #[inline]
+    pub fn options(&self) -> &ParseOptions {
+        &self.0.options
+    }
+
+    /// 获取节点对应的[`GreenNode`]。
+    #[inline]
+    pub fn green(&self) -> &GreenNode {
+        &self.0.green
+    }
+
+    /// 获取节点的原始文本，包括其中的空白和注释。
+    pub fn text(&self) -> String {
+        self.to_string()
+    }
+
+    /// 获取父节点，根节点没有父节点。
+    #[inline]
+    pub fn parent(&self) -> Option<SyntaxNode> {
+        self.0.parent.clone()
+    }
+
+    /// 从该节点开始依次获取该节点、父节点、父节点的父节点，直到根节点。
+    pub fn ancestors(&self) -> impl Iterator<Item = SyntaxNode> {
+        successors(Some(self.clone()), SyntaxNode::parent)
+    }
+
+    /// 获取根节点。
+    pub fn root(&self) -> SyntaxNode {
+        self.ancestors().last().unwrap_or_else(|| self.clone())
+    }
+
+    /// 获取第`index`个子元素，`offset`是它在源代码中的字节偏移。
+    fn child_at(&self, index: usize, offset: usize) -> Option<SyntaxElement> {
+        Some(match self.0.green.children().get(index)? {
+            GreenElement::Node(green) => SyntaxElement::Node(Self(Arc::new(NodeData {
+                green: green.clone(),
+                parent: Some(self.clone()),
+                index,
+                offset,
+                options: self.0.options,
+            }))),
+            GreenElement::Token(green) => SyntaxElement::Token(SyntaxToken {
+                green: green.clone(),
+                parent: self.clone(),
+                index,
+                offset,
+            }),
+        })
+    }
+
+    /// 获取所有子元素，包括标记。
+    pub fn children_with_tokens(&self) -> impl Iterator<Item = SyntaxElement> + '_ {
+        let mut offset = self.0.offset;
+        (0..self.0.green.children().len()).filter_map(move |i| {
+            let child = self.child_at(i, offset)?;
+            offset += child.text_len();
+            Some(child)
+        })
+    }
+
+    /// 获取所有子节点。
+    pub fn children(&self) -> impl Iterator<Item = SyntaxNode> + '_ {
+        self.children_with_tokens()
+            .filter_map(SyntaxElement::into_node)
+    }
+
+    /// 获取第一个子元素。
+    pub fn first_child_or_token(&self) -> Option<SyntaxElement> {
+        self.child_at(0, self.0.offset)
+    }
+
+    /// 获取最后一个子元素。
+    pub fn last_child_or_token(&self) -> Option<SyntaxElement> {
+        let index = self.0.green.children().len().checked_sub(1)?;
+        let len = self.0.green.children()[index].text_len();
+        self.child_at(index, self.text_range().end - len)
+    }
+
+    /// 获取第一个子节点。
+    pub fn first_child(&self) -> Option<SyntaxNode> {
+        self.children().next()
+    }
+
+    /// 获取最后一个子节点。
+    pub fn last_child(&self) -> Option<SyntaxNode> {
+        successors(
+            self.last_child_or_token(),
+            SyntaxElement::prev_sibling_or_token,
+        )
+        .find_map(SyntaxElement::into_node)
+    }
+
+    /// 获取下一个兄弟元素。
+    pub fn next_sibling_or_token(&self) -> Option<SyntaxElement> {
+        let parent = self.0.parent.as_ref()?;
+        parent.child_at(self.0.index + 1, self.text_range().end)
+    }
+
+    /// 获取上一个兄弟元素。
+    pub fn prev_sibling_or_token(&self) -> Option<SyntaxElement> {
+        prev_sibling(self.0.parent.as_ref()?, self.0.index, self.0.offset)
+    }
+
+    /// 获取下一个兄弟节点。
+    pub fn next_sibling(&self) -> Option<SyntaxNode> {
+        successors(
+            self.next_sibling_or_token(),
+            SyntaxElement::next_sibling_or_token,
+        )
+        .find_map(SyntaxElement::into_node)
+    }
+
+    /// 获取上一个兄弟节点。
+    pub fn prev_sibling(&self) -> Option<SyntaxNode> {
+        successors(
+            self.prev_sibling_or_token(),
+            SyntaxElement::prev_sibling_or_token,
+        )
+        .find_map(SyntaxElement::into_node)
+    }
+
+    /// 获取子树中的第一个标记。
+    pub fn first_token(&self) -> Option<SyntaxToken> {
+        self.descendants_with_tokens()
+            .find_map(SyntaxElement::into_token)
+    }
+
+    /// 获取子树中的最后一个标记。
+    pub fn last_token(&self) -> Option<SyntaxToken> {
+        let mut element = self.last_child_or_token()?;
+        loop {
+            element = match element {
+                SyntaxElement::Token(t) => return Some(t),
+                SyntaxElement::Node(n) => match n.last_child_or_token() {
+                    Some(e) => e,
+                    None => n.prev_sibling_or_token()?,
+                },
+            }
+        }
+    }
+
+    /// 以先序遍历的顺序获取子树中的所有元素，包括该节点本身。
+    pub fn descendants_with_tokens(&self) -> impl Iterator<Item = SyntaxElement> {
+        let root = self.clone();
+        successors(Some(SyntaxElement::Node(self.clone())), move |element| {
+            if let SyntaxElement::Node(n) = element {
+                if let Some(child) = n.first_child_or_token() {
+                    return Some(child);
+                }
+            }
+            let mut element = element.clone();
+            loop {
+                if element.as_node() == Some(&root) {
+                    return None;
+                }
+                if let Some(sibling) = element.next_sibling_or_token() {
+                    return Some(sibling);
+                }
+                element = SyntaxElement::Node(element.parent()?);
+            }
+        })
+    }
+
+    /// 以先序遍历的顺序获取子树中的所有节点，包括该节点本身。
+    pub fn descendants(&self) -> impl Iterator<Item = SyntaxNode> {
+        self.descendants_with_tokens()
+            .filter_map(SyntaxElement::into_node)
+    }
+
+    /// 获取子树中包含字节偏移`offset`的标记，`offset`在两个标记之间时返回后一个标记。
+    pub fn token_at_offset(&self, offset: usize) -> Option<SyntaxToken> {
+        let range = self.text_range();
+        if !range.contains(&offset) {
+            return None;
+        }
+        let mut node = self.clone();
+        loop {
+            let child = node
+                .children_with_tokens()
+                .find(|c| c.text_range().contains(&offset))?;
+            match child {
+                SyntaxElement::Node(n) => node = n,
+                SyntaxElement::Token(t) => return Some(t),
+            }
+        }
+    }
+
+    /// 获取子树中完整包含字节范围`range`的最小元素，`range`超出该节点时返回`None`。
+    pub fn covering_element(&self, range: Range<usize>) -> Option<SyntaxElement> {
+        let own = self.text_range();
+        if range.start < own.start || range.end > own.end {
+            return None;
+        }
+        let mut node = self.clone();
+        loop {
+            let child = node.children_with_tokens().find(|c| {
+                let r = c.text_range();
+                r.start <= range.start && range.end <= r.end && !(r.is_empty() && range.is_empty())
+            });
+            match child {
+                Some(SyntaxElement::Node(n)) => node = n,
+                Some(SyntaxElement::Token(t)) => return Some(SyntaxElement::Token(t)),
+                None => return Some(SyntaxElement::Node(node)),
+            }
+        }
+    }
+}
+
+/// 获取`parent`中第`index`个子元素（字节偏移为`offset`）的上一个兄弟元素。
+fn prev_sibling(parent: &SyntaxNode, index: usize, offset: usize) -> Option<SyntaxElement> {
+    let index = index.checked_sub(1)?;
+    let len = parent.0.green.children()[index].text_len();
+    parent.child_at(index, offset - len)
+}
+
+impl PartialEq for SyntaxNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.green.ptr_eq(&other.0.green) && self.0.offset == other.0.offset
+    }
+}
+
+impl Eq for SyntaxNode {}
+
+impl Hash for SyntaxNode {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.green.addr().hash(state);
+        self.0.offset.hash(state);
+    }
+}
+
+impl Display for SyntaxNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.0.green, f)
+    }
+}
+
+impl Debug for SyntaxNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}@{:?}", self.kind(), self.text_range())
+    }
+}
+
+/// SyntaxToken是具体语法树中的标记游标，记录了所在的节点和在源代码中的位置。
+#[derive(Clone)]
+pub struct SyntaxToken {
+    green: GreenToken,
+    parent: SyntaxNode,
+    /// 在父节点的子元素中的下标。
+    index: usize,
+    /// 在源代码中的字节偏移。
+    offset: usize,
+}
+
+impl SyntaxToken {
+    /// 获取标记的种类。
+    #[inline]
+    pub fn kind(&self) -> SyntaxKind {
+        self.green.kind()
+    }
+
+    /// 获取标记的原始文本。
+    #[inline]
+    pub fn text(&self) -> &str {
+        self.green.text()
+    }
+
+    /// 获取词法分析得到的标记，空白和普通注释返回`None`。
+    ///
+    /// 被拆开消费的复合标记（例如`List<List<T>>`中的`>>`）会被拆成多个标记，每个标记只包含其中一部分。
+    #[inline]
+    pub fn token(&self) -> Option<&Token> {
+        self.green.token()
+    }
+
+    /// 获取标记在源代码中的字节范围。
+    #[inline]
+    pub fn text_range(&self) -> Range<usize> {
+        self.offset..self.offset + self.green.text_len()
+    }
+
+    /// 获取对应的[`GreenToken`]。
+    #[inline]
+    pub fn green(&self) -> &GreenToken {
+        &self.green
+    }
+
+    /// 获取标记所在的节点。
+    #[inline]
+    pub fn parent(&self) -> SyntaxNode {
+        self.parent.clone()
+    }
+
+    /// 依次获取标记所在的节点和它的所有祖先节点。
+    pub fn ancestors(&self) -> impl Iterator<Item = SyntaxNode> {
+        self.parent.ancestors()
+    }
+
+    /// 获取下一个兄弟元素。
+    pub fn next_sibling_or_token(&self) -> Option<SyntaxElement> {
+        self.parent.child_at(self.index + 1, self.text_range().end)
+    }
+
+    /// 获取上一个兄弟元素。
+    pub fn prev_sibling_or_token(&self) -> Option<SyntaxElement> {
+        prev_sibling(&self.parent, self.index, self.offset)
+    }
+
+    /// 获取整棵语法树中的下一个标记。
+    pub fn next_token(&self) -> Option<SyntaxToken> {
+        let mut element = SyntaxElement::Token(self.clone());
+        loop {
+            element = match element.next_sibling_or_token() {
+                Some(SyntaxElement::Token(t)) => return Some(t),
+                Some(SyntaxElement::Node(n)) => match n.first_token() {
+                    Some(t) => return Some(t),
+                    None => SyntaxElement::Node(n),
+                },
+                None => SyntaxElement::Node(element.parent()?),
+            }
+        }
+    }
+
+    /// 获取整棵语法树中的上一个标记。
+    pub fn prev_token(&self) -> Option<SyntaxToken> {
+        let mut element = SyntaxElement::Token(self.clone());
+        loop {
+            element = match element.prev_sibling_or_token() {
+                Some(SyntaxElement::Token(t)) => return Some(t),
+                Some(SyntaxElement::Node(n)) => match n.last_token() {
+                    Some(t) => return Some(t),
+                    None => SyntaxElement::Node(n),
+                },
+                None => SyntaxElement::Node(element.parent()?),
+            }
+        }
+    }
+}
+
+impl PartialEq for SyntaxToken {
+    fn eq(&self, other: &Self) -> bool {
+        self.parent == other.parent && self.index == other.index
+    }
+}
+
+impl Eq for SyntaxToken {}
+
+impl Hash for SyntaxToken {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.parent.hash(state);
+        self.index.hash(state);
+    }
+}
+
+impl Display for SyntaxToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.text())
+    }
+}
+
+impl Debug for SyntaxToken {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "{:?}@{:?} {:?}",
+            self.kind(),
+            self.text_range(),
+            self.text()
+        )
+    }
+}
+
+/// SyntaxElement是节点游标或标记游标。
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SyntaxElement {
+    /// 节点。
+    Node(SyntaxNode),
+    /// 标记。
+    Token(SyntaxToken),
+}
+
+impl SyntaxElement {
+    /// 获取元素的种类。
+    pub fn kind(&self) -> SyntaxKind {
+        match self {
+            Self::Node(n) => n.kind(),
+            Self::Token(t) => t.kind(),
+        }
+    }
+
+    /// 获取元素在源代码中的字节范围。
+    pub fn text_range(&self) -> Range<usize> {
+        match self {
+            Self::Node(n) => n.text_range(),
+            Self::Token(t) => t.text_range(),
+        }
+    }
+
+    fn text_len(&self) -> usize {
+        self.text_range().len()
+    }
+
+    /// 获取父节点，根节点没有父节点。
+    pub fn parent(&self) -> Option<SyntaxNode> {
+        match self {
+            Self::Node(n) => n.parent(),
+            Self::Token(t) => Some(t.parent()),
+        }
+    }
+
+    /// 获取下一个兄弟元素。
+    pub fn next_sibling_or_token(&self) -> Option<SyntaxElement> {
+        match self {
+            Self::Node(n) => n.next_sibling_or_token(),
+            Self::Token(t) => t.next_sibling_or_token(),
+        }
+    }
+
+    /// 获取上一个兄弟元素。
+    pub fn prev_sibling_or_token(&self) -> Option<SyntaxElement> {
+        match self {
+            Self::Node(n) => n.prev_sibling_or_token(),
+            Self::Token(t) => t.prev_sibling_or_token(),
+        }
+    }
+
+    /// 如果是节点，获取它的引用。
+    pub fn as_node(&self) -> Option<&SyntaxNode> {
+        match self {
+            Self::Node(n) => Some(n),
+            Self::Token(_) => None,
+        }
+    }
+
+    /// 如果是标记，获取它的引用。
+    pub fn as_token(&self) -> Option<&SyntaxToken> {
+        match self {
+            Self::Node(_) => None,
+            Self::Token(t) => Some(t),
+        }
+    }
+
+    /// 如果是节点，转换成节点。
+    pub fn into_node(self) -> Option<SyntaxNode> {
+        match self {
+            Self::Node(n) => Some(n),
+            Self::Token(_) => None,
+        }
+    }
+
+    /// 如果是标记，转换成标记。
+    pub fn into_token(self) -> Option<SyntaxToken> {
+        match self {
+            Self::Node(_) => None,
+            Self::Token(t) => Some(t),
+        }
+    }
+}
+
+impl Display for SyntaxElement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Node(n) => Display::fmt(n, f),
+            Self::Token(t) => Display::fmt(t, f),
+        }
+    }
+}
+
+impl From<SyntaxNode> for SyntaxElement {
+    fn from(node: SyntaxNode) -> Self {
+        Self::Node(node)
+    }
+}
+
+impl From<SyntaxToken> for SyntaxElement {
+    fn from(token: SyntaxToken) -> Self {
+        Self::Token(token)
+    }
+}
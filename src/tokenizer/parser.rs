@@ -1,82 +1,105 @@
 use super::Token;
-use nom::{branch::{alt, permutation}, bytes::complete::{tag, take_until, take_while}, character::{
-    complete::{alpha1, bin_digit1, char, digit1, hex_digit1, oct_digit1},
-    one_of,
+use crate::{LiteralError, ParseError};
+use nom::{branch::{alt, permutation}, bytes::complete::{tag, take_until, take_while, take_while_m_n}, character::{
+    complete::{alpha1, bin_digit1, char, digit1, hex_digit1, none_of, oct_digit1, one_of, satisfy},
     streaming::multispace0,
-}, combinator::{map, opt}, error::{Error, ErrorKind}, multi::many0, sequence::{delimited, pair, preceded, terminated}, IResult, Parser};
+}, combinator::{map, map_opt, not, opt}, error::{Error, ErrorKind}, multi::{many0, many1}, sequence::{delimited, pair, preceded, terminated}, IResult, Offset, Parser};
+
+/// 十进制`int`字面量的最大值。`2147483648`只能作为一元`-`的操作数，由语法分析检查。
+pub(crate) const INT_LITERAL_MAX: u32 = 1 << 31;
+/// 十进制`long`字面量的最大值。`9223372036854775808L`只能作为一元`-`的操作数，由语法分析检查。
+pub(crate) const LONG_LITERAL_MAX: u64 = 1 << 63;
 
 fn keyword(i: &str) -> IResult<&str, Token> {
     let (remaining, out) = alt([
-        tag(Token::ABSTRACT),
-        tag(Token::ASSERT),
-        tag(Token::BOOLEAN),
-        tag(Token::BREAK),
-        tag(Token::BYTE),
-        tag(Token::CASE),
-        tag(Token::CATCH),
-        tag(Token::CHAR),
-        tag(Token::CLASS),
-        tag(Token::CONST),
-        tag(Token::CONTINUE),
-        tag(Token::DEFAULT),
-        tag(Token::DO),
-        tag(Token::DOUBLE),
-        tag(Token::ELSE),
-        tag(Token::ENUM),
-        tag(Token::EXTENDS),
-        tag(Token::FINAL),
-        tag(Token::FINALLY),
-        tag(Token::FLOAT),
-        tag(Token::FOR),
-        tag(Token::IF),
-        tag(Token::IMPLEMENTS),
-        tag(Token::IMPORT),
-        tag(Token::INSTANCEOF),
-        tag(Token::INT),
-        tag(Token::INTERFACE),
-        tag(Token::LONG),
-        tag(Token::NATIVE),
-        tag(Token::NEW),
-        tag(Token::PACKAGE),
-        tag(Token::PRIVATE),
-        tag(Token::PROTECTED),
-        tag(Token::PUBLIC),
-        tag(Token::RETURN),
-        tag(Token::SHORT),
-        tag(Token::STATIC),
-        tag(Token::STRICTFP),
-        tag(Token::SUPER),
-        tag(Token::SWITCH),
-        tag(Token::SYNCHRONIZED),
-        tag(Token::THIS),
-        tag(Token::THROW),
-        tag(Token::THROWS),
-        tag(Token::TRANSIENT),
-        tag(Token::TRY),
-        tag(Token::VOID),
-        tag(Token::VOLATILE),
-        tag(Token::WHILE),
+        keyword_tag(Token::ABSTRACT),
+        keyword_tag(Token::ASSERT),
+        keyword_tag(Token::BOOLEAN),
+        keyword_tag(Token::BREAK),
+        keyword_tag(Token::BYTE),
+        keyword_tag(Token::CASE),
+        keyword_tag(Token::CATCH),
+        keyword_tag(Token::CHAR),
+        keyword_tag(Token::CLASS),
+        keyword_tag(Token::CONST),
+        keyword_tag(Token::CONTINUE),
+        keyword_tag(Token::DEFAULT),
+        keyword_tag(Token::DO),
+        keyword_tag(Token::DOUBLE),
+        keyword_tag(Token::ELSE),
+        keyword_tag(Token::ENUM),
+        keyword_tag(Token::EXTENDS),
+        keyword_tag(Token::FINAL),
+        keyword_tag(Token::FINALLY),
+        keyword_tag(Token::FLOAT),
+        keyword_tag(Token::FOR),
+        keyword_tag(Token::IF),
+        keyword_tag(Token::IMPLEMENTS),
+        keyword_tag(Token::IMPORT),
+        keyword_tag(Token::INSTANCEOF),
+        keyword_tag(Token::INT),
+        keyword_tag(Token::INTERFACE),
+        keyword_tag(Token::LONG),
+        keyword_tag(Token::NATIVE),
+        keyword_tag(Token::NEW),
+        keyword_tag(Token::PACKAGE),
+        keyword_tag(Token::PRIVATE),
+        keyword_tag(Token::PROTECTED),
+        keyword_tag(Token::PUBLIC),
+        keyword_tag(Token::RETURN),
+        keyword_tag(Token::SHORT),
+        keyword_tag(Token::STATIC),
+        keyword_tag(Token::STRICTFP),
+        keyword_tag(Token::SUPER),
+        keyword_tag(Token::SWITCH),
+        keyword_tag(Token::SYNCHRONIZED),
+        keyword_tag(Token::THIS),
+        keyword_tag(Token::THROW),
+        keyword_tag(Token::THROWS),
+        keyword_tag(Token::TRANSIENT),
+        keyword_tag(Token::TRY),
+        keyword_tag(Token::VOID),
+        keyword_tag(Token::VOLATILE),
+        keyword_tag(Token::WHILE),
     ])
     .parse(i)?;
     Ok((remaining, Token::from(out)))
 }
 
+/// 匹配一个关键字，关键字后面不能紧跟标识符字符，否则它只是某个标识符或更长关键字的前缀（例如`integer`、`interface`）。
+fn keyword_tag<'a>(
+    keyword: &'static str,
+) -> impl Parser<&'a str, Output = &'a str, Error = Error<&'a str>> {
+    terminated(tag(keyword), not(satisfy(is_identifier_char)))
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
 fn identifier(i: &str) -> IResult<&str, Token> {
     let (remaining, out) = alt((alpha1, tag("_"))).parse(i)?;
-    let (remaining, out2) =
-        take_while(|c: char| c.is_alphanumeric() || c == '_').parse(remaining)?;
+    let (remaining, out2) = take_while(is_identifier_char).parse(remaining)?;
     Ok((remaining, Token::Identifier(out.to_string() + out2)))
 }
 
 fn boolean_literal(i: &str) -> IResult<&str, Token> {
-    map(alt((tag(Token::TRUE), tag(Token::FALSE))), |b: &str| {
-        Token::BooleanLiteral(b == Token::TRUE)
-    })
+    map(
+        terminated(
+            alt((tag(Token::TRUE), tag(Token::FALSE))),
+            not(satisfy(is_identifier_char)),
+        ),
+        |b: &str| Token::BooleanLiteral(b == Token::TRUE),
+    )
     .parse(i)
 }
 
 fn null_literal(i: &str) -> IResult<&str, Token> {
-    map(tag(Token::NULL), |_| Token::NullLiteral).parse(i)
+    map(
+        terminated(tag(Token::NULL), not(satisfy(is_identifier_char))),
+        |_| Token::NullLiteral,
+    )
+    .parse(i)
 }
 
 fn number<const RADIX: u8>(i: &str) -> IResult<&str, String> {
@@ -94,8 +117,38 @@ fn number<const RADIX: u8>(i: &str) -> IResult<&str, String> {
     .parse(i)
 }
 
+/// 字面量错误，出错的字面量是`i`中`remaining`之前的部分，`code`区分错误的原因（见[`lexical_error`]）。
+fn literal_error<'a>(i: &'a str, remaining: &'a str, code: ErrorKind) -> nom::Err<Error<&'a str>> {
+    nom::Err::Failure(Error::new(&i[..i.len() - remaining.len()], code))
+}
+
+/// 把词法分析的失败转换为带有位置的[`ParseError`]，`source`是完整的源代码。
+pub(crate) fn lexical_error(source: &str, e: &Error<&str>) -> ParseError {
+    let span = || {
+        let start = source.offset(e.input);
+        start..start + e.input.len()
+    };
+    match e.code {
+        ErrorKind::TooLarge => ParseError::literal(source, LiteralError::OutOfRange, span()),
+        ErrorKind::Char => ParseError::literal(source, LiteralError::InvalidChar, span()),
+        _ => ParseError::lexical(source, source.len() - e.input.trim_start().len()),
+    }
+}
+
 fn integer_literal(i: &str) -> IResult<&str, Token> {
-    map(number::<10>, |s| Token::IntegerLiteral(s.parse().unwrap())).parse(i)
+    let (remaining, s) = number::<10>(i)?;
+    match s.parse() {
+        Ok(n) if n <= INT_LITERAL_MAX => Ok((remaining, Token::IntegerLiteral(n))),
+        _ => Err(literal_error(i, remaining, ErrorKind::TooLarge)),
+    }
+}
+
+fn long_literal(i: &str) -> IResult<&str, Token> {
+    let (remaining, s) = terminated(number::<10>, one_of("lL")).parse(i)?;
+    match s.parse() {
+        Ok(n) if n <= LONG_LITERAL_MAX => Ok((remaining, Token::LongLiteral(n))),
+        _ => Err(literal_error(i, remaining, ErrorKind::TooLarge)),
+    }
 }
 
 fn double_literal(i: &str) -> IResult<&str, Token> {
@@ -127,35 +180,88 @@ fn float_literal(i: &str) -> IResult<&str, Token> {
     .parse(i)
 }
 
+/// 按`radix`进制解析`digits`和后面可选的`L`或`l`后缀，没有后缀时用`int`构造`u32`范围内的标记，
+/// 否则用`long`构造`u64`范围内的标记，超出范围时返回字面量错误。
+fn radix_literal<'a>(
+    i: &'a str,
+    remaining: &'a str,
+    digits: &str,
+    radix: u32,
+    int: fn(u32) -> Token,
+    long: fn(u64) -> Token,
+) -> IResult<&'a str, Token> {
+    let (remaining, suffix) = opt(one_of("lL")).parse(remaining)?;
+    let value = u64::from_str_radix(digits, radix).ok();
+    let token = match suffix {
+        Some(_) => value.map(long),
+        None => value.and_then(|v| u32::try_from(v).ok()).map(int),
+    };
+    token
+        .map(|t| (remaining, t))
+        .ok_or_else(|| literal_error(i, remaining, ErrorKind::TooLarge))
+}
+
 fn hex_literal(i: &str) -> IResult<&str, Token> {
-    map(
-        permutation((tag("0"), one_of("xX"), number::<16>)),
-        |(_, _, h)| Token::HexLiteral(u32::from_str_radix(&h, 16).unwrap_or(0)),
-    )
-    .parse(i)
+    let (remaining, (_, _, h)) = permutation((tag("0"), one_of("xX"), number::<16>)).parse(i)?;
+    radix_literal(i, remaining, &h, 16, Token::HexLiteral, Token::HexLongLiteral)
 }
 
 fn oct_literal(i: &str) -> IResult<&str, Token> {
-    map(permutation((tag("0"), number::<8>)), |(_, o)| {
-        Token::OctLiteral(u32::from_str_radix(&o, 8).unwrap_or(0))
+    let (remaining, (_, o)) = permutation((tag("0"), number::<8>)).parse(i)?;
+    radix_literal(i, remaining, &o, 8, Token::OctLiteral, Token::OctLongLiteral)
+}
+
+fn bin_literal(i: &str) -> IResult<&str, Token> {
+    let (remaining, (_, _, b)) = permutation((tag("0"), one_of("bB"), number::<2>)).parse(i)?;
+    radix_literal(i, remaining, &b, 2, Token::BinLiteral, Token::BinLongLiteral)
+}
+
+/// 解析八进制转义序列`\0`到`\377`中反斜杠后面的数字。
+fn octal_escape(i: &str) -> IResult<&str, char> {
+    let max = match i.starts_with(['0', '1', '2', '3']) {
+        true => 3,
+        false => 2,
+    };
+    map_opt(take_while_m_n(1, max, |c: char| c.is_digit(8)), |o: &str| {
+        u32::from_str_radix(o, 8).ok().and_then(char::from_u32)
     })
     .parse(i)
 }
 
-fn bin_literal(i: &str) -> IResult<&str, Token> {
-    map(
-        permutation((tag("0"), one_of("bB"), number::<2>)),
-        |(_, _, b)| Token::BinLiteral(u32::from_str_radix(&b, 2).unwrap_or(0)),
-    )
+/// 解析转义序列中反斜杠后面的部分。
+fn escape_sequence(i: &str) -> IResult<&str, char> {
+    alt((
+        map(one_of("btnfrs\"'\\"), |c| match c {
+            'b' => '\u{8}',
+            't' => '\t',
+            'n' => '\n',
+            'f' => '\u{c}',
+            'r' => '\r',
+            's' => ' ',
+            c => c,
+        }),
+        octal_escape,
+        map_opt(
+            preceded(many1(char('u')), take_while_m_n(4, 4, |c: char| c.is_ascii_hexdigit())),
+            |h: &str| u32::from_str_radix(h, 16).ok().and_then(char::from_u32),
+        ),
+    ))
     .parse(i)
 }
 
+/// 字符字面量中必须恰好有一个字符或转义序列，例如`'a'`、`'\n'`、`'\u0041'`。
 fn char_literal(i: &str) -> IResult<&str, Token> {
-    map(
-        delimited(char('\''), take_while(|c: char| c != '\''), char('\'')),
-        |s: &str| Token::CharLiteral(s.chars().next().unwrap()),
-    )
-    .parse(i)
+    let (rest, _) = char('\'').parse(i)?;
+    let content = alt((preceded(char('\\'), escape_sequence), none_of("'\\\r\n")));
+    if let Ok((remaining, c)) = terminated(content, char('\'')).parse(rest) {
+        return Ok((remaining, Token::CharLiteral(c)));
+    }
+    // 同一行中有结束的引号时报告整个字面量，否则是无法识别的字符
+    let line = rest.split(['\r', '\n']).next().unwrap_or_default();
+    match line.find('\'') {
+        Some(end) => Err(literal_error(i, &rest[end + 1..], ErrorKind::Char)),
+        None => Err(nom::Err::Error(Error::new(i, ErrorKind::Char))),
+    }
 }
 
 fn string_literal(i: &str) -> IResult<&str, Token> {
@@ -174,6 +280,7 @@ fn separator(i: &str) -> IResult<&str, Token> {
         tag(Token::RIGHT_BRACE),
         tag(Token::LEFT_BRACKET),
         tag(Token::RIGHT_BRACKET),
+        tag(Token::AT),
         tag(Token::SEMI_COLON),
         tag(Token::COMMA),
         tag(Token::DOT),
@@ -184,6 +291,9 @@ fn separator(i: &str) -> IResult<&str, Token> {
 
 fn operator(i: &str) -> IResult<&str, Token> {
     let (remaining, out) = alt([
+        // `->`和`::`必须在`-`和`:`之前匹配
+        tag(Token::ARROW),
+        tag(Token::DOUBLE_COLON),
         tag(Token::UNSIGNED_SHIFT_RIGHT_ASSIGN),
         tag(Token::UNSIGNED_SHIFT_RIGHT),
         tag(Token::SHIFT_RIGHT_ASSIGN),
@@ -222,13 +332,33 @@ fn operator(i: &str) -> IResult<&str, Token> {
         tag(Token::GREATER_THAN),
         tag(Token::ASSIGN),
         tag(Token::TRIPLE_DOT),
-        tag(Token::ARROW),
-        tag(Token::DOUBLE_COLON),
     ])
     .parse(i)?;
     Ok((remaining, Token::from(out)))
 }
 
+fn markdown_line(i: &str) -> IResult<&str, &str> {
+    preceded(tag("///"), take_while(|c| c != '\n' && c != '\r')).parse(i)
+}
+
+/// 跳过一个换行符和下一行开头的空白后解析`///`行。
+fn next_markdown_line(i: &str) -> IResult<&str, &str> {
+    let (remaining, _) = alt((tag("\r\n"), tag("\n"), tag("\r"))).parse(i)?;
+    markdown_line(remaining.trim_start_matches([' ', '\t']))
+}
+
+/// 连续的`///`行组成一个Markdown文档注释，中间的空行会把它们分成多个注释。
+fn markdown_doc(i: &str) -> IResult<&str, Token> {
+    let (mut remaining, first) = markdown_line(i)?;
+    let mut lines = vec![first];
+    while let Ok((r, line)) = next_markdown_line(remaining) {
+        lines.push(line);
+        remaining = r;
+    }
+
+    Ok((remaining, Token::MarkdownDoc(lines.join("\n"))))
+}
+
 fn single_comment(i: &str) -> IResult<&str, Token> {
     let (remaining, _) = tag("//").parse(i)?;
     let (remaining, out) = take_while(|c| c != '\n' && c != '\r').parse(remaining)?;
@@ -268,6 +398,7 @@ pub fn one_token(i: &str) -> IResult<&str, Token> {
 
     alt((
         java_doc,
+        markdown_doc,
         single_comment,
         multi_comment,
         keyword,
@@ -279,6 +410,7 @@ pub fn one_token(i: &str) -> IResult<&str, Token> {
         bin_literal,
         float_literal,
         double_literal,
+        long_literal,
         integer_literal,
         char_literal,
         string_literal,
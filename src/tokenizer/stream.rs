@@ -1,29 +1,291 @@
-use super::{one_token, Token};
+use super::{lexical_error, one_token, Token};
+use crate::{MissingToken, ParseError, ParseErrorKind, ParseLimit, ParseOptions};
 use nom::{
     error::{Error, ErrorKind},
-    multi::many0,
-    Compare, CompareResult, IResult, Input, Needed, Parser,
+    Compare, CompareResult, IResult, Input, Needed,
 };
-use std::{borrow::Cow, iter::Enumerate, vec::IntoIter};
+use std::{
+    any::Any,
+    fmt::{Debug, Display},
+    iter::Enumerate,
+    mem::take,
+    ops::Range,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+/// 标记在源代码中的位置信息。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TokenInfo {
+    /// 标记在源代码中的字节范围。
+    pub span: Range<usize>,
+    /// 标记和前一个标记之间是否有换行。
+    pub newline_before: bool,
+}
+
+/// 解析失败时最靠后的位置上期望的内容，用于生成错误信息。
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Expectation {
+    /// 失败位置之后剩余的标记数量，数量越少位置越靠后。
+    pub(crate) remaining: usize,
+    /// 期望的内容，例如`` `;` ``或`标识符`。
+    pub(crate) expected: Vec<String>,
+    /// 失败位置上的标记和它的位置信息，`None`表示输入已经结束。
+    pub(crate) found: Option<(Token, Option<TokenInfo>)>,
+    /// 恢复解析时在失败位置补上的标记，在输入的末尾可能补上多个结尾标记。
+    pub(crate) missing: Vec<MissingToken>,
+}
+
+/// 从同一个输入切分出的标记流共享的嵌套层数和终止解析的错误。
+#[derive(Debug, Default)]
+struct Limits {
+    depth: AtomicUsize,
+    /// 最先出现的终止解析的错误（超过限制或字面量错误）和出错的标记的位置信息。
+    fatal: Mutex<Option<(ParseErrorKind, Option<TokenInfo>)>>,
+}
+
+/// 嵌套层数的守卫，离开作用域时退出一层嵌套，见[`TokenStream::enter`]。
+pub(crate) struct DepthGuard(Arc<Limits>);
+
+impl Drop for DepthGuard {
+    fn drop(&mut self) {
+        self.0.depth.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// 接收解析时产生的记录（例如具体语法树的节点）的记录器，见[`TokenStream::recording`]。
+///
+/// 标记流不关心记录的内容，只在回溯时通知记录器撤销失败的尝试留下的记录。
+pub(crate) trait NodeSink: Debug + Send + Sync {
+    /// 丢弃结束位置在字节偏移`offset`之后的记录。
+    fn rewind(&self, offset: usize);
+
+    /// 用于获取具体的记录器，见[`TokenStream::sink`]。
+    fn as_any(&self) -> &dyn Any;
+}
+
+/// 从同一个输入切分出的标记流共享的标记序列。
+#[derive(Debug, Default)]
+struct Storage {
+    tokens: Vec<Token>,
+    /// 每个标记的位置信息，手动构造的标记流没有位置信息（为空）。
+    info: Vec<TokenInfo>,
+    /// 每个标记前面被去掉的普通注释，最后一项是末尾的注释，没有去掉注释时为空。
+    comments: Vec<Vec<(Token, TokenInfo)>>,
+}
+
+impl Storage {
+    /// 追加`stream`中的第`index`个标记，以及它的位置信息和前面的注释。
+    fn push(&mut self, stream: &TokenStream, index: usize) {
+        self.tokens.extend(stream.get(index).cloned());
+        self.info.extend(stream.info_at(index).cloned());
+        self.comments.push(stream.comments_at(index).to_vec());
+    }
+}
+
+/// 插入或替换标记后仍然共享的剩余标记，见[`TokenStream::patch`]。
+///
+/// 单独存储的开头部分末尾的`overlap`个标记是`range`开头的标记的副本，
+/// 保证[`TokenStream::as_slice`]可以向后查看插入的标记之后的标记。
+#[derive(Clone, Debug)]
+struct Rest {
+    data: Arc<Storage>,
+    range: Range<usize>,
+    overlap: usize,
+}
+
+/// 插入或替换标记时复制的之后的标记数量，也就是[`TokenStream::as_slice`]至少能向后查看的标记数量。
+const LOOKAHEAD: usize = 8;
+
+/// 逐个克隆标记流中的标记的迭代器，见[`TokenStream::iter_elements`](Input::iter_elements)。
+///
+/// 迭代器和标记流共享标记序列，创建时不会复制剩余的标记。
+#[derive(Clone, Debug)]
+pub struct TokenIter {
+    data: Arc<Storage>,
+    range: Range<usize>,
+    rest: Option<(Arc<Storage>, Range<usize>)>,
+}
+
+impl Iterator for TokenIter {
+    type Item = Token;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.range.next() {
+            Some(i) => Some(self.data.tokens[i].clone()),
+            None => {
+                let (data, range) = self.rest.as_mut()?;
+                range.next().map(|i| data.tokens[i].clone())
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.range.len() + self.rest.as_ref().map_or(0, |(_, r)| r.len());
+        (len, Some(len))
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for TokenIter {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some((data, range)) = self.rest.as_mut() {
+            if let Some(i) = range.next_back() {
+                return Some(data.tokens[i].clone());
+            }
+        }
+        self.range.next_back().map(|i| self.data.tokens[i].clone())
+    }
+}
+
+impl ExactSizeIterator for TokenIter {}
 
 #[derive(Clone, Debug, Default)]
 pub struct TokenStream {
-    data: Cow<'static, Vec<Token>>,
+    data: Arc<Storage>,
+    /// 当前标记流在共享的标记序列中的范围，切分标记流时不复制标记。
+    range: Range<usize>,
+    /// 插入或替换过标记时，`data`只存储开头的一段标记，其余标记在这里。
+    rest: Option<Arc<Rest>>,
+    /// 从同一个输入切分出的标记流共享的期望记录，手动构造的标记流不记录。
+    expectation: Option<Arc<Mutex<Expectation>>>,
+    /// 恢复模式下记录的语法错误，`None`表示没有启用恢复模式。
+    diagnostics: Option<Arc<Mutex<Vec<Expectation>>>>,
+    /// 解析时使用的记录器，`None`表示不记录。
+    sink: Option<Arc<dyn NodeSink>>,
+    limits: Arc<Limits>,
+    options: ParseOptions,
 }
 
 impl TokenStream {
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.input_len() == 0
+    }
+
+    /// 获取第一个标记（如果有）。
+    #[inline]
+    pub fn first(&self) -> Option<&Token> {
+        self.as_slice().first()
+    }
+
+    /// 获取第一个标记的位置信息（如果有）。
+    #[inline]
+    pub fn info(&self) -> Option<&TokenInfo> {
+        self.as_info().first()
+    }
+
+    /// 获取第一个标记前面被去掉的普通注释，没有标记时是末尾的注释。
+    ///
+    /// 注释只在调用[`TokenStream::without_comments`]之后才会被记录。
+    #[inline]
+    pub fn comments(&self) -> &[(Token, TokenInfo)] {
+        self.comments_at(0)
     }
 
+    /// 获取第`index`个标记前面被去掉的普通注释，`index`等于标记数量时是末尾的注释。
     #[inline]
+    pub(crate) fn comments_at(&self, index: usize) -> &[(Token, TokenInfo)] {
+        if index > self.input_len() {
+            return &[];
+        }
+        let (data, index) = match &self.rest {
+            Some(rest) if index >= self.own_len() => {
+                (&rest.data, rest.range.start + index - self.own_len())
+            }
+            _ => (&self.data, self.range.start + index),
+        };
+        data.comments.get(index).map_or(&[], Vec::as_slice)
+    }
+
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(input: &str) -> IResult<&str, Self> {
-        let (remaining, out) = many0(one_token).parse(input)?;
+        Self::tokenize(input, None)
+    }
+
+    /// 对源代码进行词法分析，和[`TokenStream::from_str`]不同，失败时返回带有出错位置的[`ParseError`]，
+    /// 可以用[`crate::Renderer`]输出。
+    pub fn from_source(source: &str) -> Result<Self, ParseError> {
+        match Self::from_str(source) {
+            Ok((_, tokens)) => Ok(tokens),
+            Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(lexical_error(source, &e)),
+            Err(nom::Err::Incomplete(_)) => Err(ParseError::lexical(source, source.len())),
+        }
+    }
+
+    /// 对`input`进行词法分析，跳过无法识别的字符和有错误的字面量而不是失败，同时返回这些错误。
+    pub(crate) fn from_str_recovering(input: &str) -> (Self, Vec<ParseError>) {
+        let mut skipped = Vec::new();
+        match Self::tokenize(input, Some(&mut skipped)) {
+            Ok((_, tokens)) => (tokens, skipped),
+            Err(_) => (Default::default(), skipped),
+        }
+    }
+
+    fn tokenize<'s>(
+        input: &'s str,
+        mut skipped: Option<&mut Vec<ParseError>>,
+    ) -> IResult<&'s str, Self> {
+        let mut data = Vec::new();
+        let mut info = Vec::new();
+        let mut remaining = input;
+        loop {
+            let trimmed = remaining.trim_start_matches([' ', '\t', '\r', '\n']);
+            let newline_before = remaining[..remaining.len() - trimmed.len()].contains('\n');
+            let (rest, token) = match one_token(remaining) {
+                Ok(r) => r,
+                Err(_) if trimmed.is_empty() => break,
+                Err(e) => match (skipped.as_deref_mut(), trimmed.chars().next()) {
+                    (Some(skipped), Some(c)) => {
+                        let skip = match &e {
+                            // 有错误的字面量整个跳过，见[`lexical_error`]
+                            nom::Err::Failure(e)
+                                if matches!(e.code, ErrorKind::TooLarge | ErrorKind::Char) =>
+                            {
+                                skipped.push(lexical_error(input, e));
+                                e.input.len()
+                            }
+                            _ => {
+                                skipped
+                                    .push(ParseError::lexical(input, input.len() - trimmed.len()));
+                                c.len_utf8()
+                            }
+                        };
+                        remaining = &trimmed[skip..];
+                        continue;
+                    }
+                    _ if matches!(e, nom::Err::Error(_)) => break,
+                    _ => return Err(e),
+                },
+            };
+            let start = input.len() - trimmed.len();
+            data.push(token);
+            info.push(TokenInfo {
+                span: start..input.len() - rest.len(),
+                newline_before,
+            });
+            remaining = rest;
+        }
         if remaining.trim_end().is_empty() {
             return Ok((
                 remaining,
                 Self {
-                    data: Cow::Owned(out),
+                    range: 0..data.len(),
+                    data: Arc::new(Storage {
+                        tokens: data,
+                        info,
+                        comments: Vec::new(),
+                    }),
+                    expectation: Some(Default::default()),
+                    ..Default::default()
                 },
             ));
         }
@@ -33,42 +295,429 @@ impl TokenStream {
     #[inline]
     pub fn from_vec(data: Vec<Token>) -> Self {
         Self {
-            data: Cow::Owned(data),
+            range: 0..data.len(),
+            data: Arc::new(Storage {
+                tokens: data,
+                ..Default::default()
+            }),
+            ..Default::default()
         }
     }
 
+    /// 切分出`range`范围内的标记，位置信息、注释和解析选项随之保留，标记本身不会被复制。
+    /// 超出当前长度的范围会被截断，而不是导致panic。
+    fn slice(&self, range: Range<usize>) -> Self {
+        let mut stream = self.clone();
+        let end = range.end.min(self.input_len());
+        let start = range.start.min(end);
+        let own = self.own_len();
+        match stream.rest.take() {
+            // 已经越过单独存储的部分，之后只使用共享的标记序列
+            Some(rest) if start >= own => {
+                stream.data = rest.data.clone();
+                stream.range = rest.range.start + start - own..rest.range.start + end - own;
+            }
+            Some(rest) if end > own => {
+                let range = rest.range.start..rest.range.start + end - own;
+                let overlap = rest.overlap.min(range.len());
+                stream.range = self.range.start + start..self.range.start + own + overlap;
+                stream.rest = match range == rest.range {
+                    true => Some(rest),
+                    false => Some(Arc::new(Rest {
+                        data: rest.data.clone(),
+                        range,
+                        overlap,
+                    })),
+                };
+            }
+            _ => stream.range = self.range.start + start..self.range.start + end,
+        }
+        stream
+    }
+
+    /// 单独存储在`data`中的标记数量，不包括向后查看用的副本。
     #[inline]
-    fn from_slice(data: &[Token]) -> Self {
+    fn own_len(&self) -> usize {
+        self.range.len() - self.rest.as_ref().map_or(0, |r| r.overlap)
+    }
+
+    /// 用`token`代替从第`index`个标记开始的`replaced`个标记，`replaced`为0时是插入，`info`是它的位置信息。
+    ///
+    /// 新的标记流只复制`index`之前的标记和之后的[`LOOKAHEAD`]个标记，其余标记仍然共享，
+    /// 所以代价只和`index`有关，和剩余的标记数量无关。
+    fn patch(&self, index: usize, replaced: usize, token: Token, info: Option<TokenInfo>) -> Self {
+        let tail = self.slice(index + replaced..self.input_len());
+        let (copied, rest) = match &tail.rest {
+            // 剩余部分本身也插入过标记时，复制它单独存储的全部标记
+            Some(rest) => (tail.range.len(), Some(rest.clone())),
+            None if tail.is_empty() => (0, None),
+            None => {
+                let overlap = tail.input_len().min(LOOKAHEAD);
+                let range = tail.range.clone();
+                let data = tail.data.clone();
+                (
+                    overlap,
+                    Some(Arc::new(Rest {
+                        data,
+                        range,
+                        overlap,
+                    })),
+                )
+            }
+        };
+        let mut storage = Storage::default();
+        (0..index).for_each(|i| storage.push(self, i));
+        storage.tokens.push(token);
+        storage.info.extend(info);
+        storage.comments.push(match replaced {
+            0 => Vec::new(),
+            _ => self.comments_at(index).to_vec(),
+        });
+        (0..copied).for_each(|i| storage.push(&tail, i));
+        if rest.is_none() {
+            storage.comments.push(tail.comments().to_vec());
+        }
+        self.with_storage(storage, rest)
+    }
+
+    /// 用新的标记、位置信息和注释创建一个标记流，共享的记录和解析选项保持不变。
+    fn with_data(
+        &self,
+        data: Vec<Token>,
+        info: Vec<TokenInfo>,
+        comments: Vec<Vec<(Token, TokenInfo)>>,
+    ) -> Self {
+        let storage = Storage {
+            tokens: data,
+            info,
+            comments,
+        };
+        self.with_storage(storage, None)
+    }
+
+    /// 用新的标记序列创建一个标记流，`rest`是之后仍然共享的剩余标记。
+    fn with_storage(&self, storage: Storage, rest: Option<Arc<Rest>>) -> Self {
         Self {
-            data: Cow::Owned(data.to_owned()),
+            range: 0..storage.tokens.len(),
+            data: Arc::new(storage),
+            rest,
+            expectation: self.expectation.clone(),
+            diagnostics: self.diagnostics.clone(),
+            sink: self.sink.clone(),
+            limits: self.limits.clone(),
+            options: self.options,
         }
     }
+
+    /// 设置解析选项，后续从该标记流切分出的标记流都会沿用这些选项。
+    pub fn with_options(mut self, options: ParseOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// 获取解析选项。
+    #[inline]
+    pub fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// 去掉所有普通注释和文档注释，语法分析器不直接处理注释。
+    ///
+    /// 去掉的注释记录在它后面的标记上，可以通过[`TokenStream::comments`]获取。
+    /// 文档注释是否属于后面的声明由语法分析器在解析声明时决定。
+    pub fn without_comments(&self) -> Self {
+        let mut data = Vec::new();
+        let mut info = Vec::new();
+        let mut comments = Vec::new();
+        let mut pending = Vec::new();
+        for (i, token) in self.iter().enumerate() {
+            pending.extend(self.comments_at(i).iter().cloned());
+            let token_info = self.info_at(i).cloned().unwrap_or_default();
+            if token.is_comment() {
+                pending.push((token.clone(), token_info));
+                continue;
+            }
+            data.push(token.clone());
+            if !self.data.info.is_empty() {
+                info.push(token_info);
+            }
+            comments.push(take(&mut pending));
+        }
+        pending.extend(self.comments_at(self.input_len()).iter().cloned());
+        comments.push(pending);
+
+        self.with_data(data, info, comments)
+    }
+
+    /// 获取开头连续存储的标记，用于向后查看。
+    ///
+    /// 插入或替换过标记（见[`TokenStream::insert`]）的标记流只返回开头的一部分，
+    /// 其中至少包含插入的标记之后的[`LOOKAHEAD`]个标记，遍历所有标记时使用[`TokenStream::iter`]。
+    #[inline]
+    pub(crate) fn as_slice(&self) -> &[Token] {
+        &self.data.tokens[self.range.clone()]
+    }
+
+    /// 获取[`TokenStream::as_slice`]中的标记的位置信息，手动构造的标记流没有位置信息。
+    #[inline]
+    pub(crate) fn as_info(&self) -> &[TokenInfo] {
+        self.data.info.get(self.range.clone()).unwrap_or_default()
+    }
+
+    /// 遍历所有标记。
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Token> {
+        let rest = self
+            .rest
+            .as_ref()
+            .map_or(&[][..], |r| &r.data.tokens[r.range.clone()]);
+        self.data.tokens[self.range.start..self.range.start + self.own_len()]
+            .iter()
+            .chain(rest)
+    }
+
+    /// 获取第`index`个标记。
+    pub(crate) fn get(&self, index: usize) -> Option<&Token> {
+        match &self.rest {
+            Some(rest) if index >= self.own_len() => {
+                rest.data.tokens[rest.range.clone()].get(index - self.own_len())
+            }
+            _ => self.as_slice().get(index),
+        }
+    }
+
+    /// 获取第`index`个标记的位置信息，手动构造的标记流没有位置信息。
+    pub(crate) fn info_at(&self, index: usize) -> Option<&TokenInfo> {
+        match &self.rest {
+            Some(rest) if index >= self.own_len() => rest
+                .data
+                .info
+                .get(rest.range.clone())?
+                .get(index - self.own_len()),
+            _ => self.as_info().get(index),
+        }
+    }
+
+    /// 记录解析在第`index`个标记处失败时期望的内容，只保留最靠后的失败位置上的期望。
+    pub(crate) fn expect(&self, index: usize, expected: impl Display) {
+        let Some(mut expectation) = self.expectation.as_ref().and_then(|e| e.lock().ok()) else {
+            return;
+        };
+        let remaining = self.input_len().saturating_sub(index);
+        match expectation.expected.is_empty() {
+            false if expectation.remaining < remaining => return,
+            false if expectation.remaining == remaining => {}
+            _ => {
+                *expectation = Expectation {
+                    remaining,
+                    expected: Vec::new(),
+                    found: self
+                        .get(index)
+                        .map(|t| (t.clone(), self.info_at(index).cloned())),
+                    missing: Vec::new(),
+                }
+            }
+        }
+        let expected = expected.to_string();
+        if !expectation.expected.contains(&expected) {
+            expectation.expected.push(expected);
+        }
+    }
+
+    /// 获取记录的期望，没有记录时返回`None`。
+    pub(crate) fn expectation(&self) -> Option<Expectation> {
+        let expectation = self.expectation.as_ref()?.lock().ok()?;
+        (!expectation.expected.is_empty()).then(|| expectation.clone())
+    }
+
+    /// 进入一层嵌套，嵌套层数超过[`ParseOptions::max_depth`]时记录超过的限制并返回`Failure`。
+    /// 返回的守卫离开作用域时退出这一层嵌套。
+    pub(crate) fn enter(&self) -> Result<DepthGuard, nom::Err<Error<Self>>> {
+        let depth = self.limits.depth.fetch_add(1, Ordering::Relaxed) + 1;
+        let guard = DepthGuard(self.limits.clone());
+        if depth > self.options.max_depth {
+            return Err(self.exceed(ParseLimit::Depth(self.options.max_depth), 0));
+        }
+        Ok(guard)
+    }
+
+    /// 记录在第`index`个标记处超过了`limit`，返回用于终止解析的`Failure`。
+    pub(crate) fn exceed(&self, limit: ParseLimit, index: usize) -> nom::Err<Error<Self>> {
+        self.fail(ParseErrorKind::Limit(limit), index)
+    }
+
+    /// 记录第`index`个标记处的终止解析的错误，返回用于终止解析的`Failure`，例如超出范围的字面量。
+    pub(crate) fn fail(&self, kind: ParseErrorKind, index: usize) -> nom::Err<Error<Self>> {
+        if let Ok(mut fatal) = self.limits.fatal.lock() {
+            fatal.get_or_insert_with(|| (kind, self.info_at(index).cloned()));
+        }
+        nom::Err::Failure(Error::new(self.clone(), ErrorKind::TooLarge))
+    }
+
+    /// 获取最先出现的终止解析的错误和出错的位置，没有时返回`None`。
+    pub(crate) fn fatal(&self) -> Option<(ParseErrorKind, Option<TokenInfo>)> {
+        self.limits.fatal.lock().ok()?.clone()
+    }
+
+    /// 清除记录的期望，用于在恢复模式下单独记录每个节点的失败位置。
+    pub(crate) fn reset_expectation(&self) {
+        if let Some(mut expectation) = self.expectation.as_ref().and_then(|e| e.lock().ok()) {
+            *expectation = Default::default();
+        }
+    }
+
+    /// 启用恢复模式，后续从该标记流切分出的标记流共享同一份语法错误记录。
+    pub(crate) fn recovering(mut self) -> Self {
+        self.diagnostics = Some(Default::default());
+        self
+    }
+
+    /// 是否启用了恢复模式。
+    #[inline]
+    pub(crate) fn is_recovering(&self) -> bool {
+        self.diagnostics.is_some()
+    }
+
+    /// 在恢复模式下记录一个语法错误，同一位置上相同的错误只记录一次。
+    pub(crate) fn report(&self, error: Expectation) {
+        let Some(mut diagnostics) = self.diagnostics.as_ref().and_then(|d| d.lock().ok()) else {
+            return;
+        };
+        // 输入结束处的错误排在最后
+        let span = |e: &Expectation| {
+            e.found
+                .as_ref()
+                .map_or(Some(usize::MAX), |(_, i)| i.as_ref().map(|i| i.span.start))
+        };
+        // 错误基本按位置顺序记录，只需要检查末尾位置不靠前的错误
+        if !diagnostics
+            .iter()
+            .rev()
+            .take_while(|d| span(d) >= span(&error))
+            .any(|d| span(d) == span(&error) && d.expected == error.expected)
+        {
+            diagnostics.push(error);
+        }
+    }
+
+    /// 获取恢复模式下记录的所有语法错误。
+    pub(crate) fn diagnostics(&self) -> Vec<Expectation> {
+        self.diagnostics
+            .as_ref()
+            .and_then(|d| d.lock().ok())
+            .map_or_else(Vec::new, |d| d.clone())
+    }
+
+    /// 获取恢复模式下记录的语法错误的数量，用于在尝试之前记录检查点。
+    pub(crate) fn diagnostics_len(&self) -> usize {
+        self.diagnostics
+            .as_ref()
+            .and_then(|d| d.lock().ok())
+            .map_or(0, |d| d.len())
+    }
+
+    /// 丢弃第`len`个之后记录的语法错误，用于撤销一次失败的尝试。
+    pub(crate) fn truncate_diagnostics(&self, len: usize) {
+        if let Some(mut diagnostics) = self.diagnostics.as_ref().and_then(|d| d.lock().ok()) {
+            diagnostics.truncate(len);
+        }
+    }
+
+    /// 开始使用`sink`记录，后续从该标记流切分出的标记流共享同一个记录器，用于构建具体语法树。
+    pub(crate) fn recording(mut self, sink: Arc<dyn NodeSink>) -> Self {
+        self.sink = Some(sink);
+        self
+    }
+
+    /// 获取类型为`T`的记录器，没有使用记录器或类型不同时返回`None`。
+    pub(crate) fn sink<T: 'static>(&self) -> Option<&T> {
+        self.sink.as_ref()?.as_any().downcast_ref()
+    }
+
+    /// 获取第一个标记在源代码中的字节偏移，没有标记时是最后一个标记的结束位置。
+    ///
+    /// 只在使用记录器时有意义，没有记录器或没有位置信息时返回`usize::MAX`。
+    pub(crate) fn source_offset(&self) -> usize {
+        if self.sink.is_none() {
+            return usize::MAX;
+        }
+        match self.data.info.get(self.range.start) {
+            Some(info) => info.span.start,
+            None => self.data.info.last().map_or(usize::MAX, |i| i.span.end),
+        }
+    }
+
+    /// 通知记录器撤销该标记流开头之后的记录，在消费标记之前调用，撤销回溯前失败的尝试。
+    #[inline]
+    fn rewind(&self) {
+        if let Some(sink) = &self.sink {
+            sink.rewind(self.source_offset());
+        }
+    }
+
+    /// 在第`index`个标记之前插入一个源代码中缺失的标记，它的位置是一个空范围。
+    ///
+    /// 插入后的标记流和原来的标记流共享插入位置之后的标记，见[`TokenStream::patch`]。
+    pub(crate) fn insert(&self, index: usize, token: Token) -> Self {
+        let index = index.min(self.input_len());
+        let info = (!self.data.info.is_empty()).then(|| {
+            let at = match self.info_at(index) {
+                Some(i) => i.span.start,
+                None => index
+                    .checked_sub(1)
+                    .and_then(|i| self.info_at(i))
+                    .map_or(0, |i| i.span.end),
+            };
+            TokenInfo {
+                span: at..at,
+                newline_before: false,
+            }
+        });
+        self.patch(index, 0, token, info)
+    }
+
+    /// 用`token`替换第一个标记，用于把`>>`这样的复合标记拆开逐个消费。
+    ///
+    /// 替换后的标记的位置是原标记末尾的对应部分，例如`>>`中的第二个`>`。
+    pub(crate) fn replace_first(&self, token: Token) -> Self {
+        if self.is_empty() {
+            return self.clone();
+        }
+        let info = self.info().map(|first| {
+            let len = token.to_string().len();
+            TokenInfo {
+                span: first.span.end.saturating_sub(len).max(first.span.start)..first.span.end,
+                newline_before: false,
+            }
+        });
+        self.patch(0, 1, token, info)
+    }
 }
 
 impl Input for TokenStream {
     type Item = Token;
-    type Iter = IntoIter<Token>;
+    type Iter = TokenIter;
     type IterIndices = Enumerate<Self::Iter>;
 
     #[inline]
     fn input_len(&self) -> usize {
-        self.data.len()
+        self.own_len() + self.rest.as_ref().map_or(0, |r| r.range.len())
     }
 
     #[inline]
     fn take(&self, index: usize) -> Self {
-        Self::from_slice(&self.data[..index])
+        self.slice(0..index)
     }
 
     #[inline]
     fn take_from(&self, index: usize) -> Self {
-        Self::from_slice(&self.data[index..])
+        self.rewind();
+        self.slice(index..self.input_len())
     }
 
     #[inline]
     fn take_split(&self, index: usize) -> (Self, Self) {
-        let (prefix, suffix) = self.data.split_at(index);
-        (Self::from_slice(suffix), Self::from_slice(prefix))
+        self.rewind();
+        (self.slice(index..self.input_len()), self.slice(0..index))
     }
 
     #[inline]
@@ -76,7 +725,7 @@ impl Input for TokenStream {
     where
         P: Fn(Self::Item) -> bool,
     {
-        for (i, j) in self.data.iter().enumerate() {
+        for (i, j) in self.iter().enumerate() {
             if predicate(j.clone()) {
                 return Some(i);
             }
@@ -86,40 +735,34 @@ impl Input for TokenStream {
 
     #[inline]
     fn iter_elements(&self) -> Self::Iter {
-        let data = <Vec<_> as Clone>::clone(&self.data);
-        data.into_iter()
+        TokenIter {
+            data: self.data.clone(),
+            range: self.range.start..self.range.start + self.own_len(),
+            rest: self
+                .rest
+                .as_ref()
+                .map(|r| (r.data.clone(), r.range.clone())),
+        }
     }
 
     #[inline]
     fn iter_indices(&self) -> Self::IterIndices {
-        let data = <Vec<_> as Clone>::clone(&self.data);
-        data.into_iter().enumerate()
+        self.iter_elements().enumerate()
     }
 
     #[inline]
     fn slice_index(&self, count: usize) -> Result<usize, Needed> {
-        let mut cnt = 0;
-        for (index, _) in self.data.iter().enumerate() {
-            if cnt == count {
-                return Ok(index);
-            }
-            cnt += 1;
+        match count <= self.input_len() {
+            true => Ok(count),
+            false => Err(Needed::Unknown),
         }
-        if cnt == count {
-            return Ok(self.input_len());
-        }
-        Err(Needed::Unknown)
     }
 }
 
 impl Compare<TokenStream> for TokenStream {
     #[inline]
     fn compare(&self, t: TokenStream) -> CompareResult {
-        let pos = self
-            .data
-            .iter()
-            .zip(t.data.iter())
-            .position(|(a, b)| a != b);
+        let pos = self.iter().zip(t.iter()).position(|(a, b)| a != b);
 
         match pos {
             Some(_) => CompareResult::Error,
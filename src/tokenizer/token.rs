@@ -110,8 +110,10 @@ pub enum Token {
     BooleanLiteral(bool),
     /// 任意字符字面量
     CharLiteral(char),
-    /// 任意有效整数字面量
-    IntegerLiteral(i32),
+    /// 任意有效整数字面量，`2147483648`只能作为一元`-`的操作数
+    IntegerLiteral(u32),
+    /// 带`L`或`l`后缀的长整数字面量，`9223372036854775808L`只能作为一元`-`的操作数
+    LongLiteral(u64),
     /// 任意双精度小数字面量
     DoubleLiteral(f64),
     /// 任意浮点数字面量
@@ -122,6 +124,12 @@ pub enum Token {
     OctLiteral(u32),
     /// 任意二进制数字
     BinLiteral(u32),
+    /// 带`L`或`l`后缀的十六进制长整数
+    HexLongLiteral(u64),
+    /// 带`L`或`l`后缀的八进制长整数
+    OctLongLiteral(u64),
+    /// 带`L`或`l`后缀的二进制长整数
+    BinLongLiteral(u64),
     /// 任意字符串字面量
     StringLiteral(String),
     /// 空值字面量
@@ -150,10 +158,15 @@ pub enum Token {
     LeftBracket,
     /// 右方括号 `]` (用于数组访问)
     RightBracket,
+    /// At符号 `@` (用于注解)
+    At,
 
     /// Java文档注释
     JavaDoc(String),
 
+    /// Markdown文档注释，由连续的`///`行组成，参数是去掉`///`后以换行符连接的各行内容
+    MarkdownDoc(String),
+
     /// 无符号右移赋值 `>>>=`
     UnsignedShiftRightAssign,
     /// 右移赋值 `>>=`
@@ -300,6 +313,7 @@ impl Token {
     pub(crate) const RIGHT_BRACE: &'static str = "}";
     pub(crate) const LEFT_BRACKET: &'static str = "[";
     pub(crate) const RIGHT_BRACKET: &'static str = "]";
+    pub(crate) const AT: &'static str = "@";
 
     pub(crate) const UNSIGNED_SHIFT_RIGHT_ASSIGN: &'static str = ">>>=";
     pub(crate) const UNSIGNED_SHIFT_RIGHT: &'static str = ">>>";
@@ -442,10 +456,14 @@ impl Token {
             self,
             Self::BooleanLiteral(_)
                 | Self::IntegerLiteral(_)
+                | Self::LongLiteral(_)
                 | Self::BinLiteral(_)
                 | Self::FloatLiteral(_)
                 | Self::HexLiteral(_)
                 | Self::OctLiteral(_)
+                | Self::HexLongLiteral(_)
+                | Self::OctLongLiteral(_)
+                | Self::BinLongLiteral(_)
                 | Self::CharLiteral(_)
                 | Self::StringLiteral(_)
                 | Self::NullLiteral
@@ -456,12 +474,22 @@ impl Token {
         matches!(self, Self::IntegerLiteral(_))
     }
 
+    pub fn is_long(&self) -> bool {
+        matches!(
+            self,
+            Self::LongLiteral(_)
+                | Self::HexLongLiteral(_)
+                | Self::OctLongLiteral(_)
+                | Self::BinLongLiteral(_)
+        )
+    }
+
     pub fn is_octal(&self) -> bool {
-        matches!(self, Self::OctLiteral(_))
+        matches!(self, Self::OctLiteral(_) | Self::OctLongLiteral(_))
     }
 
     pub fn is_binary(&self) -> bool {
-        matches!(self, Self::BinLiteral(_))
+        matches!(self, Self::BinLiteral(_) | Self::BinLongLiteral(_))
     }
 
     pub fn is_double(&self) -> bool {
@@ -473,7 +501,7 @@ impl Token {
     }
 
     pub fn is_hex(&self) -> bool {
-        matches!(self, Self::HexLiteral(_))
+        matches!(self, Self::HexLiteral(_) | Self::HexLongLiteral(_))
     }
 
     pub fn is_boolean(&self) -> bool {
@@ -493,7 +521,11 @@ impl Token {
     }
 
     pub fn is_documentation(&self) -> bool {
-        matches!(self, Self::JavaDoc(_))
+        matches!(self, Self::JavaDoc(_) | Self::MarkdownDoc(_))
+    }
+
+    pub fn is_comment(&self) -> bool {
+        matches!(self, Self::Comment { .. }) || self.is_documentation()
     }
 
     pub fn is_separator(&self) -> bool {
@@ -508,6 +540,7 @@ impl Token {
                 | Self::RightBrace
                 | Self::LeftBracket
                 | Self::RightBracket
+                | Self::At
         )
     }
 
@@ -563,7 +596,7 @@ impl Token {
     }
 
     pub fn is_annotation(&self) -> bool {
-        false // 需要自定义解析逻辑
+        &Self::At == self
     }
 
     pub fn is_identifier(&self) -> bool {
@@ -631,6 +664,7 @@ impl From<&str> for Token {
             Self::RIGHT_BRACE => Self::RightBrace,
             Self::LEFT_BRACKET => Self::LeftBracket,
             Self::RIGHT_BRACKET => Self::RightBracket,
+            Self::AT => Self::At,
             Self::LEFT_PAREN => Self::LeftParen,
             Self::RIGHT_PAREN => Self::RightParen,
             Self::UNSIGNED_SHIFT_RIGHT_ASSIGN => Self::UnsignedShiftRightAssign,
@@ -739,14 +773,18 @@ impl Display for Token {
                 single_line: false,
             } => return write!(f, "/*{}*/", text),
             Self::Identifier(i) => i.as_str(),
-            Self::CharLiteral(c) => return write!(f, "'{}'", c),
+            Self::CharLiteral(c) => return write_char_literal(f, *c),
             Self::BooleanLiteral(b) => return write!(f, "{}", b),
             Self::IntegerLiteral(i) => return write!(f, "{}", i),
+            Self::LongLiteral(i) => return write!(f, "{}L", i),
             Self::DoubleLiteral(i) => return write!(f, "{}", i),
             Self::FloatLiteral(i) => return write!(f, "{}f", i),
             Self::HexLiteral(h) => return write!(f, "0x{:x}", h),
             Self::OctLiteral(o) => return write!(f, "0{:o}", o),
             Self::BinLiteral(b) => return write!(f, "0b{:b}", b),
+            Self::HexLongLiteral(h) => return write!(f, "0x{:x}L", h),
+            Self::OctLongLiteral(o) => return write!(f, "0{:o}L", o),
+            Self::BinLongLiteral(b) => return write!(f, "0b{:b}L", b),
             Self::StringLiteral(s) => return write!(f, "\"{}\"", s),
             Self::NullLiteral => Self::NULL,
             Self::ShiftRight => Self::SHIFT_RIGHT,
@@ -760,7 +798,17 @@ impl Display for Token {
             Self::RightBrace => Self::RIGHT_BRACE,
             Self::LeftBracket => Self::LEFT_BRACKET,
             Self::RightBracket => Self::RIGHT_BRACKET,
+            Self::At => Self::AT,
             Self::JavaDoc(s) => return write!(f, "/**{}*/", s),
+            Self::MarkdownDoc(s) => {
+                for (i, line) in s.split('\n').enumerate() {
+                    if i > 0 {
+                        writeln!(f)?;
+                    }
+                    write!(f, "///{}", line)?;
+                }
+                return Ok(());
+            }
             Self::UnsignedShiftRightAssign => Self::UNSIGNED_SHIFT_RIGHT_ASSIGN,
             Self::ShiftRightAssign => Self::SHIFT_RIGHT_ASSIGN,
             Self::ShiftLeftAssign => Self::SHIFT_LEFT_ASSIGN,
@@ -803,3 +851,19 @@ impl Display for Token {
         write!(f, "{}", text)
     }
 }
+
+/// 按Java的转义规则输出字符字面量，例如`'\n'`、`'\''`。
+pub(crate) fn write_char_literal(f: &mut Formatter<'_>, c: char) -> std::fmt::Result {
+    let escaped = match c {
+        '\u{8}' => "\\b",
+        '\t' => "\\t",
+        '\n' => "\\n",
+        '\u{c}' => "\\f",
+        '\r' => "\\r",
+        '\'' => "\\'",
+        '\\' => "\\\\",
+        c if c.is_control() => return write!(f, "'\\u{:04x}'", c as u32),
+        c => return write!(f, "'{}'", c),
+    };
+    write!(f, "'{}'", escaped)
+}
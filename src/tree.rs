@@ -1,4 +1,8 @@
+mod fold;
 mod node;
+mod options;
 mod parser;
+mod visit;
+mod visit_mut;
 
-pub use {node::*, parser::*};
+pub use {fold::*, node::*, options::*, parser::*, visit::*, visit_mut::*};
@@ -0,0 +1,2214 @@
+use super::{
+    Annotation, AnnotationDeclaration, AnnotationElement, AnnotationMethod, ArrayInitializer,
+    Block, CatchClause, CatchClauseParameter, ClassBody, ClassBodyDeclaration, ClassDeclaration,
+    Comment, Comments, CompilationUnitDeclaration, ConstructorBody, ConstructorDeclaration,
+    DocumentationComment, ElementValue, ElementValuePair, EnumConstantDeclaration, EnumDeclaration,
+    ErrorNode, ExplicitConstructorInvocation, Expression, FieldDeclaration, ForControl, ForInit,
+    FormalParameter, ImportDeclaration, Initializer, InstanceOfTarget, InterfaceDeclaration,
+    LambdaBody, LambdaParameters, Literal, LocalVariableDeclaration, MethodDeclaration,
+    MethodReferenceTarget, ModuleDeclaration, ModuleDirective, PackageDeclaration, Pattern,
+    RecordComponent, RecordDeclaration, ReferenceType, Statement, Switch, SwitchBody, SwitchGroup,
+    SwitchLabel, SwitchRule, SwitchRuleBody, TopLevelClassOrInterfaceDeclaration, TryResource,
+    TryStatement, Type, TypeArgument, TypeParameter, VariableDeclarator, VariableInitializer,
+};
+
+/// Fold消耗语法树中的节点并返回新的节点，每种节点类型对应一个`fold_*`方法，适合以函数式的方式编写代码改写工具。
+///
+/// 每个方法的默认实现调用同名的`fold_*`函数，依次折叠节点的子节点（包括关联的注释），
+/// 然后用折叠的结果组成新的节点；没有子节点的节点原样返回。
+/// 实现者只需覆盖关心的方法，不调用对应的`fold_*`函数则保留整个子树不变。
+/// 多个折叠器可以用[`Fold::then`]组合起来。
+///
+/// # 示例
+/// ```
+/// use java_lang::{parse_str, Fold, ImportDeclaration, PackageDeclaration};
+///
+/// /// 把包`com.old`及其子包改名为`com.new`。
+/// struct RenamePackage;
+///
+/// impl<'a> Fold<'a> for RenamePackage {
+///     fn fold_package_declaration(&mut self, node: PackageDeclaration<'a>) -> PackageDeclaration<'a> {
+///         PackageDeclaration {
+///             name: node.name.replacen("com.old", "com.new", 1).into(),
+///             ..node
+///         }
+///     }
+///
+///     fn fold_import_declaration(&mut self, node: ImportDeclaration<'a>) -> ImportDeclaration<'a> {
+///         match node {
+///             ImportDeclaration::SimpleType(name) if name.starts_with("com.old.") => {
+///                 ImportDeclaration::SimpleType(name.replacen("com.old", "com.new", 1).into())
+///             }
+///             node => node,
+///         }
+///     }
+/// }
+///
+/// /// 去掉`java.lang`中的类型的导入。
+/// struct RemoveJavaLang;
+///
+/// impl<'a> Fold<'a> for RemoveJavaLang {
+///     fn fold_import_declaration(&mut self, node: ImportDeclaration<'a>) -> ImportDeclaration<'a> {
+///         match node {
+///             ImportDeclaration::SimpleType(name) if name.starts_with("java.lang.") => {
+///                 ImportDeclaration::TypeOnDemand("java.lang".into())
+///             }
+///             node => node,
+///         }
+///     }
+/// }
+///
+/// let unit = parse_str("package com.old.app;\nimport com.old.Util;\nimport java.lang.String;\nclass A {}").unwrap();
+/// let unit = RenamePackage
+///     .then(RemoveJavaLang)
+///     .fold_compilation_unit_declaration(unit);
+/// assert_eq!(unit.package().unwrap().name, "com.new.app");
+/// assert_eq!(
+///     unit.imports(),
+///     [
+///         ImportDeclaration::SimpleType("com.new.Util".into()),
+///         ImportDeclaration::TypeOnDemand("java.lang".into()),
+///     ]
+/// );
+/// ```
+pub trait Fold<'a> {
+    /// 折叠编译单元。
+    fn fold_compilation_unit_declaration(
+        &mut self,
+        node: CompilationUnitDeclaration<'a>,
+    ) -> CompilationUnitDeclaration<'a> {
+        fold_compilation_unit_declaration(self, node)
+    }
+
+    /// 折叠包声明。
+    fn fold_package_declaration(&mut self, node: PackageDeclaration<'a>) -> PackageDeclaration<'a> {
+        fold_package_declaration(self, node)
+    }
+
+    /// 折叠导入声明。
+    fn fold_import_declaration(&mut self, node: ImportDeclaration<'a>) -> ImportDeclaration<'a> {
+        node
+    }
+
+    /// 折叠模块声明。
+    fn fold_module_declaration(&mut self, node: ModuleDeclaration<'a>) -> ModuleDeclaration<'a> {
+        fold_module_declaration(self, node)
+    }
+
+    /// 折叠模块指令。
+    fn fold_module_directive(&mut self, node: ModuleDirective<'a>) -> ModuleDirective<'a> {
+        node
+    }
+
+    /// 折叠顶层类或接口声明。
+    fn fold_top_level_class_or_interface_declaration(
+        &mut self,
+        node: TopLevelClassOrInterfaceDeclaration<'a>,
+    ) -> TopLevelClassOrInterfaceDeclaration<'a> {
+        fold_top_level_class_or_interface_declaration(self, node)
+    }
+
+    /// 折叠类声明。
+    fn fold_class_declaration(&mut self, node: ClassDeclaration<'a>) -> ClassDeclaration<'a> {
+        fold_class_declaration(self, node)
+    }
+
+    /// 折叠接口声明。
+    fn fold_interface_declaration(
+        &mut self,
+        node: InterfaceDeclaration<'a>,
+    ) -> InterfaceDeclaration<'a> {
+        fold_interface_declaration(self, node)
+    }
+
+    /// 折叠枚举声明。
+    fn fold_enum_declaration(&mut self, node: EnumDeclaration<'a>) -> EnumDeclaration<'a> {
+        fold_enum_declaration(self, node)
+    }
+
+    /// 折叠枚举常量声明。
+    fn fold_enum_constant_declaration(
+        &mut self,
+        node: EnumConstantDeclaration<'a>,
+    ) -> EnumConstantDeclaration<'a> {
+        fold_enum_constant_declaration(self, node)
+    }
+
+    /// 折叠记录声明。
+    fn fold_record_declaration(&mut self, node: RecordDeclaration<'a>) -> RecordDeclaration<'a> {
+        fold_record_declaration(self, node)
+    }
+
+    /// 折叠记录组件。
+    fn fold_record_component(&mut self, node: RecordComponent<'a>) -> RecordComponent<'a> {
+        fold_record_component(self, node)
+    }
+
+    /// 折叠注解接口声明。
+    fn fold_annotation_declaration(
+        &mut self,
+        node: AnnotationDeclaration<'a>,
+    ) -> AnnotationDeclaration<'a> {
+        fold_annotation_declaration(self, node)
+    }
+
+    /// 折叠类型参数。
+    fn fold_type_parameter(&mut self, node: TypeParameter<'a>) -> TypeParameter<'a> {
+        fold_type_parameter(self, node)
+    }
+
+    /// 折叠类体。
+    fn fold_class_body(&mut self, node: ClassBody<'a>) -> ClassBody<'a> {
+        fold_class_body(self, node)
+    }
+
+    /// 折叠类体中的声明。
+    fn fold_class_body_declaration(
+        &mut self,
+        node: ClassBodyDeclaration<'a>,
+    ) -> ClassBodyDeclaration<'a> {
+        fold_class_body_declaration(self, node)
+    }
+
+    /// 折叠字段声明。
+    fn fold_field_declaration(&mut self, node: FieldDeclaration<'a>) -> FieldDeclaration<'a> {
+        fold_field_declaration(self, node)
+    }
+
+    /// 折叠方法声明。
+    fn fold_method_declaration(&mut self, node: MethodDeclaration<'a>) -> MethodDeclaration<'a> {
+        fold_method_declaration(self, node)
+    }
+
+    /// 折叠构造函数声明。
+    fn fold_constructor_declaration(
+        &mut self,
+        node: ConstructorDeclaration<'a>,
+    ) -> ConstructorDeclaration<'a> {
+        fold_constructor_declaration(self, node)
+    }
+
+    /// 折叠初始化块。
+    fn fold_initializer(&mut self, node: Initializer<'a>) -> Initializer<'a> {
+        fold_initializer(self, node)
+    }
+
+    /// 折叠注解接口的元素声明。
+    fn fold_annotation_method(&mut self, node: AnnotationMethod<'a>) -> AnnotationMethod<'a> {
+        fold_annotation_method(self, node)
+    }
+
+    /// 折叠形式参数。
+    fn fold_formal_parameter(&mut self, node: FormalParameter<'a>) -> FormalParameter<'a> {
+        fold_formal_parameter(self, node)
+    }
+
+    /// 折叠变量声明符。
+    fn fold_variable_declarator(&mut self, node: VariableDeclarator<'a>) -> VariableDeclarator<'a> {
+        fold_variable_declarator(self, node)
+    }
+
+    /// 折叠变量初始化器。
+    fn fold_variable_initializer(
+        &mut self,
+        node: VariableInitializer<'a>,
+    ) -> VariableInitializer<'a> {
+        fold_variable_initializer(self, node)
+    }
+
+    /// 折叠数组初始化器。
+    fn fold_array_initializer(&mut self, node: ArrayInitializer<'a>) -> ArrayInitializer<'a> {
+        fold_array_initializer(self, node)
+    }
+
+    /// 折叠代码块。
+    fn fold_block(&mut self, node: Block<'a>) -> Block<'a> {
+        fold_block(self, node)
+    }
+
+    /// 折叠构造函数体。
+    fn fold_constructor_body(&mut self, node: ConstructorBody<'a>) -> ConstructorBody<'a> {
+        fold_constructor_body(self, node)
+    }
+
+    /// 折叠语句。
+    fn fold_statement(&mut self, node: Statement<'a>) -> Statement<'a> {
+        fold_statement(self, node)
+    }
+
+    /// 折叠局部变量声明。
+    fn fold_local_variable_declaration(
+        &mut self,
+        node: LocalVariableDeclaration<'a>,
+    ) -> LocalVariableDeclaration<'a> {
+        fold_local_variable_declaration(self, node)
+    }
+
+    /// 折叠`for`循环的控制部分。
+    fn fold_for_control(&mut self, node: ForControl<'a>) -> ForControl<'a> {
+        fold_for_control(self, node)
+    }
+
+    /// 折叠`for`循环的初始化部分。
+    fn fold_for_init(&mut self, node: ForInit<'a>) -> ForInit<'a> {
+        fold_for_init(self, node)
+    }
+
+    /// 折叠`try`语句。
+    fn fold_try_statement(&mut self, node: TryStatement<'a>) -> TryStatement<'a> {
+        fold_try_statement(self, node)
+    }
+
+    /// 折叠`try`语句的资源。
+    fn fold_try_resource(&mut self, node: TryResource<'a>) -> TryResource<'a> {
+        fold_try_resource(self, node)
+    }
+
+    /// 折叠`catch`子句。
+    fn fold_catch_clause(&mut self, node: CatchClause<'a>) -> CatchClause<'a> {
+        fold_catch_clause(self, node)
+    }
+
+    /// 折叠`catch`子句的参数。
+    fn fold_catch_clause_parameter(
+        &mut self,
+        node: CatchClauseParameter<'a>,
+    ) -> CatchClauseParameter<'a> {
+        fold_catch_clause_parameter(self, node)
+    }
+
+    /// 折叠显式构造函数调用。
+    fn fold_explicit_constructor_invocation(
+        &mut self,
+        node: ExplicitConstructorInvocation<'a>,
+    ) -> ExplicitConstructorInvocation<'a> {
+        fold_explicit_constructor_invocation(self, node)
+    }
+
+    /// 折叠`switch`语句或表达式。
+    fn fold_switch(&mut self, node: Switch<'a>) -> Switch<'a> {
+        fold_switch(self, node)
+    }
+
+    /// 折叠`switch`块。
+    fn fold_switch_body(&mut self, node: SwitchBody<'a>) -> SwitchBody<'a> {
+        fold_switch_body(self, node)
+    }
+
+    /// 折叠`switch`块中的语句组。
+    fn fold_switch_group(&mut self, node: SwitchGroup<'a>) -> SwitchGroup<'a> {
+        fold_switch_group(self, node)
+    }
+
+    /// 折叠`switch`规则。
+    fn fold_switch_rule(&mut self, node: SwitchRule<'a>) -> SwitchRule<'a> {
+        fold_switch_rule(self, node)
+    }
+
+    /// 折叠`switch`规则的主体。
+    fn fold_switch_rule_body(&mut self, node: SwitchRuleBody<'a>) -> SwitchRuleBody<'a> {
+        fold_switch_rule_body(self, node)
+    }
+
+    /// 折叠`switch`标签。
+    fn fold_switch_label(&mut self, node: SwitchLabel<'a>) -> SwitchLabel<'a> {
+        fold_switch_label(self, node)
+    }
+
+    /// 折叠表达式。
+    fn fold_expression(&mut self, node: Expression<'a>) -> Expression<'a> {
+        fold_expression(self, node)
+    }
+
+    /// 折叠字面量。
+    fn fold_literal(&mut self, node: Literal<'a>) -> Literal<'a> {
+        node
+    }
+
+    /// 折叠Lambda表达式的参数列表。
+    fn fold_lambda_parameters(&mut self, node: LambdaParameters<'a>) -> LambdaParameters<'a> {
+        fold_lambda_parameters(self, node)
+    }
+
+    /// 折叠Lambda体。
+    fn fold_lambda_body(&mut self, node: LambdaBody<'a>) -> LambdaBody<'a> {
+        fold_lambda_body(self, node)
+    }
+
+    /// 折叠方法引用中`::`前面的部分。
+    fn fold_method_reference_target(
+        &mut self,
+        node: MethodReferenceTarget<'a>,
+    ) -> MethodReferenceTarget<'a> {
+        fold_method_reference_target(self, node)
+    }
+
+    /// 折叠模式。
+    fn fold_pattern(&mut self, node: Pattern<'a>) -> Pattern<'a> {
+        fold_pattern(self, node)
+    }
+
+    /// 折叠`instanceof`右边的类型或模式。
+    fn fold_instance_of_target(&mut self, node: InstanceOfTarget<'a>) -> InstanceOfTarget<'a> {
+        fold_instance_of_target(self, node)
+    }
+
+    /// 折叠类型。
+    fn fold_type(&mut self, node: Type<'a>) -> Type<'a> {
+        fold_type(self, node)
+    }
+
+    /// 折叠引用类型。
+    fn fold_reference_type(&mut self, node: ReferenceType<'a>) -> ReferenceType<'a> {
+        fold_reference_type(self, node)
+    }
+
+    /// 折叠类型实参。
+    fn fold_type_argument(&mut self, node: TypeArgument<'a>) -> TypeArgument<'a> {
+        fold_type_argument(self, node)
+    }
+
+    /// 折叠注解。
+    fn fold_annotation(&mut self, node: Annotation<'a>) -> Annotation<'a> {
+        fold_annotation(self, node)
+    }
+
+    /// 折叠注解括号中的内容。
+    fn fold_annotation_element(&mut self, node: AnnotationElement<'a>) -> AnnotationElement<'a> {
+        fold_annotation_element(self, node)
+    }
+
+    /// 折叠注解的元素值对。
+    fn fold_element_value_pair(&mut self, node: ElementValuePair<'a>) -> ElementValuePair<'a> {
+        fold_element_value_pair(self, node)
+    }
+
+    /// 折叠注解的元素值。
+    fn fold_element_value(&mut self, node: ElementValue<'a>) -> ElementValue<'a> {
+        fold_element_value(self, node)
+    }
+
+    /// 折叠文档注释。
+    fn fold_documentation_comment(
+        &mut self,
+        node: DocumentationComment<'a>,
+    ) -> DocumentationComment<'a> {
+        node
+    }
+
+    /// 折叠关联到节点的普通注释。
+    fn fold_comments(&mut self, node: Comments<'a>) -> Comments<'a> {
+        fold_comments(self, node)
+    }
+
+    /// 折叠普通注释。
+    fn fold_comment(&mut self, node: Comment<'a>) -> Comment<'a> {
+        node
+    }
+
+    /// 折叠恢复解析时跳过的错误节点。
+    fn fold_error_node(&mut self, node: ErrorNode<'a>) -> ErrorNode<'a> {
+        fold_error_node(self, node)
+    }
+
+    /// 组合两个折叠器：每个节点先由`self`折叠，再把结果交给`next`折叠。
+    fn then<F: Fold<'a>>(self, next: F) -> Chain<Self, F>
+    where
+        Self: Sized,
+    {
+        Chain(self, next)
+    }
+}
+
+/// Chain是由[`Fold::then`]组合起来的两个折叠器。
+///
+/// 每个`fold_*`方法先用第一个折叠器折叠整个节点，再用第二个折叠器折叠得到的结果。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Chain<A, B>(pub A, pub B);
+impl<'a, A: Fold<'a>, B: Fold<'a>> Fold<'a> for Chain<A, B> {
+    fn fold_compilation_unit_declaration(
+        &mut self,
+        node: CompilationUnitDeclaration<'a>,
+    ) -> CompilationUnitDeclaration<'a> {
+        let node = self.0.fold_compilation_unit_declaration(node);
+        self.1.fold_compilation_unit_declaration(node)
+    }
+
+    fn fold_package_declaration(&mut self, node: PackageDeclaration<'a>) -> PackageDeclaration<'a> {
+        let node = self.0.fold_package_declaration(node);
+        self.1.fold_package_declaration(node)
+    }
+
+    fn fold_import_declaration(&mut self, node: ImportDeclaration<'a>) -> ImportDeclaration<'a> {
+        let node = self.0.fold_import_declaration(node);
+        self.1.fold_import_declaration(node)
+    }
+
+    fn fold_module_declaration(&mut self, node: ModuleDeclaration<'a>) -> ModuleDeclaration<'a> {
+        let node = self.0.fold_module_declaration(node);
+        self.1.fold_module_declaration(node)
+    }
+
+    fn fold_module_directive(&mut self, node: ModuleDirective<'a>) -> ModuleDirective<'a> {
+        let node = self.0.fold_module_directive(node);
+        self.1.fold_module_directive(node)
+    }
+
+    fn fold_top_level_class_or_interface_declaration(
+        &mut self,
+        node: TopLevelClassOrInterfaceDeclaration<'a>,
+    ) -> TopLevelClassOrInterfaceDeclaration<'a> {
+        let node = self.0.fold_top_level_class_or_interface_declaration(node);
+        self.1.fold_top_level_class_or_interface_declaration(node)
+    }
+
+    fn fold_class_declaration(&mut self, node: ClassDeclaration<'a>) -> ClassDeclaration<'a> {
+        let node = self.0.fold_class_declaration(node);
+        self.1.fold_class_declaration(node)
+    }
+
+    fn fold_interface_declaration(
+        &mut self,
+        node: InterfaceDeclaration<'a>,
+    ) -> InterfaceDeclaration<'a> {
+        let node = self.0.fold_interface_declaration(node);
+        self.1.fold_interface_declaration(node)
+    }
+
+    fn fold_enum_declaration(&mut self, node: EnumDeclaration<'a>) -> EnumDeclaration<'a> {
+        let node = self.0.fold_enum_declaration(node);
+        self.1.fold_enum_declaration(node)
+    }
+
+    fn fold_enum_constant_declaration(
+        &mut self,
+        node: EnumConstantDeclaration<'a>,
+    ) -> EnumConstantDeclaration<'a> {
+        let node = self.0.fold_enum_constant_declaration(node);
+        self.1.fold_enum_constant_declaration(node)
+    }
+
+    fn fold_record_declaration(&mut self, node: RecordDeclaration<'a>) -> RecordDeclaration<'a> {
+        let node = self.0.fold_record_declaration(node);
+        self.1.fold_record_declaration(node)
+    }
+
+    fn fold_record_component(&mut self, node: RecordComponent<'a>) -> RecordComponent<'a> {
+        let node = self.0.fold_record_component(node);
+        self.1.fold_record_component(node)
+    }
+
+    fn fold_annotation_declaration(
+        &mut self,
+        node: AnnotationDeclaration<'a>,
+    ) -> AnnotationDeclaration<'a> {
+        let node = self.0.fold_annotation_declaration(node);
+        self.1.fold_annotation_declaration(node)
+    }
+
+    fn fold_type_parameter(&mut self, node: TypeParameter<'a>) -> TypeParameter<'a> {
+        let node = self.0.fold_type_parameter(node);
+        self.1.fold_type_parameter(node)
+    }
+
+    fn fold_class_body(&mut self, node: ClassBody<'a>) -> ClassBody<'a> {
+        let node = self.0.fold_class_body(node);
+        self.1.fold_class_body(node)
+    }
+
+    fn fold_class_body_declaration(
+        &mut self,
+        node: ClassBodyDeclaration<'a>,
+    ) -> ClassBodyDeclaration<'a> {
+        let node = self.0.fold_class_body_declaration(node);
+        self.1.fold_class_body_declaration(node)
+    }
+
+    fn fold_field_declaration(&mut self, node: FieldDeclaration<'a>) -> FieldDeclaration<'a> {
+        let node = self.0.fold_field_declaration(node);
+        self.1.fold_field_declaration(node)
+    }
+
+    fn fold_method_declaration(&mut self, node: MethodDeclaration<'a>) -> MethodDeclaration<'a> {
+        let node = self.0.fold_method_declaration(node);
+        self.1.fold_method_declaration(node)
+    }
+
+    fn fold_constructor_declaration(
+        &mut self,
+        node: ConstructorDeclaration<'a>,
+    ) -> ConstructorDeclaration<'a> {
+        let node = self.0.fold_constructor_declaration(node);
+        self.1.fold_constructor_declaration(node)
+    }
+
+    fn fold_initializer(&mut self, node: Initializer<'a>) -> Initializer<'a> {
+        let node = self.0.fold_initializer(node);
+        self.1.fold_initializer(node)
+    }
+
+    fn fold_annotation_method(&mut self, node: AnnotationMethod<'a>) -> AnnotationMethod<'a> {
+        let node = self.0.fold_annotation_method(node);
+        self.1.fold_annotation_method(node)
+    }
+
+    fn fold_formal_parameter(&mut self, node: FormalParameter<'a>) -> FormalParameter<'a> {
+        let node = self.0.fold_formal_parameter(node);
+        self.1.fold_formal_parameter(node)
+    }
+
+    fn fold_variable_declarator(&mut self, node: VariableDeclarator<'a>) -> VariableDeclarator<'a> {
+        let node = self.0.fold_variable_declarator(node);
+        self.1.fold_variable_declarator(node)
+    }
+
+    fn fold_variable_initializer(
+        &mut self,
+        node: VariableInitializer<'a>,
+    ) -> VariableInitializer<'a> {
+        let node = self.0.fold_variable_initializer(node);
+        self.1.fold_variable_initializer(node)
+    }
+
+    fn fold_array_initializer(&mut self, node: ArrayInitializer<'a>) -> ArrayInitializer<'a> {
+        let node = self.0.fold_array_initializer(node);
+        self.1.fold_array_initializer(node)
+    }
+
+    fn fold_block(&mut self, node: Block<'a>) -> Block<'a> {
+        let node = self.0.fold_block(node);
+        self.1.fold_block(node)
+    }
+
+    fn fold_constructor_body(&mut self, node: ConstructorBody<'a>) -> ConstructorBody<'a> {
+        let node = self.0.fold_constructor_body(node);
+        self.1.fold_constructor_body(node)
+    }
+
+    fn fold_statement(&mut self, node: Statement<'a>) -> Statement<'a> {
+        let node = self.0.fold_statement(node);
+        self.1.fold_statement(node)
+    }
+
+    fn fold_local_variable_declaration(
+        &mut self,
+        node: LocalVariableDeclaration<'a>,
+    ) -> LocalVariableDeclaration<'a> {
+        let node = self.0.fold_local_variable_declaration(node);
+        self.1.fold_local_variable_declaration(node)
+    }
+
+    fn fold_for_control(&mut self, node: ForControl<'a>) -> ForControl<'a> {
+        let node = self.0.fold_for_control(node);
+        self.1.fold_for_control(node)
+    }
+
+    fn fold_for_init(&mut self, node: ForInit<'a>) -> ForInit<'a> {
+        let node = self.0.fold_for_init(node);
+        self.1.fold_for_init(node)
+    }
+
+    fn fold_try_statement(&mut self, node: TryStatement<'a>) -> TryStatement<'a> {
+        let node = self.0.fold_try_statement(node);
+        self.1.fold_try_statement(node)
+    }
+
+    fn fold_try_resource(&mut self, node: TryResource<'a>) -> TryResource<'a> {
+        let node = self.0.fold_try_resource(node);
+        self.1.fold_try_resource(node)
+    }
+
+    fn fold_catch_clause(&mut self, node: CatchClause<'a>) -> CatchClause<'a> {
+        let node = self.0.fold_catch_clause(node);
+        self.1.fold_catch_clause(node)
+    }
+
+    fn fold_catch_clause_parameter(
+        &mut self,
+        node: CatchClauseParameter<'a>,
+    ) -> CatchClauseParameter<'a> {
+        let node = self.0.fold_catch_clause_parameter(node);
+        self.1.fold_catch_clause_parameter(node)
+    }
+
+    fn fold_explicit_constructor_invocation(
+        &mut self,
+        node: ExplicitConstructorInvocation<'a>,
+    ) -> ExplicitConstructorInvocation<'a> {
+        let node = self.0.fold_explicit_constructor_invocation(node);
+        self.1.fold_explicit_constructor_invocation(node)
+    }
+
+    fn fold_switch(&mut self, node: Switch<'a>) -> Switch<'a> {
+        let node = self.0.fold_switch(node);
+        self.1.fold_switch(node)
+    }
+
+    fn fold_switch_body(&mut self, node: SwitchBody<'a>) -> SwitchBody<'a> {
+        let node = self.0.fold_switch_body(node);
+        self.1.fold_switch_body(node)
+    }
+
+    fn fold_switch_group(&mut self, node: SwitchGroup<'a>) -> SwitchGroup<'a> {
+        let node = self.0.fold_switch_group(node);
+        self.1.fold_switch_group(node)
+    }
+
+    fn fold_switch_rule(&mut self, node: SwitchRule<'a>) -> SwitchRule<'a> {
+        let node = self.0.fold_switch_rule(node);
+        self.1.fold_switch_rule(node)
+    }
+
+    fn fold_switch_rule_body(&mut self, node: SwitchRuleBody<'a>) -> SwitchRuleBody<'a> {
+        let node = self.0.fold_switch_rule_body(node);
+        self.1.fold_switch_rule_body(node)
+    }
+
+    fn fold_switch_label(&mut self, node: SwitchLabel<'a>) -> SwitchLabel<'a> {
+        let node = self.0.fold_switch_label(node);
+        self.1.fold_switch_label(node)
+    }
+
+    fn fold_expression(&mut self, node: Expression<'a>) -> Expression<'a> {
+        let node = self.0.fold_expression(node);
+        self.1.fold_expression(node)
+    }
+
+    fn fold_literal(&mut self, node: Literal<'a>) -> Literal<'a> {
+        let node = self.0.fold_literal(node);
+        self.1.fold_literal(node)
+    }
+
+    fn fold_lambda_parameters(&mut self, node: LambdaParameters<'a>) -> LambdaParameters<'a> {
+        let node = self.0.fold_lambda_parameters(node);
+        self.1.fold_lambda_parameters(node)
+    }
+
+    fn fold_lambda_body(&mut self, node: LambdaBody<'a>) -> LambdaBody<'a> {
+        let node = self.0.fold_lambda_body(node);
+        self.1.fold_lambda_body(node)
+    }
+
+    fn fold_method_reference_target(
+        &mut self,
+        node: MethodReferenceTarget<'a>,
+    ) -> MethodReferenceTarget<'a> {
+        let node = self.0.fold_method_reference_target(node);
+        self.1.fold_method_reference_target(node)
+    }
+
+    fn fold_pattern(&mut self, node: Pattern<'a>) -> Pattern<'a> {
+        let node = self.0.fold_pattern(node);
+        self.1.fold_pattern(node)
+    }
+
+    fn fold_instance_of_target(&mut self, node: InstanceOfTarget<'a>) -> InstanceOfTarget<'a> {
+        let node = self.0.fold_instance_of_target(node);
+        self.1.fold_instance_of_target(node)
+    }
+
+    fn fold_type(&mut self, node: Type<'a>) -> Type<'a> {
+        let node = self.0.fold_type(node);
+        self.1.fold_type(node)
+    }
+
+    fn fold_reference_type(&mut self, node: ReferenceType<'a>) -> ReferenceType<'a> {
+        let node = self.0.fold_reference_type(node);
+        self.1.fold_reference_type(node)
+    }
+
+    fn fold_type_argument(&mut self, node: TypeArgument<'a>) -> TypeArgument<'a> {
+        let node = self.0.fold_type_argument(node);
+        self.1.fold_type_argument(node)
+    }
+
+    fn fold_annotation(&mut self, node: Annotation<'a>) -> Annotation<'a> {
+        let node = self.0.fold_annotation(node);
+        self.1.fold_annotation(node)
+    }
+
+    fn fold_annotation_element(&mut self, node: AnnotationElement<'a>) -> AnnotationElement<'a> {
+        let node = self.0.fold_annotation_element(node);
+        self.1.fold_annotation_element(node)
+    }
+
+    fn fold_element_value_pair(&mut self, node: ElementValuePair<'a>) -> ElementValuePair<'a> {
+        let node = self.0.fold_element_value_pair(node);
+        self.1.fold_element_value_pair(node)
+    }
+
+    fn fold_element_value(&mut self, node: ElementValue<'a>) -> ElementValue<'a> {
+        let node = self.0.fold_element_value(node);
+        self.1.fold_element_value(node)
+    }
+
+    fn fold_documentation_comment(
+        &mut self,
+        node: DocumentationComment<'a>,
+    ) -> DocumentationComment<'a> {
+        let node = self.0.fold_documentation_comment(node);
+        self.1.fold_documentation_comment(node)
+    }
+
+    fn fold_comments(&mut self, node: Comments<'a>) -> Comments<'a> {
+        let node = self.0.fold_comments(node);
+        self.1.fold_comments(node)
+    }
+
+    fn fold_comment(&mut self, node: Comment<'a>) -> Comment<'a> {
+        let node = self.0.fold_comment(node);
+        self.1.fold_comment(node)
+    }
+
+    fn fold_error_node(&mut self, node: ErrorNode<'a>) -> ErrorNode<'a> {
+        let node = self.0.fold_error_node(node);
+        self.1.fold_error_node(node)
+    }
+}
+
+/// 依次折叠编译单元的子节点，返回由折叠结果组成的新节点。
+pub fn fold_compilation_unit_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: CompilationUnitDeclaration<'a>,
+) -> CompilationUnitDeclaration<'a> {
+    match node {
+        CompilationUnitDeclaration::Ordinary {
+            package,
+            imports,
+            import_comments,
+            top_level_class_or_interfaces,
+        } => {
+            let package = package.map(|i| folder.fold_package_declaration(i));
+            let (imports, import_comments) = fold_imports(folder, imports, import_comments);
+            CompilationUnitDeclaration::Ordinary {
+                package,
+                imports,
+                import_comments,
+                top_level_class_or_interfaces: top_level_class_or_interfaces
+                    .into_iter()
+                    .map(|i| folder.fold_top_level_class_or_interface_declaration(i))
+                    .collect(),
+            }
+        }
+        CompilationUnitDeclaration::Modular {
+            imports,
+            import_comments,
+            module,
+        } => {
+            let (imports, import_comments) = fold_imports(folder, imports, import_comments);
+            CompilationUnitDeclaration::Modular {
+                imports,
+                import_comments,
+                module: folder.fold_module_declaration(module),
+            }
+        }
+        CompilationUnitDeclaration::Implicit {
+            imports,
+            import_comments,
+            class,
+        } => {
+            let (imports, import_comments) = fold_imports(folder, imports, import_comments);
+            CompilationUnitDeclaration::Implicit {
+                imports,
+                import_comments,
+                class: folder.fold_class_declaration(class),
+            }
+        }
+    }
+}
+
+/// 依次折叠导入声明和它们关联的普通注释，每个导入声明的注释在它之前折叠。
+fn fold_imports<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    imports: Vec<ImportDeclaration<'a>>,
+    comments: Vec<Comments<'a>>,
+) -> (Vec<ImportDeclaration<'a>>, Vec<Comments<'a>>) {
+    let mut comments = comments.into_iter();
+    let mut folded = Vec::with_capacity(comments.len());
+    let mut declarations = Vec::with_capacity(imports.len());
+    for import in imports {
+        if let Some(c) = comments.next() {
+            folded.push(folder.fold_comments(c));
+        }
+        declarations.push(folder.fold_import_declaration(import));
+    }
+    folded.extend(comments.map(|c| folder.fold_comments(c)));
+    (declarations, folded)
+}
+
+/// 依次折叠包声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_package_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: PackageDeclaration<'a>,
+) -> PackageDeclaration<'a> {
+    PackageDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        modifiers: node
+            .modifiers
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        ..node
+    }
+}
+
+/// 依次折叠模块声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_module_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ModuleDeclaration<'a>,
+) -> ModuleDeclaration<'a> {
+    ModuleDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        directives: node
+            .directives
+            .into_iter()
+            .map(|i| folder.fold_module_directive(i))
+            .collect(),
+        ..node
+    }
+}
+
+/// 依次折叠顶层类或接口声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_top_level_class_or_interface_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: TopLevelClassOrInterfaceDeclaration<'a>,
+) -> TopLevelClassOrInterfaceDeclaration<'a> {
+    match node {
+        TopLevelClassOrInterfaceDeclaration::Class(d) => {
+            TopLevelClassOrInterfaceDeclaration::Class(folder.fold_class_declaration(d))
+        }
+        TopLevelClassOrInterfaceDeclaration::Interface(d) => {
+            TopLevelClassOrInterfaceDeclaration::Interface(folder.fold_interface_declaration(d))
+        }
+        TopLevelClassOrInterfaceDeclaration::Enum(d) => {
+            TopLevelClassOrInterfaceDeclaration::Enum(folder.fold_enum_declaration(d))
+        }
+        TopLevelClassOrInterfaceDeclaration::Record(d) => {
+            TopLevelClassOrInterfaceDeclaration::Record(folder.fold_record_declaration(d))
+        }
+        TopLevelClassOrInterfaceDeclaration::Annotation(d) => {
+            TopLevelClassOrInterfaceDeclaration::Annotation(folder.fold_annotation_declaration(d))
+        }
+        TopLevelClassOrInterfaceDeclaration::Error(e) => {
+            TopLevelClassOrInterfaceDeclaration::Error(folder.fold_error_node(e))
+        }
+    }
+}
+
+/// 依次折叠类声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_class_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ClassDeclaration<'a>,
+) -> ClassDeclaration<'a> {
+    ClassDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        type_parameters: node
+            .type_parameters
+            .into_iter()
+            .map(|i| folder.fold_type_parameter(i))
+            .collect(),
+        extends: node.extends.map(|i| folder.fold_reference_type(i)),
+        implements: node
+            .implements
+            .into_iter()
+            .map(|i| folder.fold_reference_type(i))
+            .collect(),
+        permits: node
+            .permits
+            .into_iter()
+            .map(|i| folder.fold_reference_type(i))
+            .collect(),
+        body: folder.fold_class_body(node.body),
+        ..node
+    }
+}
+
+/// 依次折叠接口声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_interface_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: InterfaceDeclaration<'a>,
+) -> InterfaceDeclaration<'a> {
+    InterfaceDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        type_parameters: node
+            .type_parameters
+            .into_iter()
+            .map(|i| folder.fold_type_parameter(i))
+            .collect(),
+        extends: node
+            .extends
+            .into_iter()
+            .map(|i| folder.fold_reference_type(i))
+            .collect(),
+        permits: node
+            .permits
+            .into_iter()
+            .map(|i| folder.fold_reference_type(i))
+            .collect(),
+        body: folder.fold_class_body(node.body),
+        ..node
+    }
+}
+
+/// 依次折叠枚举声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_enum_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: EnumDeclaration<'a>,
+) -> EnumDeclaration<'a> {
+    EnumDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        implements: node
+            .implements
+            .into_iter()
+            .map(|i| folder.fold_reference_type(i))
+            .collect(),
+        constants: node
+            .constants
+            .into_iter()
+            .map(|i| folder.fold_enum_constant_declaration(i))
+            .collect(),
+        body: folder.fold_class_body(node.body),
+        ..node
+    }
+}
+
+/// 依次折叠枚举常量声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_enum_constant_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: EnumConstantDeclaration<'a>,
+) -> EnumConstantDeclaration<'a> {
+    EnumConstantDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        arguments: node
+            .arguments
+            .map(|a| a.into_iter().map(|i| folder.fold_expression(i)).collect()),
+        body: node.body.map(|i| folder.fold_class_body(i)),
+        ..node
+    }
+}
+
+/// 依次折叠记录声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_record_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: RecordDeclaration<'a>,
+) -> RecordDeclaration<'a> {
+    RecordDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        type_parameters: node
+            .type_parameters
+            .into_iter()
+            .map(|i| folder.fold_type_parameter(i))
+            .collect(),
+        components: node
+            .components
+            .into_iter()
+            .map(|i| folder.fold_record_component(i))
+            .collect(),
+        implements: node
+            .implements
+            .into_iter()
+            .map(|i| folder.fold_reference_type(i))
+            .collect(),
+        body: folder.fold_class_body(node.body),
+        ..node
+    }
+}
+
+/// 依次折叠记录组件的子节点，返回由折叠结果组成的新节点。
+pub fn fold_record_component<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: RecordComponent<'a>,
+) -> RecordComponent<'a> {
+    RecordComponent {
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        r#type: folder.fold_type(node.r#type),
+        ..node
+    }
+}
+
+/// 依次折叠注解接口声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_annotation_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: AnnotationDeclaration<'a>,
+) -> AnnotationDeclaration<'a> {
+    AnnotationDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        body: folder.fold_class_body(node.body),
+        ..node
+    }
+}
+
+/// 依次折叠类型参数的子节点，返回由折叠结果组成的新节点。
+pub fn fold_type_parameter<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: TypeParameter<'a>,
+) -> TypeParameter<'a> {
+    TypeParameter {
+        extends: node
+            .extends
+            .into_iter()
+            .map(|i| folder.fold_reference_type(i))
+            .collect(),
+        ..node
+    }
+}
+
+/// 依次折叠类体的子节点，返回由折叠结果组成的新节点。
+pub fn fold_class_body<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ClassBody<'a>,
+) -> ClassBody<'a> {
+    ClassBody {
+        declarations: node
+            .declarations
+            .into_iter()
+            .map(|i| folder.fold_class_body_declaration(i))
+            .collect(),
+        dangling: node
+            .dangling
+            .into_iter()
+            .map(|i| folder.fold_comment(i))
+            .collect(),
+    }
+}
+
+/// 依次折叠类体中的声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_class_body_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ClassBodyDeclaration<'a>,
+) -> ClassBodyDeclaration<'a> {
+    match node {
+        ClassBodyDeclaration::Field(d) => {
+            ClassBodyDeclaration::Field(folder.fold_field_declaration(d))
+        }
+        ClassBodyDeclaration::Method(d) => {
+            ClassBodyDeclaration::Method(folder.fold_method_declaration(d))
+        }
+        ClassBodyDeclaration::Constructor(d) => {
+            ClassBodyDeclaration::Constructor(folder.fold_constructor_declaration(d))
+        }
+        ClassBodyDeclaration::Initializer(d) => {
+            ClassBodyDeclaration::Initializer(folder.fold_initializer(d))
+        }
+        ClassBodyDeclaration::AnnotationMethod(d) => {
+            ClassBodyDeclaration::AnnotationMethod(folder.fold_annotation_method(d))
+        }
+        ClassBodyDeclaration::Class(d) => {
+            ClassBodyDeclaration::Class(folder.fold_class_declaration(d))
+        }
+        ClassBodyDeclaration::Interface(d) => {
+            ClassBodyDeclaration::Interface(folder.fold_interface_declaration(d))
+        }
+        ClassBodyDeclaration::Enum(d) => {
+            ClassBodyDeclaration::Enum(folder.fold_enum_declaration(d))
+        }
+        ClassBodyDeclaration::Record(d) => {
+            ClassBodyDeclaration::Record(folder.fold_record_declaration(d))
+        }
+        ClassBodyDeclaration::Annotation(d) => {
+            ClassBodyDeclaration::Annotation(folder.fold_annotation_declaration(d))
+        }
+        ClassBodyDeclaration::Error(e) => ClassBodyDeclaration::Error(folder.fold_error_node(e)),
+    }
+}
+
+/// 依次折叠字段声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_field_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: FieldDeclaration<'a>,
+) -> FieldDeclaration<'a> {
+    FieldDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        r#type: folder.fold_type(node.r#type),
+        declarators: node
+            .declarators
+            .into_iter()
+            .map(|i| folder.fold_variable_declarator(i))
+            .collect(),
+        ..node
+    }
+}
+
+/// 依次折叠方法声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_method_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: MethodDeclaration<'a>,
+) -> MethodDeclaration<'a> {
+    MethodDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        type_parameters: node
+            .type_parameters
+            .into_iter()
+            .map(|i| folder.fold_type_parameter(i))
+            .collect(),
+        return_type: node.return_type.map(|i| folder.fold_type(i)),
+        parameters: node
+            .parameters
+            .into_iter()
+            .map(|i| folder.fold_formal_parameter(i))
+            .collect(),
+        throws: node
+            .throws
+            .into_iter()
+            .map(|i| folder.fold_reference_type(i))
+            .collect(),
+        body: node.body.map(|i| folder.fold_block(i)),
+        ..node
+    }
+}
+
+/// 依次折叠构造函数声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_constructor_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ConstructorDeclaration<'a>,
+) -> ConstructorDeclaration<'a> {
+    ConstructorDeclaration {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        type_parameters: node
+            .type_parameters
+            .into_iter()
+            .map(|i| folder.fold_type_parameter(i))
+            .collect(),
+        parameters: node.parameters.map(|p| {
+            p.into_iter()
+                .map(|i| folder.fold_formal_parameter(i))
+                .collect()
+        }),
+        throws: node
+            .throws
+            .into_iter()
+            .map(|i| folder.fold_reference_type(i))
+            .collect(),
+        body: folder.fold_constructor_body(node.body),
+        ..node
+    }
+}
+
+/// 依次折叠初始化块的子节点，返回由折叠结果组成的新节点。
+pub fn fold_initializer<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: Initializer<'a>,
+) -> Initializer<'a> {
+    Initializer {
+        comments: folder.fold_comments(node.comments),
+        block: folder.fold_block(node.block),
+        ..node
+    }
+}
+
+/// 依次折叠注解接口的元素声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_annotation_method<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: AnnotationMethod<'a>,
+) -> AnnotationMethod<'a> {
+    AnnotationMethod {
+        comments: folder.fold_comments(node.comments),
+        documentation: node
+            .documentation
+            .map(|i| folder.fold_documentation_comment(i)),
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        return_type: folder.fold_type(node.return_type),
+        default: node.default.map(|i| folder.fold_element_value(i)),
+        ..node
+    }
+}
+
+/// 依次折叠形式参数的子节点，返回由折叠结果组成的新节点。
+pub fn fold_formal_parameter<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: FormalParameter<'a>,
+) -> FormalParameter<'a> {
+    FormalParameter {
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        r#type: folder.fold_type(node.r#type),
+        ..node
+    }
+}
+
+/// 依次折叠变量声明符的子节点，返回由折叠结果组成的新节点。
+pub fn fold_variable_declarator<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: VariableDeclarator<'a>,
+) -> VariableDeclarator<'a> {
+    VariableDeclarator {
+        initializer: node
+            .initializer
+            .map(|i| folder.fold_variable_initializer(i)),
+        ..node
+    }
+}
+
+/// 依次折叠变量初始化器的子节点，返回由折叠结果组成的新节点。
+pub fn fold_variable_initializer<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: VariableInitializer<'a>,
+) -> VariableInitializer<'a> {
+    match node {
+        VariableInitializer::Expression(e) => {
+            VariableInitializer::Expression(folder.fold_expression(e))
+        }
+        VariableInitializer::Array(a) => {
+            VariableInitializer::Array(folder.fold_array_initializer(a))
+        }
+    }
+}
+
+/// 依次折叠数组初始化器的子节点，返回由折叠结果组成的新节点。
+pub fn fold_array_initializer<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ArrayInitializer<'a>,
+) -> ArrayInitializer<'a> {
+    ArrayInitializer {
+        initializers: node
+            .initializers
+            .into_iter()
+            .map(|i| folder.fold_variable_initializer(i))
+            .collect(),
+    }
+}
+
+/// 依次折叠代码块的子节点，返回由折叠结果组成的新节点。
+pub fn fold_block<'a, F: Fold<'a> + ?Sized>(folder: &mut F, node: Block<'a>) -> Block<'a> {
+    let mut comments = node.comments.into_iter();
+    let mut folded = Vec::with_capacity(comments.len());
+    let mut statements = Vec::with_capacity(node.statements.len());
+    for statement in node.statements {
+        if let Some(c) = comments.next() {
+            folded.push(folder.fold_comments(c));
+        }
+        statements.push(folder.fold_statement(statement));
+    }
+    folded.extend(comments.map(|c| folder.fold_comments(c)));
+    Block {
+        statements,
+        comments: folded,
+        dangling: node
+            .dangling
+            .into_iter()
+            .map(|i| folder.fold_comment(i))
+            .collect(),
+    }
+}
+
+/// 依次折叠构造函数体的子节点，返回由折叠结果组成的新节点。
+pub fn fold_constructor_body<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ConstructorBody<'a>,
+) -> ConstructorBody<'a> {
+    let mut comments = node.comments.into_iter();
+    let mut folded = Vec::with_capacity(comments.len());
+    let mut prologue = Vec::with_capacity(node.prologue.len());
+    for statement in node.prologue {
+        if let Some(c) = comments.next() {
+            folded.push(folder.fold_comments(c));
+        }
+        prologue.push(folder.fold_statement(statement));
+    }
+    let invocation = node.invocation.map(|i| {
+        if let Some(c) = comments.next() {
+            folded.push(folder.fold_comments(c));
+        }
+        folder.fold_explicit_constructor_invocation(i)
+    });
+    let mut epilogue = Vec::with_capacity(node.epilogue.len());
+    for statement in node.epilogue {
+        if let Some(c) = comments.next() {
+            folded.push(folder.fold_comments(c));
+        }
+        epilogue.push(folder.fold_statement(statement));
+    }
+    folded.extend(comments.map(|c| folder.fold_comments(c)));
+    ConstructorBody {
+        prologue,
+        invocation,
+        epilogue,
+        comments: folded,
+        dangling: node
+            .dangling
+            .into_iter()
+            .map(|i| folder.fold_comment(i))
+            .collect(),
+    }
+}
+
+/// 依次折叠语句的子节点，返回由折叠结果组成的新节点。
+pub fn fold_statement<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: Statement<'a>,
+) -> Statement<'a> {
+    match node {
+        Statement::Block(b) => Statement::Block(folder.fold_block(b)),
+        Statement::LocalVariable(d) => {
+            Statement::LocalVariable(folder.fold_local_variable_declaration(d))
+        }
+        Statement::LocalClass(d) => {
+            Statement::LocalClass(Box::new(folder.fold_class_body_declaration(*d)))
+        }
+        Statement::Labeled {
+            label,
+            statement,
+            statement_comments,
+        } => Statement::Labeled {
+            label,
+            statement_comments: folder.fold_comments(statement_comments),
+            statement: Box::new(folder.fold_statement(*statement)),
+        },
+        Statement::Expression(e) => Statement::Expression(folder.fold_expression(e)),
+        Statement::If {
+            condition,
+            then_statement,
+            else_statement,
+            then_comments,
+            else_comments,
+        } => Statement::If {
+            condition: folder.fold_expression(condition),
+            then_comments: folder.fold_comments(then_comments),
+            then_statement: Box::new(folder.fold_statement(*then_statement)),
+            else_comments: folder.fold_comments(else_comments),
+            else_statement: else_statement.map(|s| Box::new(folder.fold_statement(*s))),
+        },
+        Statement::While {
+            condition,
+            body,
+            body_comments,
+        } => Statement::While {
+            condition: folder.fold_expression(condition),
+            body_comments: folder.fold_comments(body_comments),
+            body: Box::new(folder.fold_statement(*body)),
+        },
+        Statement::Do {
+            body,
+            condition,
+            body_comments,
+        } => Statement::Do {
+            body_comments: folder.fold_comments(body_comments),
+            body: Box::new(folder.fold_statement(*body)),
+            condition: folder.fold_expression(condition),
+        },
+        Statement::For {
+            control,
+            body,
+            body_comments,
+        } => Statement::For {
+            control: folder.fold_for_control(control),
+            body_comments: folder.fold_comments(body_comments),
+            body: Box::new(folder.fold_statement(*body)),
+        },
+        Statement::Return(e) => Statement::Return(e.map(|i| folder.fold_expression(i))),
+        Statement::Throw(e) => Statement::Throw(folder.fold_expression(e)),
+        Statement::Assert { condition, message } => Statement::Assert {
+            condition: folder.fold_expression(condition),
+            message: message.map(|i| folder.fold_expression(i)),
+        },
+        Statement::Synchronized { lock, block } => Statement::Synchronized {
+            lock: folder.fold_expression(lock),
+            block: folder.fold_block(block),
+        },
+        Statement::Switch(s) => Statement::Switch(folder.fold_switch(s)),
+        Statement::Yield(e) => Statement::Yield(folder.fold_expression(e)),
+        Statement::Try(t) => Statement::Try(folder.fold_try_statement(t)),
+        Statement::Error(e) => Statement::Error(folder.fold_error_node(e)),
+        statement @ (Statement::Empty | Statement::Break(_) | Statement::Continue(_)) => statement,
+    }
+}
+
+/// 依次折叠局部变量声明的子节点，返回由折叠结果组成的新节点。
+pub fn fold_local_variable_declaration<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: LocalVariableDeclaration<'a>,
+) -> LocalVariableDeclaration<'a> {
+    LocalVariableDeclaration {
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        r#type: folder.fold_type(node.r#type),
+        declarators: node
+            .declarators
+            .into_iter()
+            .map(|i| folder.fold_variable_declarator(i))
+            .collect(),
+        ..node
+    }
+}
+
+/// 依次折叠`for`循环的控制部分的子节点，返回由折叠结果组成的新节点。
+pub fn fold_for_control<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ForControl<'a>,
+) -> ForControl<'a> {
+    match node {
+        ForControl::Basic {
+            init,
+            condition,
+            update,
+        } => ForControl::Basic {
+            init: folder.fold_for_init(init),
+            condition: condition.map(|i| folder.fold_expression(i)),
+            update: update
+                .into_iter()
+                .map(|i| folder.fold_expression(i))
+                .collect(),
+        },
+        ForControl::Enhanced { variable, iterable } => ForControl::Enhanced {
+            variable: folder.fold_local_variable_declaration(variable),
+            iterable: folder.fold_expression(iterable),
+        },
+    }
+}
+
+/// 依次折叠`for`循环的初始化部分的子节点，返回由折叠结果组成的新节点。
+pub fn fold_for_init<'a, F: Fold<'a> + ?Sized>(folder: &mut F, node: ForInit<'a>) -> ForInit<'a> {
+    match node {
+        ForInit::Declaration(d) => ForInit::Declaration(folder.fold_local_variable_declaration(d)),
+        ForInit::Expressions(e) => {
+            ForInit::Expressions(e.into_iter().map(|i| folder.fold_expression(i)).collect())
+        }
+    }
+}
+
+/// 依次折叠`try`语句的子节点，返回由折叠结果组成的新节点。
+pub fn fold_try_statement<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: TryStatement<'a>,
+) -> TryStatement<'a> {
+    TryStatement {
+        resources: node
+            .resources
+            .into_iter()
+            .map(|i| folder.fold_try_resource(i))
+            .collect(),
+        block: folder.fold_block(node.block),
+        catches: node
+            .catches
+            .into_iter()
+            .map(|i| folder.fold_catch_clause(i))
+            .collect(),
+        finally: node.finally.map(|i| folder.fold_block(i)),
+    }
+}
+
+/// 依次折叠`try`语句的资源的子节点，返回由折叠结果组成的新节点。
+pub fn fold_try_resource<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: TryResource<'a>,
+) -> TryResource<'a> {
+    match node {
+        TryResource::Declaration {
+            modifiers,
+            annotations,
+            r#type,
+            name,
+            value,
+        } => TryResource::Declaration {
+            modifiers,
+            annotations: annotations
+                .into_iter()
+                .map(|i| folder.fold_annotation(i))
+                .collect(),
+            r#type: folder.fold_type(r#type),
+            name,
+            value: folder.fold_expression(value),
+        },
+        TryResource::Variable(e) => TryResource::Variable(folder.fold_expression(e)),
+    }
+}
+
+/// 依次折叠`catch`子句的子节点，返回由折叠结果组成的新节点。
+pub fn fold_catch_clause<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: CatchClause<'a>,
+) -> CatchClause<'a> {
+    CatchClause {
+        parameter: folder.fold_catch_clause_parameter(node.parameter),
+        block: folder.fold_block(node.block),
+    }
+}
+
+/// 依次折叠`catch`子句的参数的子节点，返回由折叠结果组成的新节点。
+pub fn fold_catch_clause_parameter<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: CatchClauseParameter<'a>,
+) -> CatchClauseParameter<'a> {
+    CatchClauseParameter {
+        annotations: node
+            .annotations
+            .into_iter()
+            .map(|i| folder.fold_annotation(i))
+            .collect(),
+        types: node
+            .types
+            .into_iter()
+            .map(|i| folder.fold_reference_type(i))
+            .collect(),
+        ..node
+    }
+}
+
+/// 依次折叠显式构造函数调用的子节点，返回由折叠结果组成的新节点。
+pub fn fold_explicit_constructor_invocation<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ExplicitConstructorInvocation<'a>,
+) -> ExplicitConstructorInvocation<'a> {
+    ExplicitConstructorInvocation {
+        qualifier: node.qualifier.map(|i| folder.fold_expression(i)),
+        type_arguments: node
+            .type_arguments
+            .into_iter()
+            .map(|i| folder.fold_type_argument(i))
+            .collect(),
+        arguments: node
+            .arguments
+            .into_iter()
+            .map(|i| folder.fold_expression(i))
+            .collect(),
+        ..node
+    }
+}
+
+/// 依次折叠`switch`语句或表达式的子节点，返回由折叠结果组成的新节点。
+pub fn fold_switch<'a, F: Fold<'a> + ?Sized>(folder: &mut F, node: Switch<'a>) -> Switch<'a> {
+    Switch {
+        selector: Box::new(folder.fold_expression(*node.selector)),
+        body: folder.fold_switch_body(node.body),
+        dangling: node
+            .dangling
+            .into_iter()
+            .map(|i| folder.fold_comment(i))
+            .collect(),
+    }
+}
+
+/// 依次折叠`switch`块的子节点，返回由折叠结果组成的新节点。
+pub fn fold_switch_body<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: SwitchBody<'a>,
+) -> SwitchBody<'a> {
+    match node {
+        SwitchBody::Groups(groups) => SwitchBody::Groups(
+            groups
+                .into_iter()
+                .map(|i| folder.fold_switch_group(i))
+                .collect(),
+        ),
+        SwitchBody::Rules(rules) => SwitchBody::Rules(
+            rules
+                .into_iter()
+                .map(|i| folder.fold_switch_rule(i))
+                .collect(),
+        ),
+    }
+}
+
+/// 依次折叠`switch`块中的语句组的子节点，返回由折叠结果组成的新节点。
+pub fn fold_switch_group<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: SwitchGroup<'a>,
+) -> SwitchGroup<'a> {
+    let comments = folder.fold_comments(node.comments);
+    let labels = node
+        .labels
+        .into_iter()
+        .map(|i| folder.fold_switch_label(i))
+        .collect();
+    let mut statement_comments = node.statement_comments.into_iter();
+    let mut folded = Vec::with_capacity(statement_comments.len());
+    let mut statements = Vec::with_capacity(node.statements.len());
+    for statement in node.statements {
+        if let Some(c) = statement_comments.next() {
+            folded.push(folder.fold_comments(c));
+        }
+        statements.push(folder.fold_statement(statement));
+    }
+    folded.extend(statement_comments.map(|c| folder.fold_comments(c)));
+    SwitchGroup {
+        labels,
+        statements,
+        statement_comments: folded,
+        comments,
+    }
+}
+
+/// 依次折叠`switch`规则的子节点，返回由折叠结果组成的新节点。
+pub fn fold_switch_rule<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: SwitchRule<'a>,
+) -> SwitchRule<'a> {
+    SwitchRule {
+        comments: folder.fold_comments(node.comments),
+        label: folder.fold_switch_label(node.label),
+        body: folder.fold_switch_rule_body(node.body),
+    }
+}
+
+/// 依次折叠`switch`规则的主体的子节点，返回由折叠结果组成的新节点。
+pub fn fold_switch_rule_body<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: SwitchRuleBody<'a>,
+) -> SwitchRuleBody<'a> {
+    match node {
+        SwitchRuleBody::Expression(e) => SwitchRuleBody::Expression(folder.fold_expression(e)),
+        SwitchRuleBody::Block(b) => SwitchRuleBody::Block(folder.fold_block(b)),
+        SwitchRuleBody::Throw(e) => SwitchRuleBody::Throw(folder.fold_expression(e)),
+    }
+}
+
+/// 依次折叠`switch`标签的子节点，返回由折叠结果组成的新节点。
+pub fn fold_switch_label<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: SwitchLabel<'a>,
+) -> SwitchLabel<'a> {
+    match node {
+        SwitchLabel::Case(e) => {
+            SwitchLabel::Case(e.into_iter().map(|i| folder.fold_expression(i)).collect())
+        }
+        SwitchLabel::Pattern { patterns, guard } => SwitchLabel::Pattern {
+            patterns: patterns
+                .into_iter()
+                .map(|i| folder.fold_pattern(i))
+                .collect(),
+            guard: guard.map(|i| folder.fold_expression(i)),
+        },
+        label @ (SwitchLabel::NullDefault | SwitchLabel::Default) => label,
+    }
+}
+
+/// 依次折叠表达式的子节点，返回由折叠结果组成的新节点。
+pub fn fold_expression<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: Expression<'a>,
+) -> Expression<'a> {
+    match node {
+        Expression::Literal(l) => Expression::Literal(folder.fold_literal(l)),
+        Expression::FieldAccess { target, name } => Expression::FieldAccess {
+            target: Box::new(folder.fold_expression(*target)),
+            name,
+        },
+        Expression::MethodInvocation {
+            target,
+            type_arguments,
+            name,
+            arguments,
+        } => Expression::MethodInvocation {
+            target: target.map(|t| Box::new(folder.fold_expression(*t))),
+            type_arguments: type_arguments
+                .into_iter()
+                .map(|i| folder.fold_type_argument(i))
+                .collect(),
+            name,
+            arguments: arguments
+                .into_iter()
+                .map(|i| folder.fold_expression(i))
+                .collect(),
+        },
+        Expression::SuperMethodInvocation {
+            qualifier,
+            type_arguments,
+            name,
+            arguments,
+        } => Expression::SuperMethodInvocation {
+            qualifier,
+            type_arguments: type_arguments
+                .into_iter()
+                .map(|i| folder.fold_type_argument(i))
+                .collect(),
+            name,
+            arguments: arguments
+                .into_iter()
+                .map(|i| folder.fold_expression(i))
+                .collect(),
+        },
+        Expression::ArrayAccess { array, index } => Expression::ArrayAccess {
+            array: Box::new(folder.fold_expression(*array)),
+            index: Box::new(folder.fold_expression(*index)),
+        },
+        Expression::ClassLiteral(t) => Expression::ClassLiteral(folder.fold_type(t)),
+        Expression::ClassCreation {
+            outer,
+            type_arguments,
+            r#type,
+            arguments,
+            body,
+        } => Expression::ClassCreation {
+            outer: outer.map(|o| Box::new(folder.fold_expression(*o))),
+            type_arguments: type_arguments
+                .into_iter()
+                .map(|i| folder.fold_type_argument(i))
+                .collect(),
+            r#type: folder.fold_reference_type(r#type),
+            arguments: arguments
+                .into_iter()
+                .map(|i| folder.fold_expression(i))
+                .collect(),
+            body: body.map(|i| folder.fold_class_body(i)),
+        },
+        Expression::ArrayCreation {
+            r#type,
+            dimensions,
+            initializer,
+        } => Expression::ArrayCreation {
+            r#type: folder.fold_type(r#type),
+            dimensions: dimensions
+                .into_iter()
+                .map(|i| folder.fold_expression(i))
+                .collect(),
+            initializer: initializer.map(|i| folder.fold_array_initializer(i)),
+        },
+        Expression::Parenthesized(e) => {
+            Expression::Parenthesized(Box::new(folder.fold_expression(*e)))
+        }
+        Expression::Assignment {
+            target,
+            operator,
+            value,
+        } => Expression::Assignment {
+            target: Box::new(folder.fold_expression(*target)),
+            operator,
+            value: Box::new(folder.fold_expression(*value)),
+        },
+        Expression::Ternary {
+            condition,
+            if_true,
+            if_false,
+        } => Expression::Ternary {
+            condition: Box::new(folder.fold_expression(*condition)),
+            if_true: Box::new(folder.fold_expression(*if_true)),
+            if_false: Box::new(folder.fold_expression(*if_false)),
+        },
+        Expression::Binary {
+            operator,
+            left,
+            right,
+        } => Expression::Binary {
+            operator,
+            left: Box::new(folder.fold_expression(*left)),
+            right: Box::new(folder.fold_expression(*right)),
+        },
+        Expression::Lambda { parameters, body } => Expression::Lambda {
+            parameters: folder.fold_lambda_parameters(parameters),
+            body: folder.fold_lambda_body(body),
+        },
+        Expression::MethodReference {
+            target,
+            type_arguments,
+            name,
+        } => Expression::MethodReference {
+            target: folder.fold_method_reference_target(target),
+            type_arguments: type_arguments
+                .into_iter()
+                .map(|i| folder.fold_type_argument(i))
+                .collect(),
+            name,
+        },
+        Expression::Switch(s) => Expression::Switch(folder.fold_switch(s)),
+        Expression::InstanceOf { expression, target } => Expression::InstanceOf {
+            expression: Box::new(folder.fold_expression(*expression)),
+            target: folder.fold_instance_of_target(target),
+        },
+        Expression::Unary { operator, operand } => Expression::Unary {
+            operator,
+            operand: Box::new(folder.fold_expression(*operand)),
+        },
+        Expression::Postfix { operator, operand } => Expression::Postfix {
+            operator,
+            operand: Box::new(folder.fold_expression(*operand)),
+        },
+        Expression::Cast {
+            r#type,
+            bounds,
+            expression,
+        } => Expression::Cast {
+            r#type: folder.fold_type(r#type),
+            bounds: bounds
+                .into_iter()
+                .map(|i| folder.fold_reference_type(i))
+                .collect(),
+            expression: Box::new(folder.fold_expression(*expression)),
+        },
+        expression @ (Expression::Name(_)
+        | Expression::This
+        | Expression::QualifiedThis(_)
+        | Expression::SuperFieldAccess { .. }
+        | Expression::VoidClassLiteral) => expression,
+    }
+}
+
+/// 依次折叠Lambda表达式的参数列表的子节点，返回由折叠结果组成的新节点。
+pub fn fold_lambda_parameters<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: LambdaParameters<'a>,
+) -> LambdaParameters<'a> {
+    match node {
+        LambdaParameters::Formal(parameters) => LambdaParameters::Formal(
+            parameters
+                .into_iter()
+                .map(|i| folder.fold_formal_parameter(i))
+                .collect(),
+        ),
+        parameters @ LambdaParameters::Inferred(_) => parameters,
+    }
+}
+
+/// 依次折叠Lambda体的子节点，返回由折叠结果组成的新节点。
+pub fn fold_lambda_body<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: LambdaBody<'a>,
+) -> LambdaBody<'a> {
+    match node {
+        LambdaBody::Expression(e) => LambdaBody::Expression(Box::new(folder.fold_expression(*e))),
+        LambdaBody::Block(b) => LambdaBody::Block(folder.fold_block(b)),
+    }
+}
+
+/// 依次折叠方法引用中`::`前面的部分的子节点，返回由折叠结果组成的新节点。
+pub fn fold_method_reference_target<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: MethodReferenceTarget<'a>,
+) -> MethodReferenceTarget<'a> {
+    match node {
+        MethodReferenceTarget::Expression(e) => {
+            MethodReferenceTarget::Expression(Box::new(folder.fold_expression(*e)))
+        }
+        MethodReferenceTarget::Type(t) => MethodReferenceTarget::Type(folder.fold_type(t)),
+        target @ MethodReferenceTarget::Super(_) => target,
+    }
+}
+
+/// 依次折叠模式的子节点，返回由折叠结果组成的新节点。
+pub fn fold_pattern<'a, F: Fold<'a> + ?Sized>(folder: &mut F, node: Pattern<'a>) -> Pattern<'a> {
+    match node {
+        Pattern::Type {
+            modifiers,
+            annotations,
+            r#type,
+            name,
+        } => Pattern::Type {
+            modifiers,
+            annotations: annotations
+                .into_iter()
+                .map(|i| folder.fold_annotation(i))
+                .collect(),
+            r#type: folder.fold_type(r#type),
+            name,
+        },
+        Pattern::Record { r#type, patterns } => Pattern::Record {
+            r#type: folder.fold_reference_type(r#type),
+            patterns: patterns
+                .into_iter()
+                .map(|i| folder.fold_pattern(i))
+                .collect(),
+        },
+        Pattern::Unnamed => Pattern::Unnamed,
+    }
+}
+
+/// 依次折叠`instanceof`右边的类型或模式的子节点，返回由折叠结果组成的新节点。
+pub fn fold_instance_of_target<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: InstanceOfTarget<'a>,
+) -> InstanceOfTarget<'a> {
+    match node {
+        InstanceOfTarget::Type(t) => InstanceOfTarget::Type(folder.fold_type(t)),
+        InstanceOfTarget::Pattern(p) => InstanceOfTarget::Pattern(folder.fold_pattern(p)),
+    }
+}
+
+/// 依次折叠类型的子节点，返回由折叠结果组成的新节点。
+pub fn fold_type<'a, F: Fold<'a> + ?Sized>(folder: &mut F, node: Type<'a>) -> Type<'a> {
+    match node {
+        Type::Basic(b) => Type::Basic(b),
+        Type::Reference(r) => Type::Reference(folder.fold_reference_type(r)),
+        Type::Array(t) => Type::Array(Box::new(folder.fold_type(*t))),
+    }
+}
+
+/// 依次折叠引用类型的子节点，返回由折叠结果组成的新节点。
+pub fn fold_reference_type<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ReferenceType<'a>,
+) -> ReferenceType<'a> {
+    ReferenceType {
+        arguments: node.arguments.map(|a| {
+            a.into_iter()
+                .map(|i| folder.fold_type_argument(i))
+                .collect()
+        }),
+        sub_type: node
+            .sub_type
+            .map(|t| Box::new(folder.fold_reference_type(*t))),
+        ..node
+    }
+}
+
+/// 依次折叠类型实参的子节点，返回由折叠结果组成的新节点。
+pub fn fold_type_argument<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: TypeArgument<'a>,
+) -> TypeArgument<'a> {
+    match node {
+        TypeArgument::Type(t) => TypeArgument::Type(folder.fold_type(t)),
+        TypeArgument::Wildcard => TypeArgument::Wildcard,
+        TypeArgument::Extends(t) => TypeArgument::Extends(folder.fold_type(t)),
+        TypeArgument::Super(t) => TypeArgument::Super(folder.fold_type(t)),
+    }
+}
+
+/// 依次折叠注解的子节点，返回由折叠结果组成的新节点。
+pub fn fold_annotation<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: Annotation<'a>,
+) -> Annotation<'a> {
+    Annotation {
+        element: node.element.map(|i| folder.fold_annotation_element(i)),
+        ..node
+    }
+}
+
+/// 依次折叠注解括号中的内容的子节点，返回由折叠结果组成的新节点。
+pub fn fold_annotation_element<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: AnnotationElement<'a>,
+) -> AnnotationElement<'a> {
+    match node {
+        AnnotationElement::Value(v) => AnnotationElement::Value(folder.fold_element_value(v)),
+        AnnotationElement::Pairs(pairs) => AnnotationElement::Pairs(
+            pairs
+                .into_iter()
+                .map(|i| folder.fold_element_value_pair(i))
+                .collect(),
+        ),
+    }
+}
+
+/// 依次折叠注解的元素值对的子节点，返回由折叠结果组成的新节点。
+pub fn fold_element_value_pair<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ElementValuePair<'a>,
+) -> ElementValuePair<'a> {
+    ElementValuePair {
+        value: folder.fold_element_value(node.value),
+        ..node
+    }
+}
+
+/// 依次折叠注解的元素值的子节点，返回由折叠结果组成的新节点。
+pub fn fold_element_value<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ElementValue<'a>,
+) -> ElementValue<'a> {
+    match node {
+        ElementValue::Expression(e) => ElementValue::Expression(folder.fold_expression(e)),
+        ElementValue::Annotation(a) => {
+            ElementValue::Annotation(Box::new(folder.fold_annotation(*a)))
+        }
+        ElementValue::Array(values) => ElementValue::Array(
+            values
+                .into_iter()
+                .map(|i| folder.fold_element_value(i))
+                .collect(),
+        ),
+    }
+}
+
+/// 依次折叠关联到节点的普通注释的子节点，返回由折叠结果组成的新节点。
+pub fn fold_comments<'a, F: Fold<'a> + ?Sized>(folder: &mut F, node: Comments<'a>) -> Comments<'a> {
+    Comments {
+        leading: node
+            .leading
+            .into_iter()
+            .map(|i| folder.fold_comment(i))
+            .collect(),
+        trailing: node
+            .trailing
+            .into_iter()
+            .map(|i| folder.fold_comment(i))
+            .collect(),
+    }
+}
+
+/// 依次折叠恢复解析时跳过的错误节点的子节点，返回由折叠结果组成的新节点。
+pub fn fold_error_node<'a, F: Fold<'a> + ?Sized>(
+    folder: &mut F,
+    node: ErrorNode<'a>,
+) -> ErrorNode<'a> {
+    ErrorNode {
+        comments: folder.fold_comments(node.comments),
+        ..node
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_str, Expression, Literal};
+
+    struct Identity;
+
+    impl<'a> Fold<'a> for Identity {}
+
+    /// 把整数字面量加上一个数。
+    struct Add(u32);
+
+    impl<'a> Fold<'a> for Add {
+        fn fold_literal(&mut self, node: Literal<'a>) -> Literal<'a> {
+            match node {
+                Literal::Integer(i) => Literal::Integer(i + self.0),
+                node => node,
+            }
+        }
+    }
+
+    /// 把整数字面量乘以一个数，不进入Lambda表达式。
+    struct Mul(u32);
+
+    impl<'a> Fold<'a> for Mul {
+        fn fold_expression(&mut self, node: Expression<'a>) -> Expression<'a> {
+            match node {
+                Expression::Literal(Literal::Integer(i)) => {
+                    Expression::Literal(Literal::Integer(i * self.0))
+                }
+                node @ Expression::Lambda { .. } => node,
+                node => fold_expression(self, node),
+            }
+        }
+    }
+
+    #[test]
+    fn test_fold() {
+        let source = r#"
+package a.b;
+import java.util.List;
+// 类
+@A(x = {1, @B})
+class C<T extends D> extends E<T> implements F {
+    int a[] = {1, 2}; // 尾随
+    C(int b) { this(b, 1); }
+    <U> void f(List<? extends U> list) throws X {
+        for (int i = 0; i < 10; i++) {
+            try (var r = open()) { g(i); } catch (X | Y e) { } finally { }
+        }
+        Object o = switch (a) { case 1 -> new int[] {1}; default -> { yield (int) b; } };
+        if (o instanceof P(var x, _)) { Runnable r = () -> h(x); }
+    }
+}
+"#;
+        let unit = parse_str(source).unwrap();
+        assert_eq!(
+            Identity.fold_compilation_unit_declaration(unit.clone()),
+            unit
+        );
+
+        let unit = parse_str("class A { int a = 1 + f(2, () -> 3); }").unwrap();
+        let folded = Add(1)
+            .then(Mul(10))
+            .fold_compilation_unit_declaration(unit.clone());
+        assert_eq!(
+            folded,
+            parse_str("class A { int a = 20 + f(30, () -> 4); }").unwrap()
+        );
+        let folded = Mul(10).then(Add(1)).fold_compilation_unit_declaration(unit);
+        assert_eq!(
+            folded,
+            parse_str("class A { int a = 11 + f(21, () -> 4); }").unwrap()
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use super::Expression;
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Annotation表示Java中的注解，例如`@Override`或`@SuppressWarnings("unchecked")`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Annotation<'a> {
+    /// 注解类型的名称。
+    pub name: Cow<'a, str>,
+    /// 注解的元素，`None`表示标记注解（没有括号）。
+    pub element: Option<AnnotationElement<'a>>,
+}
+
+impl<'a> Display for Annotation<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "@{}", self.name)?;
+        if let Some(ref element) = self.element {
+            write!(f, "({})", element)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// AnnotationElement表示注解括号中的内容。
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnnotationElement<'a> {
+    /// 单元素注解，例如`@A(1)`，等价于`@A(value = 1)`。
+    Value(ElementValue<'a>),
+    /// 普通注解的元素值对列表，例如`@A(x = 1, y = 2)`，可以为空。
+    Pairs(Vec<ElementValuePair<'a>>),
+}
+
+impl<'a> Display for AnnotationElement<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Value(v) => Display::fmt(v, f),
+            Self::Pairs(pairs) => {
+                for (i, p) in pairs.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    Display::fmt(p, f)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// ElementValuePair表示注解中的元素值对`name = value`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct ElementValuePair<'a> {
+    /// 元素的名称。
+    pub name: Cow<'a, str>,
+    /// 元素的值。
+    pub value: ElementValue<'a>,
+}
+
+impl<'a> Display for ElementValuePair<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} = {}", self.name, self.value)
+    }
+}
+
+/// ElementValue表示注解元素的值。
+#[derive(Clone, Debug, PartialEq)]
+pub enum ElementValue<'a> {
+    /// 常量表达式。
+    Expression(Expression<'a>),
+    /// 嵌套的注解。
+    Annotation(Box<Annotation<'a>>),
+    /// 数组形式的值`{a, b}`。
+    Array(Vec<ElementValue<'a>>),
+}
+
+impl<'a> Display for ElementValue<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Expression(e) => Display::fmt(e, f),
+            Self::Annotation(a) => Display::fmt(a, f),
+            Self::Array(values) => {
+                write!(f, "{{")?;
+                for (i, v) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    Display::fmt(v, f)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use super::{
+    write_commented, write_dangling_comments, Comment, Comments, ExplicitConstructorInvocation,
+    Statement,
+};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Block表示由大括号包围的代码块，例如方法体。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Block<'a> {
+    /// 代码块中的语句。
+    pub statements: Vec<Statement<'a>>,
+    /// 每条语句关联的普通注释，和`statements`一一对应；手动构造的代码块可以为空。
+    pub comments: Vec<Comments<'a>>,
+    /// 最后一条语句之后、不属于任何语句的普通注释。
+    pub dangling: Vec<Comment<'a>>,
+}
+
+impl<'a> Display for Block<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "{{")?;
+        for (i, statement) in self.statements.iter().enumerate() {
+            write_commented(f, statement, "", self.comments.get(i))?;
+        }
+        write_dangling_comments(f, &self.dangling)?;
+        write!(f, "}}")
+    }
+}
+
+/// ConstructorBody表示构造函数体。
+/// 构造函数体被显式构造函数调用分为前后两部分：调用之前的语句（prologue）不能引用正在构造的实例，
+/// 调用之后的语句（epilogue）则没有这个限制。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConstructorBody<'a> {
+    /// 显式构造函数调用之前的语句。
+    pub prologue: Vec<Statement<'a>>,
+    /// 显式调用本类或父类的构造函数，`None`表示隐式调用`super()`。
+    pub invocation: Option<ExplicitConstructorInvocation<'a>>,
+    /// 显式构造函数调用之后的语句，没有显式调用时所有语句都在这里。
+    pub epilogue: Vec<Statement<'a>>,
+    /// 依次对应`prologue`中的语句、显式构造函数调用（如果有）和`epilogue`中的语句的普通注释；
+    /// 手动构造的构造函数体可以为空。
+    pub comments: Vec<Comments<'a>>,
+    /// 最后一条语句之后、不属于任何语句的普通注释。
+    pub dangling: Vec<Comment<'a>>,
+}
+
+impl<'a> Display for ConstructorBody<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "{{")?;
+        let mut comments = self.comments.iter();
+        for i in &self.prologue {
+            write_commented(f, i, "", comments.next())?;
+        }
+        if let Some(ref i) = self.invocation {
+            write_commented(f, i, "", comments.next())?;
+        }
+        for i in &self.epilogue {
+            write_commented(f, i, "", comments.next())?;
+        }
+        write_dangling_comments(f, &self.dangling)?;
+        write!(f, "}}")
+    }
+}
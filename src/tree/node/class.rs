@@ -0,0 +1,406 @@
+use super::{
+    write_commented, write_dangling_comments, write_list, Annotation, AnnotationMethod, Comment,
+    Commented, Comments, ConstructorDeclaration, DocumentationComment, ErrorNode, Expression,
+    FieldDeclaration, Initializer, MethodDeclaration, Modifier, ReferenceType, Type,
+};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// ClassDeclaration表示普通类的声明。
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClassDeclaration<'a> {
+    /// 类的名称。
+    pub name: Cow<'a, str>,
+    /// 类的修饰符。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到类的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 类型参数。
+    pub type_parameters: Vec<TypeParameter<'a>>,
+    /// 继承的父类。
+    pub extends: Option<ReferenceType<'a>>,
+    /// 实现的接口。
+    pub implements: Vec<ReferenceType<'a>>,
+    /// 密封类允许的子类。
+    pub permits: Vec<ReferenceType<'a>>,
+    /// 类体。
+    pub body: ClassBody<'a>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for ClassDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        write!(f, "class {}", self.name)?;
+        write_type_parameters(f, &self.type_parameters)?;
+        if let Some(ref extends) = self.extends {
+            write!(f, " extends {}", extends)?;
+        }
+        if !self.implements.is_empty() {
+            write!(f, " implements ")?;
+            write_list(f, &self.implements, ", ")?;
+        }
+        if !self.permits.is_empty() {
+            write!(f, " permits ")?;
+            write_list(f, &self.permits, ", ")?;
+        }
+        write!(f, " {}", self.body)
+    }
+}
+
+/// InterfaceDeclaration表示普通接口的声明。
+#[derive(Clone, Debug, PartialEq)]
+pub struct InterfaceDeclaration<'a> {
+    /// 接口的名称。
+    pub name: Cow<'a, str>,
+    /// 接口的修饰符。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到接口的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 类型参数。
+    pub type_parameters: Vec<TypeParameter<'a>>,
+    /// 继承的接口。
+    pub extends: Vec<ReferenceType<'a>>,
+    /// 密封接口允许的子类型。
+    pub permits: Vec<ReferenceType<'a>>,
+    /// 接口体。
+    pub body: ClassBody<'a>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for InterfaceDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        write!(f, "interface {}", self.name)?;
+        write_type_parameters(f, &self.type_parameters)?;
+        if !self.extends.is_empty() {
+            write!(f, " extends ")?;
+            write_list(f, &self.extends, ", ")?;
+        }
+        if !self.permits.is_empty() {
+            write!(f, " permits ")?;
+            write_list(f, &self.permits, ", ")?;
+        }
+        write!(f, " {}", self.body)
+    }
+}
+
+/// EnumDeclaration表示枚举类的声明。
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumDeclaration<'a> {
+    /// 枚举的名称。
+    pub name: Cow<'a, str>,
+    /// 枚举的修饰符。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到枚举的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 实现的接口。
+    pub implements: Vec<ReferenceType<'a>>,
+    /// 枚举常量。
+    pub constants: Vec<EnumConstantDeclaration<'a>>,
+    /// 枚举常量之后的字段、方法和构造函数等声明。
+    pub body: ClassBody<'a>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for EnumDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        write!(f, "enum {}", self.name)?;
+        if !self.implements.is_empty() {
+            write!(f, " implements ")?;
+            write_list(f, &self.implements, ", ")?;
+        }
+        writeln!(f, " {{")?;
+        for (i, constant) in self.constants.iter().enumerate() {
+            let suffix = if i + 1 < self.constants.len() {
+                ","
+            } else {
+                ";"
+            };
+            write_commented(f, constant, suffix, Some(&constant.comments))?;
+        }
+        if self.constants.is_empty() {
+            writeln!(f, ";")?;
+        }
+        self.body.write_members(f)?;
+        write!(f, "}}")
+    }
+}
+
+/// EnumConstantDeclaration表示枚举常量的声明。
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumConstantDeclaration<'a> {
+    /// 常量的名称。
+    pub name: Cow<'a, str>,
+    /// 应用到常量的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 传递给构造函数的参数，`None`表示没有括号。
+    pub arguments: Option<Vec<Expression<'a>>>,
+    /// 常量的类体。
+    pub body: Option<ClassBody<'a>>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for EnumConstantDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &[])?;
+        write!(f, "{}", self.name)?;
+        if let Some(ref arguments) = self.arguments {
+            write!(f, "(")?;
+            write_list(f, arguments, ", ")?;
+            write!(f, ")")?;
+        }
+        if let Some(ref body) = self.body {
+            write!(f, " {}", body)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// RecordDeclaration表示记录类的声明。
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordDeclaration<'a> {
+    /// 记录的名称。
+    pub name: Cow<'a, str>,
+    /// 记录的修饰符。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到记录的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 类型参数。
+    pub type_parameters: Vec<TypeParameter<'a>>,
+    /// 记录的组件。
+    pub components: Vec<RecordComponent<'a>>,
+    /// 实现的接口。
+    pub implements: Vec<ReferenceType<'a>>,
+    /// 记录体。
+    pub body: ClassBody<'a>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for RecordDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        write!(f, "record {}", self.name)?;
+        write_type_parameters(f, &self.type_parameters)?;
+        write!(f, "(")?;
+        write_list(f, &self.components, ", ")?;
+        write!(f, ")")?;
+        if !self.implements.is_empty() {
+            write!(f, " implements ")?;
+            write_list(f, &self.implements, ", ")?;
+        }
+        write!(f, " {}", self.body)
+    }
+}
+
+/// RecordComponent表示记录类的一个组件。
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordComponent<'a> {
+    /// 应用到组件的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 组件的类型。
+    pub r#type: Type<'a>,
+    /// 是否为可变参数组件。
+    pub varargs: bool,
+    /// 组件的名称。
+    pub name: Cow<'a, str>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+}
+
+impl<'a> Display for RecordComponent<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &[])?;
+        write!(f, "{}", self.r#type)?;
+        if self.varargs {
+            write!(f, "...")?;
+        }
+        write!(f, " {}", self.name)
+    }
+}
+
+/// AnnotationDeclaration表示注解接口的声明`@interface`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnnotationDeclaration<'a> {
+    /// 注解接口的名称。
+    pub name: Cow<'a, str>,
+    /// 注解接口的修饰符。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到注解接口的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 注解接口体。
+    pub body: ClassBody<'a>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for AnnotationDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        write!(f, "@interface {} {}", self.name, self.body)
+    }
+}
+
+/// TypeParameter表示泛型声明中的类型参数，例如`T extends Comparable<T>`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeParameter<'a> {
+    /// 类型参数的名称。
+    pub name: Cow<'a, str>,
+    /// 类型参数的上界。
+    pub extends: Vec<ReferenceType<'a>>,
+}
+
+impl<'a> Display for TypeParameter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name)?;
+        if !self.extends.is_empty() {
+            write!(f, " extends ")?;
+            write_list(f, &self.extends, " & ")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// ClassBody表示类、接口、枚举、记录或匿名类的类体。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ClassBody<'a> {
+    /// 类体中的成员声明。
+    pub declarations: Vec<ClassBodyDeclaration<'a>>,
+    /// 最后一个成员之后、不属于任何成员的普通注释。
+    pub dangling: Vec<Comment<'a>>,
+}
+
+impl<'a> ClassBody<'a> {
+    /// 逐行输出成员声明和它们的注释。
+    pub(crate) fn write_members(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for i in &self.declarations {
+            write_commented(f, i, "", Some(i.comments()))?;
+        }
+        write_dangling_comments(f, &self.dangling)
+    }
+}
+
+impl<'a> Display for ClassBody<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "{{")?;
+        self.write_members(f)?;
+        write!(f, "}}")
+    }
+}
+
+/// ClassBodyDeclaration表示类体中的一个成员声明。
+#[derive(Clone, Debug, PartialEq)]
+pub enum ClassBodyDeclaration<'a> {
+    /// 字段声明。
+    Field(FieldDeclaration<'a>),
+    /// 方法声明。
+    Method(MethodDeclaration<'a>),
+    /// 构造函数声明。
+    Constructor(ConstructorDeclaration<'a>),
+    /// 实例或静态初始化块。
+    Initializer(Initializer<'a>),
+    /// 注解接口的元素声明。
+    AnnotationMethod(AnnotationMethod<'a>),
+    /// 成员类。
+    Class(ClassDeclaration<'a>),
+    /// 成员接口。
+    Interface(InterfaceDeclaration<'a>),
+    /// 成员枚举。
+    Enum(EnumDeclaration<'a>),
+    /// 成员记录。
+    Record(RecordDeclaration<'a>),
+    /// 成员注解接口。
+    Annotation(AnnotationDeclaration<'a>),
+    /// 恢复解析时跳过的无法解析的成员声明。
+    Error(ErrorNode<'a>),
+}
+
+impl<'a> Display for ClassBodyDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Field(d) => Display::fmt(d, f),
+            Self::Method(d) => Display::fmt(d, f),
+            Self::Constructor(d) => Display::fmt(d, f),
+            Self::Initializer(d) => Display::fmt(d, f),
+            Self::AnnotationMethod(d) => Display::fmt(d, f),
+            Self::Class(d) => Display::fmt(d, f),
+            Self::Interface(d) => Display::fmt(d, f),
+            Self::Enum(d) => Display::fmt(d, f),
+            Self::Record(d) => Display::fmt(d, f),
+            Self::Annotation(d) => Display::fmt(d, f),
+            Self::Error(d) => Display::fmt(d, f),
+        }
+    }
+}
+
+/// 依次输出注解和修饰符，每项后面跟一个空格。
+pub(crate) fn write_modifiers(
+    f: &mut Formatter<'_>,
+    annotations: &[Annotation],
+    modifiers: &[Modifier],
+) -> FmtResult {
+    for i in annotations {
+        write!(f, "{} ", i)?;
+    }
+    for i in modifiers {
+        write!(f, "{} ", i)?;
+    }
+
+    Ok(())
+}
+
+/// 输出类型参数列表`<T, U>`，列表为空时不输出任何内容。
+pub(crate) fn write_type_parameters(
+    f: &mut Formatter<'_>,
+    type_parameters: &[TypeParameter],
+) -> FmtResult {
+    if type_parameters.is_empty() {
+        return Ok(());
+    }
+    write!(f, "<")?;
+    write_list(f, type_parameters, ", ")?;
+    write!(f, ">")
+}
@@ -0,0 +1,175 @@
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Comment表示普通注释（单行注释`//`或多行注释`/* */`），文档注释由[`super::DocumentationComment`]表示。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comment<'a> {
+    /// 注释的内容，不包括注释符号。
+    pub text: Cow<'a, str>,
+    /// 是否为单行注释。
+    pub single_line: bool,
+}
+
+impl<'a> Display for Comment<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.single_line {
+            write!(f, "//{}", self.text)
+        } else {
+            write!(f, "/*{}*/", self.text)
+        }
+    }
+}
+
+/// Comments表示关联到一个节点的普通注释。
+///
+/// 节点前面独占一行（或多行）的注释是前导注释，节点之后、换行之前的注释是尾随注释，
+/// 例如`Object obj = null; // 空值`中的`// 空值`是这条语句的尾随注释。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Comments<'a> {
+    /// 前导注释。
+    pub leading: Vec<Comment<'a>>,
+    /// 尾随注释。
+    pub trailing: Vec<Comment<'a>>,
+}
+
+impl<'a> Comments<'a> {
+    /// 是否没有任何注释。
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && self.trailing.is_empty()
+    }
+}
+
+/// Commented表示可以关联普通注释的节点。
+///
+/// 类型声明、成员声明、枚举常量、包声明、模块声明和`switch`块中的语句组和规则都实现了该特征；
+/// 代码块中语句的注释保存在[`super::Block::comments`]中，导入声明的注释保存在
+/// [`super::CompilationUnitDeclaration::import_comments`]中，
+/// 类体和代码块中不属于任何节点的注释保存在它们的`dangling`字段中。
+pub trait Commented<'a> {
+    /// 获取关联到节点的注释。
+    fn comments(&self) -> &Comments<'a>;
+
+    /// 获取关联到节点的注释的可变引用。
+    fn comments_mut(&mut self) -> &mut Comments<'a>;
+}
+
+/// 为带有`comments`字段的节点实现[`Commented`]。
+macro_rules! impl_commented {
+    ($($t:ident),*) => {
+        $(impl<'a> Commented<'a> for super::$t<'a> {
+            fn comments(&self) -> &Comments<'a> {
+                &self.comments
+            }
+
+            fn comments_mut(&mut self) -> &mut Comments<'a> {
+                &mut self.comments
+            }
+        })*
+    };
+}
+
+impl_commented!(
+    PackageDeclaration,
+    ModuleDeclaration,
+    ClassDeclaration,
+    InterfaceDeclaration,
+    EnumDeclaration,
+    EnumConstantDeclaration,
+    RecordDeclaration,
+    AnnotationDeclaration,
+    FieldDeclaration,
+    MethodDeclaration,
+    ConstructorDeclaration,
+    Initializer,
+    AnnotationMethod,
+    SwitchGroup,
+    SwitchRule,
+    ErrorNode
+);
+
+/// 依次输出前导注释、节点、`suffix`和尾随注释，最后换行。
+pub(crate) fn write_commented<T: Display>(
+    f: &mut Formatter<'_>,
+    node: &T,
+    suffix: &str,
+    comments: Option<&Comments>,
+) -> FmtResult {
+    let Some(comments) = comments else {
+        return writeln!(f, "{}{}", node, suffix);
+    };
+    write_dangling_comments(f, &comments.leading)?;
+    write!(f, "{}{}", node, suffix)?;
+    for i in &comments.trailing {
+        write!(f, " {}", i)?;
+    }
+    writeln!(f)
+}
+
+/// 输出独占一行的注释。
+pub(crate) fn write_dangling_comments(f: &mut Formatter<'_>, comments: &[Comment]) -> FmtResult {
+    for i in comments {
+        writeln!(f, "{}", i)?;
+    }
+
+    Ok(())
+}
+
+impl<'a> Commented<'a> for super::ClassBodyDeclaration<'a> {
+    fn comments(&self) -> &Comments<'a> {
+        match self {
+            Self::Field(d) => d.comments(),
+            Self::Method(d) => d.comments(),
+            Self::Constructor(d) => d.comments(),
+            Self::Initializer(d) => d.comments(),
+            Self::AnnotationMethod(d) => d.comments(),
+            Self::Class(d) => d.comments(),
+            Self::Interface(d) => d.comments(),
+            Self::Enum(d) => d.comments(),
+            Self::Record(d) => d.comments(),
+            Self::Annotation(d) => d.comments(),
+            Self::Error(d) => d.comments(),
+        }
+    }
+
+    fn comments_mut(&mut self) -> &mut Comments<'a> {
+        match self {
+            Self::Field(d) => d.comments_mut(),
+            Self::Method(d) => d.comments_mut(),
+            Self::Constructor(d) => d.comments_mut(),
+            Self::Initializer(d) => d.comments_mut(),
+            Self::AnnotationMethod(d) => d.comments_mut(),
+            Self::Class(d) => d.comments_mut(),
+            Self::Interface(d) => d.comments_mut(),
+            Self::Enum(d) => d.comments_mut(),
+            Self::Record(d) => d.comments_mut(),
+            Self::Annotation(d) => d.comments_mut(),
+            Self::Error(d) => d.comments_mut(),
+        }
+    }
+}
+
+impl<'a> Commented<'a> for super::TopLevelClassOrInterfaceDeclaration<'a> {
+    fn comments(&self) -> &Comments<'a> {
+        match self {
+            Self::Class(d) => d.comments(),
+            Self::Interface(d) => d.comments(),
+            Self::Enum(d) => d.comments(),
+            Self::Record(d) => d.comments(),
+            Self::Annotation(d) => d.comments(),
+            Self::Error(d) => d.comments(),
+        }
+    }
+
+    fn comments_mut(&mut self) -> &mut Comments<'a> {
+        match self {
+            Self::Class(d) => d.comments_mut(),
+            Self::Interface(d) => d.comments_mut(),
+            Self::Enum(d) => d.comments_mut(),
+            Self::Record(d) => d.comments_mut(),
+            Self::Annotation(d) => d.comments_mut(),
+            Self::Error(d) => d.comments_mut(),
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
-use super::{ImportDeclaration, ModuleDeclaration, PackageDeclaration, TopLevelClassOrInterfaceDeclaration};
+use super::{
+    write_commented, write_dangling_comments, ClassDeclaration, Commented, Comments,
+    ImportDeclaration, ModuleDeclaration, PackageDeclaration, TopLevelClassOrInterfaceDeclaration,
+};
 
 /// CompilationUnitDeclaration表示一个编译单元，它是Java程序语法语法的终极符号。
-/// 它可以是普通编译单元或模块编译单元。
-#[derive(Debug)]
+/// 它可以是普通编译单元、模块编译单元或隐式声明类的编译单元。
+#[derive(Clone, Debug, PartialEq)]
 pub enum CompilationUnitDeclaration<'a> {
     /// 表示一个普通编译单元。
     /// 它由可选的包声明、import声明和顶层类或接口声明组成。
@@ -12,6 +15,8 @@ pub enum CompilationUnitDeclaration<'a> {
         package: Option<PackageDeclaration<'a>>,
         /// Import声明，允许使用简单名称引用其他包中的类和接口。
         imports: Vec<ImportDeclaration<'a>>,
+        /// 每个Import声明关联的普通注释，和`imports`一一对应；手动构造的编译单元可以为空。
+        import_comments: Vec<Comments<'a>>,
         /// 类和接口的顶层声明。
         top_level_class_or_interfaces: Vec<TopLevelClassOrInterfaceDeclaration<'a>>,
     },
@@ -20,14 +25,26 @@ pub enum CompilationUnitDeclaration<'a> {
     Modular {
         /// Import声明，允许在模块声明中引用此模块和其它模块中的包中的类和接口。
         imports: Vec<ImportDeclaration<'a>>,
+        /// 每个Import声明关联的普通注释，和`imports`一一对应。
+        import_comments: Vec<Comments<'a>>,
         /// 模块声明，指定编译单元所属的模块。
         module: ModuleDeclaration<'a>,
     },
+    /// 表示一个隐式声明类的编译单元（预览特性）。
+    /// 它由import声明和不属于任何类声明的顶层字段、方法和类型组成，例如只包含`void main() {}`的源文件。
+    Implicit {
+        /// Import声明。
+        imports: Vec<ImportDeclaration<'a>>,
+        /// 每个Import声明关联的普通注释，和`imports`一一对应。
+        import_comments: Vec<Comments<'a>>,
+        /// 容纳所有顶层成员的合成类，它的名称为空（实际名称由源文件名决定），修饰符为`final`。
+        class: ClassDeclaration<'a>,
+    },
 }
 
 impl<'a> CompilationUnitDeclaration<'a> {
     /// 获取包声明
-    pub fn package(&self) -> Option<&PackageDeclaration> {
+    pub fn package(&self) -> Option<&PackageDeclaration<'a>> {
         if let Self::Ordinary {
             package: Some(package),
             ..
@@ -39,9 +56,20 @@ impl<'a> CompilationUnitDeclaration<'a> {
     }
 
     /// 获取导入声明
-    pub fn imports(&self) -> &[ImportDeclaration] {
+    pub fn imports(&self) -> &[ImportDeclaration<'a>] {
         match self {
-            Self::Ordinary { imports, .. } | Self::Modular { imports, .. } => imports,
+            Self::Ordinary { imports, .. }
+            | Self::Modular { imports, .. }
+            | Self::Implicit { imports, .. } => imports,
+        }
+    }
+
+    /// 获取导入声明关联的普通注释，和[`CompilationUnitDeclaration::imports`]一一对应
+    pub fn import_comments(&self) -> &[Comments<'a>] {
+        match self {
+            Self::Ordinary { import_comments, .. }
+            | Self::Modular { import_comments, .. }
+            | Self::Implicit { import_comments, .. } => import_comments,
         }
     }
 }
@@ -51,9 +79,8 @@ impl<'a> Display for CompilationUnitDeclaration<'a> {
         if let Some(package) = self.package() {
             Display::fmt(package, f)?;
         }
-        for i in self.imports() {
-            Display::fmt(&i, f)?;
-            write!(f, "\n")?;
+        for (i, import) in self.imports().iter().enumerate() {
+            write_commented(f, import, "", self.import_comments().get(i))?;
         }
         if let Self::Ordinary {
             top_level_class_or_interfaces,
@@ -61,12 +88,16 @@ impl<'a> Display for CompilationUnitDeclaration<'a> {
         } = self
         {
             for i in top_level_class_or_interfaces {
-                Display::fmt(&i, f)?;
-                write!(f, "\n")?;
+                write_commented(f, i, "", Some(i.comments()))?;
             }
         }
         if let Self::Modular { module, .. } = self {
-            Display::fmt(module, f)?;
+            write_commented(f, module, "", Some(&module.comments))?;
+        }
+        if let Self::Implicit { class, .. } = self {
+            // 合成类没有类头，只输出其中的成员
+            write_dangling_comments(f, &class.comments.leading)?;
+            class.body.write_members(f)?;
         }
 
         Ok(())
@@ -4,23 +4,70 @@ use std::{
 };
 
 /// Java中的文档注释
-#[derive(Debug, PartialEq)]
-pub struct DocumentationComment<'a>(Cow<'a, str>);
+#[derive(Clone, Debug, PartialEq)]
+pub struct DocumentationComment<'a> {
+    text: Cow<'a, str>,
+    flavor: DocumentationFlavor,
+}
+
+/// DocumentationFlavor表示文档注释的写法。
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum DocumentationFlavor {
+    /// 传统的`/** ... */`注释，内容是HTML。
+    #[default]
+    JavaDoc,
+    /// 由连续的`///`行组成的注释，内容是Markdown。
+    Markdown,
+}
+
+impl<'a> DocumentationComment<'a> {
+    /// 创建一个Markdown文档注释，`text`是去掉`///`后以换行符连接的各行内容。
+    pub fn markdown<T: Into<Cow<'a, str>>>(text: T) -> Self {
+        Self {
+            text: text.into(),
+            flavor: DocumentationFlavor::Markdown,
+        }
+    }
+
+    /// 注释的内容，不包括`/**`、`*/`和`///`。
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// 注释的写法。
+    pub fn flavor(&self) -> DocumentationFlavor {
+        self.flavor
+    }
+}
 
 impl<'a> Display for DocumentationComment<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "/**{}*/\n", self.0)
+        match self.flavor {
+            DocumentationFlavor::JavaDoc => writeln!(f, "/**{}*/", self.text),
+            DocumentationFlavor::Markdown => {
+                for line in self.text.split('\n') {
+                    writeln!(f, "///{}", line)?;
+                }
+                Ok(())
+            }
+        }
     }
 }
 
 impl<'a> From<&'a str> for DocumentationComment<'a> {
     fn from(value: &'a str) -> Self {
-        Self (value.into())
+        Self {
+            text: value.into(),
+            flavor: DocumentationFlavor::JavaDoc,
+        }
     }
 }
 
 impl<'a> From<String> for DocumentationComment<'a> {
     fn from(value: String) -> Self {
-        Self(value.into())
+        Self {
+            text: value.into(),
+            flavor: DocumentationFlavor::JavaDoc,
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,44 @@
+use super::Comments;
+use crate::Token;
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Range,
+};
+
+/// ErrorNode表示恢复解析时跳过的一段无法解析的标记。
+///
+/// 只有恢复模式（见[`crate::parse_str_recovering`]）才会产生该节点，它占据原本语句、成员声明或顶层声明的位置，
+/// 输出时原样输出跳过的标记。
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorNode<'a> {
+    /// 跳过的标记。
+    pub tokens: Vec<Token>,
+    /// 跳过的标记在源代码中的字节范围，手动构造的标记流没有位置信息。
+    pub span: Option<Range<usize>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for ErrorNode<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (i, token) in self.tokens.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", token)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// MissingToken表示恢复解析时补上的源代码中缺失的标记，例如`foo(;`中缺少的`)`。
+///
+/// 补上标记后解析出的节点和正常的节点没有区别，可以通过[`crate::RecoveredParse::missing`]区分它们。
+#[derive(Clone, Debug, PartialEq)]
+pub struct MissingToken {
+    /// 补上的标记。
+    pub token: Token,
+    /// 补上的位置在源代码中的字节偏移，手动构造的标记流没有位置信息。
+    pub offset: Option<usize>,
+}
@@ -0,0 +1,724 @@
+use super::{
+    write_list, ArrayInitializer, Block, ClassBody, FormalParameter, InstanceOfTarget,
+    ReferenceType, Switch, Type, TypeArgument,
+};
+use crate::{write_char_literal, Token};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Expression表示Java中的表达式。
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expression<'a> {
+    /// 字面量，例如`1`、`"abc"`、`null`。
+    Literal(Literal<'a>),
+    /// 表达式名称，可以是限定名称，例如`a`或`System.out`。
+    Name(Cow<'a, str>),
+    /// `this`
+    This,
+    /// 限定的`this`，例如`Outer.this`。
+    QualifiedThis(Cow<'a, str>),
+    /// 字段访问，例如`a().b`，简单的限定名称使用`Name`表示。
+    FieldAccess {
+        /// 被访问的对象。
+        target: Box<Expression<'a>>,
+        /// 字段的名称。
+        name: Cow<'a, str>,
+    },
+    /// 父类字段访问，例如`super.a`或`Outer.super.a`。
+    SuperFieldAccess {
+        /// `super`前面的限定名称。
+        qualifier: Option<Cow<'a, str>>,
+        /// 字段的名称。
+        name: Cow<'a, str>,
+    },
+    /// 方法调用，例如`foo(1)`、`a.b.foo()`或`Collections.<T>emptyList()`。
+    MethodInvocation {
+        /// 调用方法的对象，`None`表示不带限定的调用。
+        target: Option<Box<Expression<'a>>>,
+        /// 显式指定的类型参数。
+        type_arguments: Vec<TypeArgument<'a>>,
+        /// 方法的名称。
+        name: Cow<'a, str>,
+        /// 实际参数。
+        arguments: Vec<Expression<'a>>,
+    },
+    /// 父类方法调用，例如`super.foo()`或`Interface.super.foo()`。
+    SuperMethodInvocation {
+        /// `super`前面的限定名称。
+        qualifier: Option<Cow<'a, str>>,
+        /// 显式指定的类型参数。
+        type_arguments: Vec<TypeArgument<'a>>,
+        /// 方法的名称。
+        name: Cow<'a, str>,
+        /// 实际参数。
+        arguments: Vec<Expression<'a>>,
+    },
+    /// 数组访问`array[index]`。
+    ArrayAccess {
+        /// 被访问的数组。
+        array: Box<Expression<'a>>,
+        /// 下标。
+        index: Box<Expression<'a>>,
+    },
+    /// 类字面量，例如`String.class`或`int[].class`。
+    ClassLiteral(Type<'a>),
+    /// `void.class`
+    VoidClassLiteral,
+    /// 创建类的实例，例如`new ArrayList<>()`、`outer.new Inner()`或带有匿名类体的创建。
+    ClassCreation {
+        /// 内部类创建时的外部对象。
+        outer: Option<Box<Expression<'a>>>,
+        /// 构造函数的类型参数，例如`new <String>Foo()`。
+        type_arguments: Vec<TypeArgument<'a>>,
+        /// 被创建的类型。
+        r#type: ReferenceType<'a>,
+        /// 实际参数。
+        arguments: Vec<Expression<'a>>,
+        /// 匿名类的类体。
+        body: Option<ClassBody<'a>>,
+    },
+    /// 创建数组，例如`new int[3][]`或`new int[] {1, 2}`。
+    ArrayCreation {
+        /// 数组的类型（包括所有维度）。
+        r#type: Type<'a>,
+        /// 指定了长度的维度。
+        dimensions: Vec<Expression<'a>>,
+        /// 数组初始化器。
+        initializer: Option<ArrayInitializer<'a>>,
+    },
+    /// 括号表达式`(expression)`。
+    Parenthesized(Box<Expression<'a>>),
+    /// 赋值表达式，包括复合赋值，例如`a += 1`。
+    Assignment {
+        /// 被赋值的表达式。
+        target: Box<Expression<'a>>,
+        /// 赋值运算符。
+        operator: AssignmentOperator,
+        /// 赋予的值。
+        value: Box<Expression<'a>>,
+    },
+    /// 条件表达式`condition ? if_true : if_false`。
+    Ternary {
+        /// 条件。
+        condition: Box<Expression<'a>>,
+        /// 条件为真时的值。
+        if_true: Box<Expression<'a>>,
+        /// 条件为假时的值。
+        if_false: Box<Expression<'a>>,
+    },
+    /// 二元运算，例如`a + b`、`a && b`。
+    Binary {
+        /// 运算符。
+        operator: BinaryOperator,
+        /// 左操作数。
+        left: Box<Expression<'a>>,
+        /// 右操作数。
+        right: Box<Expression<'a>>,
+    },
+    /// Lambda表达式，例如`(a, b) -> a + b`或`x -> { return x; }`。
+    Lambda {
+        /// 参数列表。
+        parameters: LambdaParameters<'a>,
+        /// Lambda体。
+        body: LambdaBody<'a>,
+    },
+    /// 方法引用，例如`String::valueOf`、`this::run`、`super::toString`或`int[]::new`。
+    MethodReference {
+        /// `::`前面的部分。
+        target: MethodReferenceTarget<'a>,
+        /// 显式指定的类型参数，例如`List::<String>of`。
+        type_arguments: Vec<TypeArgument<'a>>,
+        /// 方法的名称，构造函数引用的名称为`new`。
+        name: Cow<'a, str>,
+    },
+    /// `switch`表达式。
+    Switch(Switch<'a>),
+    /// 类型比较`expression instanceof Type`或模式匹配`expression instanceof Type t`。
+    InstanceOf {
+        /// 被比较的表达式。
+        expression: Box<Expression<'a>>,
+        /// 比较的类型或模式。
+        target: InstanceOfTarget<'a>,
+    },
+    /// 前缀一元运算，例如`-a`、`!a`、`++a`。
+    Unary {
+        /// 运算符。
+        operator: UnaryOperator,
+        /// 操作数。
+        operand: Box<Expression<'a>>,
+    },
+    /// 后缀运算`a++`或`a--`。
+    Postfix {
+        /// 运算符。
+        operator: PostfixOperator,
+        /// 操作数。
+        operand: Box<Expression<'a>>,
+    },
+    /// 类型转换，例如`(int) a`或交集类型转换`(Runnable & Serializable) a`。
+    Cast {
+        /// 目标类型。
+        r#type: Type<'a>,
+        /// 交集类型转换的附加接口类型。
+        bounds: Vec<ReferenceType<'a>>,
+        /// 被转换的表达式。
+        expression: Box<Expression<'a>>,
+    },
+}
+
+impl<'a> Display for Expression<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Literal(l) => Display::fmt(l, f),
+            Self::Name(n) => write!(f, "{}", n),
+            Self::This => write!(f, "{}", Token::THIS),
+            Self::QualifiedThis(q) => write!(f, "{}.{}", q, Token::THIS),
+            Self::FieldAccess { target, name } => write!(f, "{}.{}", target, name),
+            Self::SuperFieldAccess { qualifier, name } => {
+                if let Some(q) = qualifier {
+                    write!(f, "{}.", q)?;
+                }
+                write!(f, "{}.{}", Token::SUPER, name)
+            }
+            Self::MethodInvocation {
+                target,
+                type_arguments,
+                name,
+                arguments,
+            } => {
+                if let Some(t) = target {
+                    write!(f, "{}.", t)?;
+                }
+                write_invocation(f, type_arguments, name, arguments)
+            }
+            Self::SuperMethodInvocation {
+                qualifier,
+                type_arguments,
+                name,
+                arguments,
+            } => {
+                if let Some(q) = qualifier {
+                    write!(f, "{}.", q)?;
+                }
+                write!(f, "{}.", Token::SUPER)?;
+                write_invocation(f, type_arguments, name, arguments)
+            }
+            Self::ArrayAccess { array, index } => write!(f, "{}[{}]", array, index),
+            Self::ClassLiteral(t) => write!(f, "{}.{}", t, Token::CLASS),
+            Self::VoidClassLiteral => write!(f, "{}.{}", Token::VOID, Token::CLASS),
+            Self::ClassCreation {
+                outer,
+                type_arguments,
+                r#type,
+                arguments,
+                body,
+            } => {
+                if let Some(o) = outer {
+                    write!(f, "{}.", o)?;
+                }
+                write!(f, "{} ", Token::NEW)?;
+                if !type_arguments.is_empty() {
+                    write!(f, "<")?;
+                    write_list(f, type_arguments, ", ")?;
+                    write!(f, ">")?;
+                }
+                write!(f, "{}(", r#type)?;
+                write_list(f, arguments, ", ")?;
+                write!(f, ")")?;
+                if let Some(b) = body {
+                    write!(f, " {}", b)?;
+                }
+
+                Ok(())
+            }
+            Self::ArrayCreation {
+                r#type,
+                dimensions,
+                initializer,
+            } => {
+                // 先写出元素类型，再按顺序写出有长度和没有长度的维度
+                let mut element = r#type;
+                while let Type::Array(t) = element {
+                    element = t;
+                }
+                write!(f, "{} {}", Token::NEW, element)?;
+                for i in dimensions {
+                    write!(f, "[{}]", i)?;
+                }
+                for _ in dimensions.len()..r#type.dimensions() {
+                    write!(f, "[]")?;
+                }
+                if let Some(i) = initializer {
+                    write!(f, " {}", i)?;
+                }
+
+                Ok(())
+            }
+            Self::Parenthesized(e) => write!(f, "({})", e),
+            Self::Assignment {
+                target,
+                operator,
+                value,
+            } => write!(f, "{} {} {}", target, operator, value),
+            Self::Ternary {
+                condition,
+                if_true,
+                if_false,
+            } => write!(f, "{} ? {} : {}", condition, if_true, if_false),
+            Self::Binary {
+                operator,
+                left,
+                right,
+            } => write!(f, "{} {} {}", left, operator, right),
+            Self::Lambda { parameters, body } => write!(f, "{} -> {}", parameters, body),
+            Self::MethodReference {
+                target,
+                type_arguments,
+                name,
+            } => {
+                write!(f, "{}::", target)?;
+                if !type_arguments.is_empty() {
+                    write!(f, "<")?;
+                    write_list(f, type_arguments, ", ")?;
+                    write!(f, ">")?;
+                }
+                write!(f, "{}", name)
+            }
+            Self::Switch(s) => Display::fmt(s, f),
+            Self::InstanceOf { expression, target } => {
+                write!(f, "{} instanceof {}", expression, target)
+            }
+            Self::Unary { operator, operand } => write!(f, "{}{}", operator, operand),
+            Self::Postfix { operator, operand } => write!(f, "{}{}", operand, operator),
+            Self::Cast {
+                r#type,
+                bounds,
+                expression,
+            } => {
+                write!(f, "({}", r#type)?;
+                for i in bounds {
+                    write!(f, " & {}", i)?;
+                }
+                write!(f, ") {}", expression)
+            }
+        }
+    }
+}
+
+/// LambdaParameters表示Lambda表达式的参数列表。
+#[derive(Clone, Debug, PartialEq)]
+pub enum LambdaParameters<'a> {
+    /// 省略类型的参数，例如`x -> ...`或`(a, b) -> ...`。
+    Inferred(Vec<Cow<'a, str>>),
+    /// 声明了类型的参数，例如`(int a, String b) -> ...`。
+    /// 使用`var`声明的参数的类型是名为`var`的引用类型。
+    Formal(Vec<FormalParameter<'a>>),
+}
+
+impl<'a> Display for LambdaParameters<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "(")?;
+        match self {
+            Self::Inferred(names) => write_list(f, names, ", ")?,
+            Self::Formal(parameters) => write_list(f, parameters, ", ")?,
+        }
+        write!(f, ")")
+    }
+}
+
+/// LambdaBody表示Lambda表达式的主体，可以是表达式或代码块。
+#[derive(Clone, Debug, PartialEq)]
+pub enum LambdaBody<'a> {
+    /// 表达式。
+    Expression(Box<Expression<'a>>),
+    /// 代码块。
+    Block(Block<'a>),
+}
+
+impl<'a> Display for LambdaBody<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Expression(e) => Display::fmt(e, f),
+            Self::Block(b) => Display::fmt(b, f),
+        }
+    }
+}
+
+/// MethodReferenceTarget表示方法引用中`::`前面的部分。
+#[derive(Clone, Debug, PartialEq)]
+pub enum MethodReferenceTarget<'a> {
+    /// 表达式，例如`this::run`。限定名称（例如`System.out`或`String`）既可能是表达式也可能是类型，统一表示为表达式。
+    Expression(Box<Expression<'a>>),
+    /// 只能是类型的部分，例如`List<String>::size`或`int[]::new`。
+    Type(Type<'a>),
+    /// `super`或`Outer.super`。
+    Super(Option<Cow<'a, str>>),
+}
+
+impl<'a> Display for MethodReferenceTarget<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Expression(e) => Display::fmt(e, f),
+            Self::Type(t) => Display::fmt(t, f),
+            Self::Super(qualifier) => {
+                if let Some(q) = qualifier {
+                    write!(f, "{}.", q)?;
+                }
+                write!(f, "{}", Token::SUPER)
+            }
+        }
+    }
+}
+
+fn write_invocation(
+    f: &mut Formatter<'_>,
+    type_arguments: &[TypeArgument],
+    name: &str,
+    arguments: &[Expression],
+) -> FmtResult {
+    if !type_arguments.is_empty() {
+        write!(f, "<")?;
+        write_list(f, type_arguments, ", ")?;
+        write!(f, ">")?;
+    }
+    write!(f, "{}(", name)?;
+    write_list(f, arguments, ", ")?;
+    write!(f, ")")
+}
+
+/// Literal表示Java中的字面量。
+#[derive(Clone, Debug, PartialEq)]
+pub enum Literal<'a> {
+    /// 整数字面量，`-2147483648`中的`2147483648`是一元`-`的操作数。
+    Integer(u32),
+    /// 长整数字面量，例如`1L`。
+    Long(u64),
+    /// 单精度浮点数字面量。
+    Float(f32),
+    /// 双精度浮点数字面量。
+    Double(f64),
+    /// 十六进制整数字面量。
+    Hex(u32),
+    /// 八进制整数字面量。
+    Octal(u32),
+    /// 二进制整数字面量。
+    Binary(u32),
+    /// 十六进制长整数字面量，例如`0xFFL`。
+    HexLong(u64),
+    /// 八进制长整数字面量，例如`017L`。
+    OctalLong(u64),
+    /// 二进制长整数字面量，例如`0b1L`。
+    BinaryLong(u64),
+    /// 布尔字面量。
+    Boolean(bool),
+    /// 字符字面量。
+    Char(char),
+    /// 字符串字面量。
+    String(Cow<'a, str>),
+    /// 空值字面量`null`。
+    Null,
+}
+
+impl<'a> Display for Literal<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Integer(i) => write!(f, "{}", i),
+            Self::Long(i) => write!(f, "{}L", i),
+            Self::Float(i) => write!(f, "{}f", i),
+            Self::Double(i) => write!(f, "{:?}", i),
+            Self::Hex(h) => write!(f, "0x{:x}", h),
+            Self::Octal(o) => write!(f, "0{:o}", o),
+            Self::Binary(b) => write!(f, "0b{:b}", b),
+            Self::HexLong(h) => write!(f, "0x{:x}L", h),
+            Self::OctalLong(o) => write!(f, "0{:o}L", o),
+            Self::BinaryLong(b) => write!(f, "0b{:b}L", b),
+            Self::Boolean(b) => write!(f, "{}", b),
+            Self::Char(c) => write_char_literal(f, *c),
+            Self::String(s) => write!(f, "\"{}\"", s),
+            Self::Null => write!(f, "{}", Token::NULL),
+        }
+    }
+}
+
+impl<'a> TryFrom<Token> for Literal<'a> {
+    type Error = Token;
+
+    fn try_from(value: Token) -> Result<Self, Self::Error> {
+        Ok(match value {
+            Token::IntegerLiteral(i) => Self::Integer(i),
+            Token::LongLiteral(i) => Self::Long(i),
+            Token::FloatLiteral(i) => Self::Float(i),
+            Token::DoubleLiteral(i) => Self::Double(i),
+            Token::HexLiteral(h) => Self::Hex(h),
+            Token::OctLiteral(o) => Self::Octal(o),
+            Token::BinLiteral(b) => Self::Binary(b),
+            Token::HexLongLiteral(h) => Self::HexLong(h),
+            Token::OctLongLiteral(o) => Self::OctalLong(o),
+            Token::BinLongLiteral(b) => Self::BinaryLong(b),
+            Token::BooleanLiteral(b) => Self::Boolean(b),
+            Token::CharLiteral(c) => Self::Char(c),
+            Token::StringLiteral(s) => Self::String(s.into()),
+            Token::NullLiteral => Self::Null,
+            _ => return Err(value),
+        })
+    }
+}
+
+/// AssignmentOperator表示赋值运算符。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AssignmentOperator {
+    /// `=`
+    Assign,
+    /// `+=`
+    Plus,
+    /// `-=`
+    Minus,
+    /// `*=`
+    Star,
+    /// `/=`
+    Slash,
+    /// `%=`
+    Mod,
+    /// `&=`
+    And,
+    /// `|=`
+    Or,
+    /// `^=`
+    Xor,
+    /// `<<=`
+    ShiftLeft,
+    /// `>>=`
+    ShiftRight,
+    /// `>>>=`
+    UnsignedShiftRight,
+}
+
+impl Display for AssignmentOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let text = match self {
+            Self::Assign => Token::ASSIGN,
+            Self::Plus => Token::PLUS_ASSIGN,
+            Self::Minus => Token::MINUS_ASSIGN,
+            Self::Star => Token::STAR_ASSIGN,
+            Self::Slash => Token::SLASH_ASSIGN,
+            Self::Mod => Token::MOD_ASSIGN,
+            Self::And => Token::AND_ASSIGN,
+            Self::Or => Token::OR_ASSIGN,
+            Self::Xor => Token::XOR_ASSIGN,
+            Self::ShiftLeft => Token::SHIFT_LEFT_ASSIGN,
+            Self::ShiftRight => Token::SHIFT_RIGHT_ASSIGN,
+            Self::UnsignedShiftRight => Token::UNSIGNED_SHIFT_RIGHT_ASSIGN,
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl TryFrom<&Token> for AssignmentOperator {
+    type Error = ();
+
+    fn try_from(value: &Token) -> Result<Self, Self::Error> {
+        Ok(match value {
+            Token::Assign => Self::Assign,
+            Token::PlusAssign => Self::Plus,
+            Token::MinusAssign => Self::Minus,
+            Token::StarAssign => Self::Star,
+            Token::SlashAssign => Self::Slash,
+            Token::ModAssign => Self::Mod,
+            Token::AndAssign => Self::And,
+            Token::OrAssign => Self::Or,
+            Token::XorAssign => Self::Xor,
+            Token::ShiftLeftAssign => Self::ShiftLeft,
+            Token::ShiftRightAssign => Self::ShiftRight,
+            Token::UnsignedShiftRightAssign => Self::UnsignedShiftRight,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// BinaryOperator表示二元运算符。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinaryOperator {
+    /// `||`
+    LogicalOr,
+    /// `&&`
+    LogicalAnd,
+    /// `|`
+    Or,
+    /// `^`
+    Xor,
+    /// `&`
+    And,
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    LessThan,
+    /// `>`
+    GreaterThan,
+    /// `<=`
+    LessThanOrEqual,
+    /// `>=`
+    GreaterThanOrEqual,
+    /// `<<`
+    ShiftLeft,
+    /// `>>`
+    ShiftRight,
+    /// `>>>`
+    UnsignedShiftRight,
+    /// `+`
+    Plus,
+    /// `-`
+    Minus,
+    /// `*`
+    Star,
+    /// `/`
+    Slash,
+    /// `%`
+    Mod,
+}
+
+impl BinaryOperator {
+    /// 运算符的优先级，数值越大结合越紧密。
+    /// `instanceof`与关系运算符的优先级相同。
+    pub fn precedence(&self) -> u8 {
+        match self {
+            Self::LogicalOr => 1,
+            Self::LogicalAnd => 2,
+            Self::Or => 3,
+            Self::Xor => 4,
+            Self::And => 5,
+            Self::Equal | Self::NotEqual => 6,
+            Self::LessThan
+            | Self::GreaterThan
+            | Self::LessThanOrEqual
+            | Self::GreaterThanOrEqual => 7,
+            Self::ShiftLeft | Self::ShiftRight | Self::UnsignedShiftRight => 8,
+            Self::Plus | Self::Minus => 9,
+            Self::Star | Self::Slash | Self::Mod => 10,
+        }
+    }
+}
+
+impl Display for BinaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let text = match self {
+            Self::LogicalOr => Token::LOGICAL_OR,
+            Self::LogicalAnd => Token::LOGICAL_AND,
+            Self::Or => Token::OR,
+            Self::Xor => Token::XOR,
+            Self::And => Token::AND,
+            Self::Equal => Token::DOUBLE_EQUAL,
+            Self::NotEqual => Token::NOT_EQUAL,
+            Self::LessThan => Token::LESS_THAN,
+            Self::GreaterThan => Token::GREATER_THAN,
+            Self::LessThanOrEqual => Token::LESS_THAN_OR_EQUAL,
+            Self::GreaterThanOrEqual => Token::GREATER_THAN_OR_EQUAL,
+            Self::ShiftLeft => Token::SHIFT_LEFT,
+            Self::ShiftRight => Token::SHIFT_RIGHT,
+            Self::UnsignedShiftRight => Token::UNSIGNED_SHIFT_RIGHT,
+            Self::Plus => Token::PLUS,
+            Self::Minus => Token::MINUS,
+            Self::Star => Token::STAR,
+            Self::Slash => Token::SLASH,
+            Self::Mod => Token::MOD,
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl TryFrom<&Token> for BinaryOperator {
+    type Error = ();
+
+    fn try_from(value: &Token) -> Result<Self, Self::Error> {
+        Ok(match value {
+            Token::LogicalOr => Self::LogicalOr,
+            Token::LogicalAnd => Self::LogicalAnd,
+            Token::Or => Self::Or,
+            Token::Xor => Self::Xor,
+            Token::And => Self::And,
+            Token::DoubleEqual => Self::Equal,
+            Token::NotEqual => Self::NotEqual,
+            Token::LessThan => Self::LessThan,
+            Token::GreaterThan => Self::GreaterThan,
+            Token::LessThanOrEqual => Self::LessThanOrEqual,
+            Token::GreaterThanOrEqual => Self::GreaterThanOrEqual,
+            Token::ShiftLeft => Self::ShiftLeft,
+            Token::ShiftRight => Self::ShiftRight,
+            Token::UnsignedShiftRight => Self::UnsignedShiftRight,
+            Token::Plus => Self::Plus,
+            Token::Minus => Self::Minus,
+            Token::Star => Self::Star,
+            Token::Slash => Self::Slash,
+            Token::Mod => Self::Mod,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// UnaryOperator表示前缀一元运算符。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnaryOperator {
+    /// `+`
+    Plus,
+    /// `-`
+    Minus,
+    /// 位非`~`
+    Not,
+    /// 逻辑非`!`
+    LogicalNot,
+    /// 前缀自增`++`
+    Increment,
+    /// 前缀自减`--`
+    Decrement,
+}
+
+impl Display for UnaryOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let text = match self {
+            Self::Plus => Token::PLUS,
+            Self::Minus => Token::MINUS,
+            Self::Not => Token::NOT,
+            Self::LogicalNot => Token::LOGICAL_NOT,
+            Self::Increment => Token::DOUBLE_PLUS,
+            Self::Decrement => Token::DOUBLE_MINUS,
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl TryFrom<&Token> for UnaryOperator {
+    type Error = ();
+
+    fn try_from(value: &Token) -> Result<Self, Self::Error> {
+        Ok(match value {
+            Token::Plus => Self::Plus,
+            Token::Minus => Self::Minus,
+            Token::Not => Self::Not,
+            Token::LogicalNot => Self::LogicalNot,
+            Token::DoublePlus => Self::Increment,
+            Token::DoubleMinus => Self::Decrement,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// PostfixOperator表示后缀运算符。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PostfixOperator {
+    /// 后缀自增`++`
+    Increment,
+    /// 后缀自减`--`
+    Decrement,
+}
+
+impl Display for PostfixOperator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let text = match self {
+            Self::Increment => Token::DOUBLE_PLUS,
+            Self::Decrement => Token::DOUBLE_MINUS,
+        };
+        write!(f, "{}", text)
+    }
+}
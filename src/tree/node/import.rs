@@ -4,7 +4,7 @@ use std::{
 };
 
 /// ImportDeclaration 枚举表示Java中的导入声明。
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum ImportDeclaration<'a> {
     /// 单类型导入声明，参数是导入的类或接口的名称。
     SimpleType(Cow<'a, str>),
@@ -14,6 +14,8 @@ pub enum ImportDeclaration<'a> {
     SingleStatic(Cow<'a, str>),
     /// 静态需求导入声明，参数是导入的类或接口的名称。
     StaticOnDemand(Cow<'a, str>),
+    /// 模块导入声明（预览特性），参数是模块名称，导入该模块导出的所有包中的公共类和接口。
+    Module(Cow<'a, str>),
 }
 
 impl<'a> Display for ImportDeclaration<'a> {
@@ -21,6 +23,7 @@ impl<'a> Display for ImportDeclaration<'a> {
         match self {
             Self::SimpleType(r) | Self::SingleStatic(r) => write!(f, "import {};", r),
             Self::TypeOnDemand(r) | Self::StaticOnDemand(r) => write!(f, "import {}.*;", r),
+            Self::Module(r) => write!(f, "import module {};", r),
         }
     }
 }
@@ -0,0 +1,385 @@
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+    ops::Range,
+};
+
+/// Span表示一段内容在文档注释原文（[`DocumentationComment::text`](super::DocumentationComment::text)）中的字节范围。
+pub type Span = Range<usize>;
+
+/// Javadoc表示解析后的文档注释，行首的`*`已经去掉。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Javadoc<'a> {
+    /// 主描述，即第一个块标签之前的内容。
+    pub description: Vec<DocFragment<'a>>,
+    /// 主描述的第一句话，用作摘要。
+    pub first_sentence: Vec<DocFragment<'a>>,
+    /// 块标签，例如`@param`和`@return`。
+    pub block_tags: Vec<BlockTag<'a>>,
+}
+
+impl<'a> Javadoc<'a> {
+    /// 遍历主描述和块标签中的所有行内标签。
+    pub fn inline_tags(&self) -> impl Iterator<Item = &InlineTag<'a>> {
+        self.description
+            .iter()
+            .chain(self.block_tags.iter().flat_map(|t| t.kind.fragments()))
+            .filter_map(|f| match f {
+                DocFragment::InlineTag(t) => Some(t),
+                DocFragment::Text { .. } => None,
+            })
+    }
+}
+
+impl<'a> Display for Javadoc<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_fragments(f, &self.description)?;
+        for i in &self.block_tags {
+            write!(f, "\n{}", i)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// DocFragment表示描述中的一段文本或一个行内标签。
+#[derive(Clone, Debug, PartialEq)]
+pub enum DocFragment<'a> {
+    /// 普通文本（可能包含HTML或Markdown），多行文本以换行符连接。
+    Text {
+        /// 文本内容。
+        text: Cow<'a, str>,
+        /// 在原文中的范围。
+        span: Span,
+    },
+    /// 行内标签，例如`{@code null}`。
+    InlineTag(InlineTag<'a>),
+}
+
+impl<'a> Display for DocFragment<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Text { text, .. } => write!(f, "{}", text),
+            Self::InlineTag(t) => Display::fmt(t, f),
+        }
+    }
+}
+
+fn write_fragments(f: &mut Formatter<'_>, fragments: &[DocFragment]) -> FmtResult {
+    for i in fragments {
+        Display::fmt(i, f)?;
+    }
+
+    Ok(())
+}
+
+/// InlineTag表示`{@name ...}`形式的行内标签。
+#[derive(Clone, Debug, PartialEq)]
+pub struct InlineTag<'a> {
+    /// 标签的种类和内容。
+    pub kind: InlineTagKind<'a>,
+    /// 在原文中的范围，包括两边的大括号。
+    pub span: Span,
+}
+
+impl<'a> Display for InlineTag<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.kind, f)
+    }
+}
+
+/// InlineTagKind表示行内标签的种类。
+#[derive(Clone, Debug, PartialEq)]
+pub enum InlineTagKind<'a> {
+    /// `{@link reference label}`或`{@linkplain reference label}`。
+    Link {
+        /// 引用的程序元素，例如`java.util.List#add(Object)`。
+        reference: Cow<'a, str>,
+        /// 显示的文本。
+        label: Option<Cow<'a, str>>,
+        /// 是否为`{@linkplain}`。
+        plain: bool,
+    },
+    /// `{@code text}`。
+    Code(Cow<'a, str>),
+    /// `{@literal text}`。
+    Literal(Cow<'a, str>),
+    /// `{@inheritDoc}`。
+    InheritDoc,
+    /// `{@value}`或`{@value package.Class#FIELD}`。
+    Value(Option<Cow<'a, str>>),
+    /// `{@snippet ...}`，包含属性和内联的代码片段。
+    Snippet(Snippet<'a>),
+    /// 其他行内标签，参数是标签名称（不包括`@`）和内容。
+    Unknown {
+        /// 标签名称。
+        name: Cow<'a, str>,
+        /// 标签内容。
+        content: Cow<'a, str>,
+    },
+}
+
+impl<'a> Display for InlineTagKind<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Link {
+                reference,
+                label,
+                plain,
+            } => {
+                let name = if *plain { "linkplain" } else { "link" };
+                write!(f, "{{@{} {}", name, reference)?;
+                if let Some(l) = label {
+                    write!(f, " {}", l)?;
+                }
+                write!(f, "}}")
+            }
+            Self::Code(c) => write!(f, "{{@code {}}}", c),
+            Self::Literal(c) => write!(f, "{{@literal {}}}", c),
+            Self::InheritDoc => write!(f, "{{@inheritDoc}}"),
+            Self::Value(None) => write!(f, "{{@value}}"),
+            Self::Value(Some(r)) => write!(f, "{{@value {}}}", r),
+            Self::Snippet(s) => Display::fmt(s, f),
+            Self::Unknown { name, content } if content.is_empty() => write!(f, "{{@{}}}", name),
+            Self::Unknown { name, content } => write!(f, "{{@{} {}}}", name, content),
+        }
+    }
+}
+
+/// BlockTag表示以`@`开头的块标签，它一直延续到下一个块标签或注释末尾。
+#[derive(Clone, Debug, PartialEq)]
+pub struct BlockTag<'a> {
+    /// 标签的种类和内容。
+    pub kind: BlockTagKind<'a>,
+    /// 在原文中的范围。
+    pub span: Span,
+}
+
+impl<'a> Display for BlockTag<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        Display::fmt(&self.kind, f)
+    }
+}
+
+/// BlockTagKind表示块标签的种类。
+#[derive(Clone, Debug, PartialEq)]
+pub enum BlockTagKind<'a> {
+    /// `@param name description`，类型参数写作`@param <T> description`。
+    Param {
+        /// 参数名称（不包括尖括号）。
+        name: Cow<'a, str>,
+        /// 是否为类型参数。
+        type_parameter: bool,
+        /// 参数说明。
+        description: Vec<DocFragment<'a>>,
+    },
+    /// `@return description`。
+    Return(Vec<DocFragment<'a>>),
+    /// `@throws`或`@exception`。
+    Throws {
+        /// 异常类型的名称。
+        exception: Cow<'a, str>,
+        /// 抛出异常的条件。
+        description: Vec<DocFragment<'a>>,
+    },
+    /// `@see reference`。
+    See(Vec<DocFragment<'a>>),
+    /// `@since version`。
+    Since(Vec<DocFragment<'a>>),
+    /// `@deprecated description`。
+    Deprecated(Vec<DocFragment<'a>>),
+    /// 其他块标签，例如`@author`。
+    Unknown {
+        /// 标签名称（不包括`@`）。
+        name: Cow<'a, str>,
+        /// 标签内容。
+        content: Vec<DocFragment<'a>>,
+    },
+}
+
+impl<'a> BlockTagKind<'a> {
+    /// 块标签中的描述部分。
+    pub fn fragments(&self) -> &[DocFragment<'a>] {
+        match self {
+            Self::Param { description, .. } | Self::Throws { description, .. } => description,
+            Self::Return(d) | Self::See(d) | Self::Since(d) | Self::Deprecated(d) => d,
+            Self::Unknown { content, .. } => content,
+        }
+    }
+}
+
+impl<'a> Display for BlockTagKind<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Param {
+                name,
+                type_parameter: true,
+                ..
+            } => write!(f, "@param <{}>", name)?,
+            Self::Param { name, .. } => write!(f, "@param {}", name)?,
+            Self::Return(_) => write!(f, "@return")?,
+            Self::Throws { exception, .. } => write!(f, "@throws {}", exception)?,
+            Self::See(_) => write!(f, "@see")?,
+            Self::Since(_) => write!(f, "@since")?,
+            Self::Deprecated(_) => write!(f, "@deprecated")?,
+            Self::Unknown { name, .. } => write!(f, "@{}", name)?,
+        }
+        let fragments = self.fragments();
+        if !fragments.is_empty() {
+            write!(f, " ")?;
+            write_fragments(f, fragments)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Snippet表示`{@snippet}`标签，代码片段可以内联在标签中，也可以来自外部文件。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Snippet<'a> {
+    /// 属性，例如`file="Example.java"`、`class=pkg.Example`和`region=main`。
+    pub attributes: Vec<SnippetAttribute<'a>>,
+    /// `:`后面的内联代码片段，已经去掉公共缩进，`None`表示只引用外部文件。
+    pub body: Option<Vec<SnippetLine<'a>>>,
+}
+
+impl<'a> Snippet<'a> {
+    /// 获取属性的值，没有值的属性返回空字符串。
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        find_attribute(&self.attributes, name)
+    }
+}
+
+impl<'a> Display for Snippet<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{{@snippet")?;
+        for i in &self.attributes {
+            write!(f, " {}", i)?;
+        }
+        if let Some(ref body) = self.body {
+            writeln!(f, " :")?;
+            for i in body {
+                writeln!(f, "{}", i)?;
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
+fn find_attribute<'b>(attributes: &'b [SnippetAttribute], name: &str) -> Option<&'b str> {
+    attributes
+        .iter()
+        .find(|a| a.name == name)
+        .map(|a| a.value.as_deref().unwrap_or_default())
+}
+
+/// SnippetAttribute表示代码片段或标记注释中的属性`name=value`，值可以用引号包围。
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnippetAttribute<'a> {
+    /// 属性名称。
+    pub name: Cow<'a, str>,
+    /// 属性值（不包括引号），`None`表示只有名称。
+    pub value: Option<Cow<'a, str>>,
+}
+
+impl<'a> Display for SnippetAttribute<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name)?;
+        if let Some(ref v) = self.value {
+            write!(f, "=\"{}\"", v)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// SnippetLine表示代码片段中的一行，标记注释已经从文本中去掉。
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnippetLine<'a> {
+    /// 这一行的代码。
+    pub text: Cow<'a, str>,
+    /// 作用于这一行的标记注释，例如`// @highlight substring="println"`。
+    pub markup: Vec<SnippetMarkup<'a>>,
+}
+
+impl<'a> Display for SnippetLine<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.text)?;
+        if !self.markup.is_empty() {
+            write!(f, " //")?;
+            for i in &self.markup {
+                write!(f, " {}", i)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// SnippetMarkup表示代码片段中的一个标记注释标签。
+#[derive(Clone, Debug, PartialEq)]
+pub struct SnippetMarkup<'a> {
+    /// 标记的种类。
+    pub kind: SnippetMarkupKind,
+    /// 标记的属性，例如`region`、`substring`、`regex`、`replacement`和`target`。
+    pub attributes: Vec<SnippetAttribute<'a>>,
+}
+
+impl<'a> SnippetMarkup<'a> {
+    /// 获取属性的值，没有值的属性返回空字符串。
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        find_attribute(&self.attributes, name)
+    }
+}
+
+impl<'a> Display for SnippetMarkup<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "@{}", self.kind)?;
+        for i in &self.attributes {
+            write!(f, " {}", i)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// SnippetMarkupKind表示标记注释标签的种类。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnippetMarkupKind {
+    /// `@start`，开始一个区域。
+    Start,
+    /// `@end`，结束一个区域。
+    End,
+    /// `@highlight`，高亮文本。
+    Highlight,
+    /// `@replace`，替换文本。
+    Replace,
+    /// `@link`，把文本链接到程序元素。
+    Link,
+}
+
+impl SnippetMarkupKind {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "start" => Self::Start,
+            "end" => Self::End,
+            "highlight" => Self::Highlight,
+            "replace" => Self::Replace,
+            "link" => Self::Link,
+            _ => return None,
+        })
+    }
+}
+
+impl Display for SnippetMarkupKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Self::Start => "start",
+            Self::End => "end",
+            Self::Highlight => "highlight",
+            Self::Replace => "replace",
+            Self::Link => "link",
+        };
+        write!(f, "{}", name)
+    }
+}
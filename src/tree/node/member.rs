@@ -0,0 +1,277 @@
+use super::{
+    write_list, write_modifiers, write_type_parameters, Annotation, Block, Comments,
+    ConstructorBody, DocumentationComment, ElementValue, Expression, Modifier, ReferenceType, Type,
+    TypeParameter,
+};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// FieldDeclaration表示字段声明，一个声明中可以包含多个变量，例如`int a = 1, b;`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldDeclaration<'a> {
+    /// 字段的修饰符。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到字段的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 字段的类型。
+    pub r#type: Type<'a>,
+    /// 声明的变量。
+    pub declarators: Vec<VariableDeclarator<'a>>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for FieldDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        write!(f, "{} ", self.r#type)?;
+        write_list(f, &self.declarators, ", ")?;
+        write!(f, ";")
+    }
+}
+
+/// MethodDeclaration表示方法声明。
+#[derive(Clone, Debug, PartialEq)]
+pub struct MethodDeclaration<'a> {
+    /// 方法的修饰符。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到方法的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 类型参数。
+    pub type_parameters: Vec<TypeParameter<'a>>,
+    /// 返回类型，`None`表示`void`。
+    pub return_type: Option<Type<'a>>,
+    /// 方法的名称。
+    pub name: Cow<'a, str>,
+    /// 形式参数。
+    pub parameters: Vec<FormalParameter<'a>>,
+    /// 声明抛出的异常。
+    pub throws: Vec<ReferenceType<'a>>,
+    /// 方法体，抽象方法和本地方法没有方法体。
+    pub body: Option<Block<'a>>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for MethodDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        if !self.type_parameters.is_empty() {
+            write_type_parameters(f, &self.type_parameters)?;
+            write!(f, " ")?;
+        }
+        match self.return_type {
+            Some(ref t) => write!(f, "{} ", t)?,
+            None => write!(f, "void ")?,
+        }
+        write!(f, "{}(", self.name)?;
+        write_list(f, &self.parameters, ", ")?;
+        write!(f, ")")?;
+        write_throws(f, &self.throws)?;
+        match self.body {
+            Some(ref body) => write!(f, " {}", body),
+            None => write!(f, ";"),
+        }
+    }
+}
+
+/// ConstructorDeclaration表示构造函数声明。
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConstructorDeclaration<'a> {
+    /// 构造函数的修饰符。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到构造函数的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 类型参数。
+    pub type_parameters: Vec<TypeParameter<'a>>,
+    /// 构造函数的名称（与类名相同）。
+    pub name: Cow<'a, str>,
+    /// 形式参数，`None`表示记录类的紧凑构造函数。
+    pub parameters: Option<Vec<FormalParameter<'a>>>,
+    /// 声明抛出的异常。
+    pub throws: Vec<ReferenceType<'a>>,
+    /// 构造函数体。
+    pub body: ConstructorBody<'a>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for ConstructorDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        if !self.type_parameters.is_empty() {
+            write_type_parameters(f, &self.type_parameters)?;
+            write!(f, " ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if let Some(ref parameters) = self.parameters {
+            write!(f, "(")?;
+            write_list(f, parameters, ", ")?;
+            write!(f, ")")?;
+        }
+        write_throws(f, &self.throws)?;
+        write!(f, " {}", self.body)
+    }
+}
+
+/// Initializer表示类体中的实例初始化块或静态初始化块。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Initializer<'a> {
+    /// 是否为静态初始化块。
+    pub r#static: bool,
+    /// 初始化块的代码。
+    pub block: Block<'a>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for Initializer<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.r#static {
+            write!(f, "static ")?;
+        }
+        Display::fmt(&self.block, f)
+    }
+}
+
+/// AnnotationMethod表示注解接口中的元素声明，例如`String value() default "";`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnnotationMethod<'a> {
+    /// 元素的修饰符。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到元素的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 元素的类型。
+    pub return_type: Type<'a>,
+    /// 元素的名称。
+    pub name: Cow<'a, str>,
+    /// 元素的默认值。
+    pub default: Option<ElementValue<'a>>,
+    /// 文档注释。
+    pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for AnnotationMethod<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref d) = self.documentation {
+            Display::fmt(d, f)?;
+        }
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        write!(f, "{} {}()", self.return_type, self.name)?;
+        if let Some(ref default) = self.default {
+            write!(f, " default {}", default)?;
+        }
+        write!(f, ";")
+    }
+}
+
+/// FormalParameter表示方法或构造函数的形式参数。
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormalParameter<'a> {
+    /// 参数的修饰符（只能是`final`）。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到参数的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 参数的类型。
+    pub r#type: Type<'a>,
+    /// 是否为可变参数`...`。
+    pub varargs: bool,
+    /// 参数的名称。
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> Display for FormalParameter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        write!(f, "{}", self.r#type)?;
+        if self.varargs {
+            write!(f, "...")?;
+        }
+        write!(f, " {}", self.name)
+    }
+}
+
+/// VariableDeclarator表示变量声明中的一个变量，例如`a[] = {1, 2}`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariableDeclarator<'a> {
+    /// 变量的名称。
+    pub name: Cow<'a, str>,
+    /// 写在变量名后面的数组维度。
+    pub dimensions: usize,
+    /// 变量的初始值。
+    pub initializer: Option<VariableInitializer<'a>>,
+}
+
+impl<'a> Display for VariableDeclarator<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name)?;
+        for _ in 0..self.dimensions {
+            write!(f, "[]")?;
+        }
+        if let Some(ref initializer) = self.initializer {
+            write!(f, " = {}", initializer)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// VariableInitializer表示变量的初始值，可以是表达式或数组初始化器。
+#[derive(Clone, Debug, PartialEq)]
+pub enum VariableInitializer<'a> {
+    /// 表达式。
+    Expression(Expression<'a>),
+    /// 数组初始化器`{1, 2}`。
+    Array(ArrayInitializer<'a>),
+}
+
+impl<'a> Display for VariableInitializer<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Expression(e) => Display::fmt(e, f),
+            Self::Array(a) => Display::fmt(a, f),
+        }
+    }
+}
+
+/// ArrayInitializer表示数组初始化器`{a, b, c}`。
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ArrayInitializer<'a> {
+    /// 数组元素的初始值。
+    pub initializers: Vec<VariableInitializer<'a>>,
+}
+
+impl<'a> Display for ArrayInitializer<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{{")?;
+        write_list(f, &self.initializers, ", ")?;
+        write!(f, "}}")
+    }
+}
+
+fn write_throws(f: &mut Formatter<'_>, throws: &[ReferenceType]) -> FmtResult {
+    if throws.is_empty() {
+        return Ok(());
+    }
+    write!(f, " throws ")?;
+    write_list(f, throws, ", ")
+}
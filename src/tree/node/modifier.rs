@@ -0,0 +1,85 @@
+use crate::Token;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Modifier表示类、接口和成员声明上的修饰符。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Modifier {
+    /// public
+    Public,
+    /// protected
+    Protected,
+    /// private
+    Private,
+    /// static
+    Static,
+    /// abstract
+    Abstract,
+    /// final
+    Final,
+    /// native
+    Native,
+    /// synchronized
+    Synchronized,
+    /// transient
+    Transient,
+    /// volatile
+    Volatile,
+    /// strictfp
+    Strictfp,
+    /// 接口默认方法的default
+    Default,
+    /// sealed
+    Sealed,
+    /// non-sealed
+    NonSealed,
+}
+
+impl Modifier {
+    pub(crate) const SEALED: &'static str = "sealed";
+    pub(crate) const NON_SEALED: &'static str = "non-sealed";
+}
+
+impl Display for Modifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let text = match self {
+            Self::Public => Token::PUBLIC,
+            Self::Protected => Token::PROTECTED,
+            Self::Private => Token::PRIVATE,
+            Self::Static => Token::STATIC,
+            Self::Abstract => Token::ABSTRACT,
+            Self::Final => Token::FINAL,
+            Self::Native => Token::NATIVE,
+            Self::Synchronized => Token::SYNCHRONIZED,
+            Self::Transient => Token::TRANSIENT,
+            Self::Volatile => Token::VOLATILE,
+            Self::Strictfp => Token::STRICTFP,
+            Self::Default => Token::DEFAULT,
+            Self::Sealed => Self::SEALED,
+            Self::NonSealed => Self::NON_SEALED,
+        };
+        write!(f, "{}", text)
+    }
+}
+
+impl TryFrom<&Token> for Modifier {
+    type Error = ();
+
+    fn try_from(value: &Token) -> Result<Self, Self::Error> {
+        Ok(match value {
+            Token::Public => Self::Public,
+            Token::Protected => Self::Protected,
+            Token::Private => Self::Private,
+            Token::Static => Self::Static,
+            Token::Abstract => Self::Abstract,
+            Token::Final => Self::Final,
+            Token::Native => Self::Native,
+            Token::Synchronized => Self::Synchronized,
+            Token::Transient => Self::Transient,
+            Token::Volatile => Self::Volatile,
+            Token::Strictfp => Self::Strictfp,
+            Token::Default => Self::Default,
+            Token::Identifier(i) if i == Self::SEALED => Self::Sealed,
+            _ => return Err(()),
+        })
+    }
+}
@@ -2,22 +2,25 @@ use std::{
     borrow::Cow,
     fmt::{Display, Formatter,Result as FmtResult}
 };
-use crate::{Annotation, DocumentationComment};
+use crate::{Annotation, Comments, DocumentationComment};
+use super::write_list;
 
 /// ModuleDeclaration表示Java程序中的模块声明。
 /// 它包括模块的名称、注解、指令以及是否为开放模块。
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct ModuleDeclaration<'a> {
     /// 模块的名称。
     pub name: Cow<'a, str>,
     /// 应用到模块声明的注解。
     pub annotations: Vec<Annotation<'a>>,
     /// 指定模块的依赖、导出、打开、使用和提供等指令。
-    pub directives: Vec<ModuleDirective>,
+    pub directives: Vec<ModuleDirective<'a>>,
     /// 是否为开放模块。
     pub open: bool,
     /// 文档注释
     pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
 }
 
 impl<'a> Display for ModuleDeclaration<'a> {
@@ -26,15 +29,103 @@ impl<'a> Display for ModuleDeclaration<'a> {
             Display::fmt(d, f)?;
         }
         for i in &self.annotations {
-            write!(f, "{}\n", i)?;
+            writeln!(f, "{}", i)?;
         }
         if self.open {
-            write!(f, "open")?;
+            write!(f, "open ")?;
+        }
+        writeln!(f, "module {} {{", self.name)?;
+        for i in &self.directives {
+            writeln!(f, "{}", i)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// ModuleDirective表示模块声明中的一条指令。
+#[derive(Clone, Debug, PartialEq)]
+pub enum ModuleDirective<'a> {
+    /// 依赖其他模块，例如`requires transitive java.sql;`。
+    Requires {
+        /// `transitive`和`static`修饰符。
+        modifiers: Vec<RequiresModifier>,
+        /// 依赖的模块名称。
+        name: Cow<'a, str>,
+    },
+    /// 导出包，例如`exports com.example.api to com.example.app;`。
+    Exports {
+        /// 导出的包名称。
+        package: Cow<'a, str>,
+        /// 限定只导出给这些模块，为空时导出给所有模块。
+        to: Vec<Cow<'a, str>>,
+    },
+    /// 在运行时开放包以供反射访问，例如`opens com.example.model to jackson.databind;`。
+    Opens {
+        /// 开放的包名称。
+        package: Cow<'a, str>,
+        /// 限定只开放给这些模块，为空时开放给所有模块。
+        to: Vec<Cow<'a, str>>,
+    },
+    /// 使用服务，例如`uses java.sql.Driver;`，参数是服务接口的名称。
+    Uses(Cow<'a, str>),
+    /// 提供服务的实现，例如`provides java.sql.Driver with com.example.MyDriver;`。
+    Provides {
+        /// 服务接口的名称。
+        service: Cow<'a, str>,
+        /// 实现类的名称。
+        with: Vec<Cow<'a, str>>,
+    },
+}
+
+impl<'a> Display for ModuleDirective<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Requires { modifiers, name } => {
+                write!(f, "requires ")?;
+                for i in modifiers {
+                    write!(f, "{} ", i)?;
+                }
+                write!(f, "{};", name)
+            }
+            Self::Exports { package, to } => write_qualified(f, "exports", package, to),
+            Self::Opens { package, to } => write_qualified(f, "opens", package, to),
+            Self::Uses(name) => write!(f, "uses {};", name),
+            Self::Provides { service, with } => {
+                write!(f, "provides {} with ", service)?;
+                write_list(f, with, ", ")?;
+                write!(f, ";")
+            }
         }
-        write!(f, "module {} {{}}", self.name)?;
-        Ok(())
     }
 }
 
-#[derive(Debug)]
-pub struct ModuleDirective;
+fn write_qualified(f: &mut Formatter<'_>, directive: &str, package: &str, to: &[Cow<str>]) -> FmtResult {
+    write!(f, "{} {}", directive, package)?;
+    if !to.is_empty() {
+        write!(f, " to ")?;
+        write_list(f, to, ", ")?;
+    }
+    write!(f, ";")
+}
+
+/// RequiresModifier表示`requires`指令的修饰符。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RequiresModifier {
+    /// `transitive`，依赖当前模块的模块也隐式依赖该模块。
+    Transitive,
+    /// `static`，只在编译时需要该模块。
+    Static,
+}
+
+impl RequiresModifier {
+    pub(crate) const TRANSITIVE: &'static str = "transitive";
+}
+
+impl Display for RequiresModifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Transitive => write!(f, "{}", Self::TRANSITIVE),
+            Self::Static => write!(f, "static"),
+        }
+    }
+}
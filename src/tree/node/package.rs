@@ -2,11 +2,11 @@ use std::{
     borrow::Cow,
     fmt::{Display, Formatter,Result as FmtResult}
 };
-use super::{Annotation, DocumentationComment};
+use super::{write_dangling_comments, Annotation, Comments, DocumentationComment};
 
 /// PackageDeclaration表示Java程序中的包声明。
 /// 它包括包的名称、修饰符和文档注释。
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct PackageDeclaration<'a> {
     /// 包的名称。
     pub name: Cow<'a, str>,
@@ -14,10 +14,13 @@ pub struct PackageDeclaration<'a> {
     pub modifiers: Vec<Annotation<'a>>,
     /// 包声明的文档注释。
     pub documentation: Option<DocumentationComment<'a>>,
+    /// 关联的普通注释。
+    pub comments: Comments<'a>,
 }
 
 impl<'a> Display for PackageDeclaration<'a> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_dangling_comments(f, &self.comments.leading)?;
         if let Some(ref d) = self.documentation {
             Display::fmt(d, f)?;
         }
@@ -25,7 +28,11 @@ impl<'a> Display for PackageDeclaration<'a> {
             Display::fmt(&i, f)?;
         }
         if !self.name.is_empty() {
-            write!(f, "package {};\n", self.name)?;
+            write!(f, "package {};", self.name)?;
+            for i in &self.comments.trailing {
+                write!(f, " {}", i)?;
+            }
+            writeln!(f)?;
         }
 
         Ok(())
@@ -0,0 +1,71 @@
+use super::{write_list, write_modifiers, Annotation, Modifier, ReferenceType, Type};
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Pattern表示模式匹配中的模式，用于`instanceof`和`switch`标签。
+#[derive(Clone, Debug, PartialEq)]
+pub enum Pattern<'a> {
+    /// 类型模式，例如`String s`、`final Integer i`或`String _`（未命名变量）。
+    /// 使用`var`声明的模式（只能出现在记录模式中）的类型是名为`var`的引用类型。
+    Type {
+        /// 模式变量的修饰符（只能是`final`）。
+        modifiers: Vec<Modifier>,
+        /// 应用到模式变量的注解。
+        annotations: Vec<Annotation<'a>>,
+        /// 匹配的类型。
+        r#type: Type<'a>,
+        /// 模式变量的名称。
+        name: Cow<'a, str>,
+    },
+    /// 记录模式，例如`Point(int x, var y)`，可以嵌套。
+    Record {
+        /// 记录类型。
+        r#type: ReferenceType<'a>,
+        /// 记录组件的模式。
+        patterns: Vec<Pattern<'a>>,
+    },
+    /// 未命名模式`_`，只能出现在记录模式中，匹配任何值。
+    Unnamed,
+}
+
+impl<'a> Display for Pattern<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Type {
+                modifiers,
+                annotations,
+                r#type,
+                name,
+            } => {
+                write_modifiers(f, annotations, modifiers)?;
+                write!(f, "{} {}", r#type, name)
+            }
+            Self::Record { r#type, patterns } => {
+                write!(f, "{}(", r#type)?;
+                write_list(f, patterns, ", ")?;
+                write!(f, ")")
+            }
+            Self::Unnamed => write!(f, "_"),
+        }
+    }
+}
+
+/// InstanceOfTarget表示`instanceof`右边的部分，可以是类型或模式。
+#[derive(Clone, Debug, PartialEq)]
+pub enum InstanceOfTarget<'a> {
+    /// 类型，例如`obj instanceof String`。
+    Type(Type<'a>),
+    /// 模式，例如`obj instanceof String s`或`obj instanceof Point(var x, var y)`。
+    Pattern(Pattern<'a>),
+}
+
+impl<'a> Display for InstanceOfTarget<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Type(t) => Display::fmt(t, f),
+            Self::Pattern(p) => Display::fmt(p, f),
+        }
+    }
+}
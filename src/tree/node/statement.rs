@@ -0,0 +1,454 @@
+use super::{
+    write_list, write_modifiers, Annotation, Block, ClassBodyDeclaration, Comments, ErrorNode,
+    Expression, Modifier,
+    ReferenceType, Switch, Type, TypeArgument, VariableDeclarator,
+};
+use crate::Token;
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Statement表示代码块中的语句，包括局部变量声明和局部类声明。
+#[derive(Clone, Debug, PartialEq)]
+pub enum Statement<'a> {
+    /// 代码块`{ ... }`。
+    Block(Block<'a>),
+    /// 局部变量声明，例如`int a = 1, b;`或`var list = new ArrayList<String>();`。
+    LocalVariable(LocalVariableDeclaration<'a>),
+    /// 局部类、接口、枚举或记录声明。
+    LocalClass(Box<ClassBodyDeclaration<'a>>),
+    /// 空语句`;`。
+    Empty,
+    /// 带标签的语句，例如`outer: for (...) ...`。
+    Labeled {
+        /// 标签。
+        label: Cow<'a, str>,
+        /// 被标记的语句。
+        statement: Box<Statement<'a>>,
+        /// 被标记的语句前面的普通注释，见[`Statement::If::then_comments`]。
+        statement_comments: Comments<'a>,
+    },
+    /// 表达式语句，例如`a = 1;`、`i++;`或`foo();`。
+    Expression(Expression<'a>),
+    /// `if (condition) then_statement else else_statement`
+    If {
+        /// 条件。
+        condition: Expression<'a>,
+        /// 条件为真时执行的语句。
+        then_statement: Box<Statement<'a>>,
+        /// 条件为假时执行的语句。
+        else_statement: Option<Box<Statement<'a>>>,
+        /// `then_statement`前面的普通注释，例如`if (x > 0) // 正数`中的`// 正数`；
+        /// 有`else`分支时，`then_statement`同一行末尾的注释是尾随注释。
+        then_comments: Comments<'a>,
+        /// `else_statement`前面的普通注释。
+        else_comments: Comments<'a>,
+    },
+    /// `while (condition) body`
+    While {
+        /// 条件。
+        condition: Expression<'a>,
+        /// 循环体。
+        body: Box<Statement<'a>>,
+        /// 循环体前面的普通注释，见[`Statement::If::then_comments`]。
+        body_comments: Comments<'a>,
+    },
+    /// `do body while (condition);`
+    Do {
+        /// 循环体。
+        body: Box<Statement<'a>>,
+        /// 条件。
+        condition: Expression<'a>,
+        /// 循环体前面的普通注释和它同一行末尾的尾随注释。
+        body_comments: Comments<'a>,
+    },
+    /// `for`循环，包括增强的`for`循环。
+    For {
+        /// 循环控制部分。
+        control: ForControl<'a>,
+        /// 循环体。
+        body: Box<Statement<'a>>,
+        /// 循环体前面的普通注释，见[`Statement::If::then_comments`]。
+        body_comments: Comments<'a>,
+    },
+    /// `break;`或`break label;`
+    Break(Option<Cow<'a, str>>),
+    /// `continue;`或`continue label;`
+    Continue(Option<Cow<'a, str>>),
+    /// `return;`或`return expression;`
+    Return(Option<Expression<'a>>),
+    /// `throw expression;`
+    Throw(Expression<'a>),
+    /// `assert condition;`或`assert condition : message;`
+    Assert {
+        /// 断言的条件。
+        condition: Expression<'a>,
+        /// 断言失败时的消息。
+        message: Option<Expression<'a>>,
+    },
+    /// `synchronized (lock) block`
+    Synchronized {
+        /// 锁对象。
+        lock: Expression<'a>,
+        /// 同步执行的代码块。
+        block: Block<'a>,
+    },
+    /// `switch`语句。
+    Switch(Switch<'a>),
+    /// `yield expression;`，用于`switch`表达式中给出结果。
+    Yield(Expression<'a>),
+    /// `try`语句，包括带资源的`try`语句。
+    Try(TryStatement<'a>),
+    /// 恢复解析时跳过的无法解析的语句。
+    Error(ErrorNode<'a>),
+}
+
+impl<'a> Display for Statement<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Block(b) => Display::fmt(b, f),
+            Self::LocalVariable(d) => write!(f, "{};", d),
+            Self::LocalClass(d) => Display::fmt(d, f),
+            Self::Empty => write!(f, ";"),
+            Self::Labeled {
+                label,
+                statement,
+                statement_comments,
+            } => {
+                write!(f, "{}: ", label)?;
+                write_body(f, statement, statement_comments)
+            }
+            Self::Expression(e) => write!(f, "{};", e),
+            Self::If {
+                condition,
+                then_statement,
+                else_statement,
+                then_comments,
+                else_comments,
+            } => {
+                write!(f, "if ({}) ", condition)?;
+                write_body(f, then_statement, then_comments)?;
+                if let Some(e) = else_statement {
+                    if then_comments.trailing.is_empty() {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "else ")?;
+                    write_body(f, e, else_comments)?;
+                }
+
+                Ok(())
+            }
+            Self::While {
+                condition,
+                body,
+                body_comments,
+            } => {
+                write!(f, "while ({}) ", condition)?;
+                write_body(f, body, body_comments)
+            }
+            Self::Do {
+                body,
+                condition,
+                body_comments,
+            } => {
+                write!(f, "do ")?;
+                write_body(f, body, body_comments)?;
+                if body_comments.trailing.is_empty() {
+                    write!(f, " ")?;
+                }
+                write!(f, "while ({});", condition)
+            }
+            Self::For {
+                control,
+                body,
+                body_comments,
+            } => {
+                write!(f, "for ({}) ", control)?;
+                write_body(f, body, body_comments)
+            }
+            Self::Break(label) => write_jump(f, "break", label),
+            Self::Continue(label) => write_jump(f, "continue", label),
+            Self::Return(Some(e)) => write!(f, "return {};", e),
+            Self::Return(None) => write!(f, "return;"),
+            Self::Throw(e) => write!(f, "throw {};", e),
+            Self::Assert { condition, message } => {
+                write!(f, "assert {}", condition)?;
+                if let Some(m) = message {
+                    write!(f, " : {}", m)?;
+                }
+                write!(f, ";")
+            }
+            Self::Synchronized { lock, block } => write!(f, "synchronized ({}) {}", lock, block),
+            Self::Switch(s) => Display::fmt(s, f),
+            Self::Yield(e) => write!(f, "yield {};", e),
+            Self::Try(t) => Display::fmt(t, f),
+            Self::Error(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+/// 输出子语句和它的注释：前导注释各占一行，有尾随注释时在它们后面换行。
+fn write_body(f: &mut Formatter<'_>, statement: &Statement, comments: &Comments) -> FmtResult {
+    for i in &comments.leading {
+        writeln!(f, "{}", i)?;
+    }
+    Display::fmt(statement, f)?;
+    for i in &comments.trailing {
+        write!(f, " {}", i)?;
+    }
+    if !comments.trailing.is_empty() {
+        writeln!(f)?;
+    }
+
+    Ok(())
+}
+
+fn write_jump(f: &mut Formatter<'_>, keyword: &str, label: &Option<Cow<str>>) -> FmtResult {
+    write!(f, "{}", keyword)?;
+    if let Some(l) = label {
+        write!(f, " {}", l)?;
+    }
+    write!(f, ";")
+}
+
+/// LocalVariableDeclaration表示局部变量声明（不包括末尾的分号）。
+/// 使用`var`声明的变量的类型是名为`var`的引用类型。
+#[derive(Clone, Debug, PartialEq)]
+pub struct LocalVariableDeclaration<'a> {
+    /// 变量的修饰符（只能是`final`）。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到变量的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 变量的类型。
+    pub r#type: Type<'a>,
+    /// 声明的变量。
+    pub declarators: Vec<VariableDeclarator<'a>>,
+}
+
+impl<'a> Display for LocalVariableDeclaration<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        write!(f, "{} ", self.r#type)?;
+        write_list(f, &self.declarators, ", ")
+    }
+}
+
+/// ForControl表示`for`循环括号中的部分。
+#[derive(Clone, Debug, PartialEq)]
+pub enum ForControl<'a> {
+    /// 基本的`for`循环`init; condition; update`。
+    Basic {
+        /// 初始化部分。
+        init: ForInit<'a>,
+        /// 循环条件，省略时为`None`。
+        condition: Option<Expression<'a>>,
+        /// 每次循环后执行的表达式。
+        update: Vec<Expression<'a>>,
+    },
+    /// 增强的`for`循环`variable : iterable`，变量声明中只有一个没有初始值的变量。
+    Enhanced {
+        /// 循环变量。
+        variable: LocalVariableDeclaration<'a>,
+        /// 被遍历的数组或`Iterable`对象。
+        iterable: Expression<'a>,
+    },
+}
+
+impl<'a> Display for ForControl<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Basic {
+                init,
+                condition,
+                update,
+            } => {
+                write!(f, "{};", init)?;
+                if let Some(c) = condition {
+                    write!(f, " {}", c)?;
+                }
+                write!(f, ";")?;
+                if !update.is_empty() {
+                    write!(f, " ")?;
+                    write_list(f, update, ", ")?;
+                }
+
+                Ok(())
+            }
+            Self::Enhanced { variable, iterable } => write!(f, "{} : {}", variable, iterable),
+        }
+    }
+}
+
+/// ForInit表示基本`for`循环的初始化部分。
+#[derive(Clone, Debug, PartialEq)]
+pub enum ForInit<'a> {
+    /// 局部变量声明。
+    Declaration(LocalVariableDeclaration<'a>),
+    /// 以逗号分隔的表达式，省略初始化部分时为空。
+    Expressions(Vec<Expression<'a>>),
+}
+
+impl<'a> Display for ForInit<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Declaration(d) => Display::fmt(d, f),
+            Self::Expressions(e) => write_list(f, e, ", "),
+        }
+    }
+}
+
+/// TryStatement表示`try`语句，至少要有一个资源、`catch`子句或`finally`块。
+#[derive(Clone, Debug, PartialEq)]
+pub struct TryStatement<'a> {
+    /// `try (...)`中的资源，自动关闭。
+    pub resources: Vec<TryResource<'a>>,
+    /// `try`块。
+    pub block: Block<'a>,
+    /// `catch`子句。
+    pub catches: Vec<CatchClause<'a>>,
+    /// `finally`块。
+    pub finally: Option<Block<'a>>,
+}
+
+impl<'a> Display for TryStatement<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "try ")?;
+        if !self.resources.is_empty() {
+            write!(f, "(")?;
+            write_list(f, &self.resources, "; ")?;
+            write!(f, ") ")?;
+        }
+        Display::fmt(&self.block, f)?;
+        for i in &self.catches {
+            write!(f, " {}", i)?;
+        }
+        if let Some(ref b) = self.finally {
+            write!(f, " finally {}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// TryResource表示带资源的`try`语句中的一个资源。
+#[derive(Clone, Debug, PartialEq)]
+pub enum TryResource<'a> {
+    /// 声明的资源，例如`var in = new FileInputStream(f)`。
+    Declaration {
+        /// 资源的修饰符（只能是`final`）。
+        modifiers: Vec<Modifier>,
+        /// 应用到资源的注解。
+        annotations: Vec<Annotation<'a>>,
+        /// 资源的类型。
+        r#type: Type<'a>,
+        /// 资源的名称。
+        name: Cow<'a, str>,
+        /// 资源的初始值。
+        value: Expression<'a>,
+    },
+    /// 引用已有的final或等效final变量，例如`try (in)`或`try (this.out)`。
+    Variable(Expression<'a>),
+}
+
+impl<'a> Display for TryResource<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Declaration {
+                modifiers,
+                annotations,
+                r#type,
+                name,
+                value,
+            } => {
+                write_modifiers(f, annotations, modifiers)?;
+                write!(f, "{} {} = {}", r#type, name, value)
+            }
+            Self::Variable(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+/// CatchClause表示`catch`子句。
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatchClause<'a> {
+    /// 捕获的异常参数。
+    pub parameter: CatchClauseParameter<'a>,
+    /// 处理异常的代码块。
+    pub block: Block<'a>,
+}
+
+impl<'a> Display for CatchClause<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "catch ({}) {}", self.parameter, self.block)
+    }
+}
+
+/// CatchClauseParameter表示`catch`子句的参数，多重捕获时有多个异常类型，例如`IOException | SQLException e`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct CatchClauseParameter<'a> {
+    /// 参数的修饰符（只能是`final`）。
+    pub modifiers: Vec<Modifier>,
+    /// 应用到参数的注解。
+    pub annotations: Vec<Annotation<'a>>,
+    /// 捕获的异常类型。
+    pub types: Vec<ReferenceType<'a>>,
+    /// 参数的名称。
+    pub name: Cow<'a, str>,
+}
+
+impl<'a> Display for CatchClauseParameter<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_modifiers(f, &self.annotations, &self.modifiers)?;
+        write_list(f, &self.types, " | ")?;
+        write!(f, " {}", self.name)
+    }
+}
+
+/// ExplicitConstructorInvocation表示在构造函数中显式调用本类或父类的构造函数，
+/// 例如`this(1);`、`super();`或`outer.super();`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExplicitConstructorInvocation<'a> {
+    /// `super`前面的外部对象。
+    pub qualifier: Option<Expression<'a>>,
+    /// 显式指定的类型参数。
+    pub type_arguments: Vec<TypeArgument<'a>>,
+    /// 调用本类还是父类的构造函数。
+    pub kind: ConstructorInvocationKind,
+    /// 实际参数。
+    pub arguments: Vec<Expression<'a>>,
+}
+
+impl<'a> Display for ExplicitConstructorInvocation<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some(ref q) = self.qualifier {
+            write!(f, "{}.", q)?;
+        }
+        if !self.type_arguments.is_empty() {
+            write!(f, "<")?;
+            write_list(f, &self.type_arguments, ", ")?;
+            write!(f, ">")?;
+        }
+        write!(f, "{}(", self.kind)?;
+        write_list(f, &self.arguments, ", ")?;
+        write!(f, ");")
+    }
+}
+
+/// ConstructorInvocationKind表示显式调用的是本类还是父类的构造函数。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConstructorInvocationKind {
+    /// `this(...)`
+    This,
+    /// `super(...)`
+    Super,
+}
+
+impl Display for ConstructorInvocationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::This => write!(f, "{}", Token::THIS),
+            Self::Super => write!(f, "{}", Token::SUPER),
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use super::{
+    write_commented, write_dangling_comments, write_list, Block, Comment, Comments, Expression,
+    Pattern, Statement,
+};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Switch表示`switch`语句或`switch`表达式的公共部分。
+/// 作为语句时出现在`Statement::Switch`中，作为表达式时出现在`Expression::Switch`中。
+#[derive(Clone, Debug, PartialEq)]
+pub struct Switch<'a> {
+    /// 选择器表达式。
+    pub selector: Box<Expression<'a>>,
+    /// `switch`块。
+    pub body: SwitchBody<'a>,
+    /// 最后一个语句组或规则之后、不属于任何语句的普通注释。
+    pub dangling: Vec<Comment<'a>>,
+}
+
+impl<'a> Display for Switch<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "switch ({}) {{", self.selector)?;
+        self.body.write_members(f)?;
+        write_dangling_comments(f, &self.dangling)?;
+        write!(f, "}}")
+    }
+}
+
+/// SwitchBody表示`switch`块。同一个`switch`块中不能混用旧式的`case X:`和箭头形式的`case X ->`。
+#[derive(Clone, Debug, PartialEq)]
+pub enum SwitchBody<'a> {
+    /// 旧式的语句组`case X: ...`，可以贯穿执行到下一组。
+    Groups(Vec<SwitchGroup<'a>>),
+    /// 箭头形式的规则`case X -> ...`。
+    Rules(Vec<SwitchRule<'a>>),
+}
+
+impl<'a> SwitchBody<'a> {
+    /// 输出大括号之间的语句组或规则，以及它们关联的普通注释。
+    pub(crate) fn write_members(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Groups(groups) => {
+                for i in groups {
+                    write!(f, "{}", i)?;
+                }
+            }
+            Self::Rules(rules) => {
+                for i in rules {
+                    write_commented(f, i, "", Some(&i.comments))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> Display for SwitchBody<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "{{")?;
+        self.write_members(f)?;
+        write!(f, "}}")
+    }
+}
+
+/// SwitchGroup表示旧式`switch`块中的一组标签和语句，例如`case 1: case 2: a(); break;`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwitchGroup<'a> {
+    /// 标签。
+    pub labels: Vec<SwitchLabel<'a>>,
+    /// 标签后面的语句。
+    pub statements: Vec<Statement<'a>>,
+    /// 每条语句关联的普通注释，和`statements`一一对应；手动构造的语句组可以为空。
+    pub statement_comments: Vec<Comments<'a>>,
+    /// 第一个标签前面的前导注释，以及最后一个标签同一行末尾的尾随注释，例如`case 1: // 一`中的`// 一`。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for SwitchGroup<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write_dangling_comments(f, &self.comments.leading)?;
+        for (i, label) in self.labels.iter().enumerate() {
+            write!(f, "{}:", label)?;
+            if i + 1 == self.labels.len() {
+                for c in &self.comments.trailing {
+                    write!(f, " {}", c)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        for (i, statement) in self.statements.iter().enumerate() {
+            write_commented(f, statement, "", self.statement_comments.get(i))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// SwitchRule表示箭头形式的规则，例如`case 1, 2 -> a();`。
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwitchRule<'a> {
+    /// 标签。
+    pub label: SwitchLabel<'a>,
+    /// 箭头后面的部分。
+    pub body: SwitchRuleBody<'a>,
+    /// 关联的普通注释，例如`case 1 -> a(); // 一`中的`// 一`是尾随注释。
+    pub comments: Comments<'a>,
+}
+
+impl<'a> Display for SwitchRule<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} -> {}", self.label, self.body)
+    }
+}
+
+/// SwitchRuleBody表示箭头形式的规则中箭头后面的部分。
+#[derive(Clone, Debug, PartialEq)]
+pub enum SwitchRuleBody<'a> {
+    /// 表达式，例如`case 1 -> "one";`。
+    Expression(Expression<'a>),
+    /// 代码块，例如`case 1 -> { yield "one"; }`。
+    Block(Block<'a>),
+    /// 抛出异常，例如`default -> throw new IllegalArgumentException();`。
+    Throw(Expression<'a>),
+}
+
+impl<'a> Display for SwitchRuleBody<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Expression(e) => write!(f, "{};", e),
+            Self::Block(b) => Display::fmt(b, f),
+            Self::Throw(e) => write!(f, "throw {};", e),
+        }
+    }
+}
+
+/// SwitchLabel表示`switch`块中的标签。
+#[derive(Clone, Debug, PartialEq)]
+pub enum SwitchLabel<'a> {
+    /// 常量标签，例如`case 1, 2`、`case RED`或`case null`。
+    Case(Vec<Expression<'a>>),
+    /// 模式标签，例如`case Circle c when c.r() > 0`或`case Point(var x, _)`。
+    Pattern {
+        /// 模式，多个模式中不能声明模式变量，例如`case Circle _, Square _`。
+        patterns: Vec<Pattern<'a>>,
+        /// `when`后面的守卫条件。
+        guard: Option<Expression<'a>>,
+    },
+    /// `case null, default`
+    NullDefault,
+    /// `default`
+    Default,
+}
+
+impl<'a> Display for SwitchLabel<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Case(constants) => {
+                write!(f, "case ")?;
+                write_list(f, constants, ", ")
+            }
+            Self::Pattern { patterns, guard } => {
+                write!(f, "case ")?;
+                write_list(f, patterns, ", ")?;
+                if let Some(g) = guard {
+                    write!(f, " when {}", g)?;
+                }
+
+                Ok(())
+            }
+            Self::NullDefault => write!(f, "case null, default"),
+            Self::Default => write!(f, "default"),
+        }
+    }
+}
@@ -1,14 +1,25 @@
-use super::{ClassDeclaration, InterfaceDeclaration};
+use super::{
+    AnnotationDeclaration, ClassDeclaration, EnumDeclaration, ErrorNode, InterfaceDeclaration,
+    RecordDeclaration,
+};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 /// TopLevelClassOrInterfaceDeclaration表示Java程序中的顶层类或接口声明。
-/// 它可以是类声明或接口声明。
-#[derive(Debug)]
+/// 它可以是类声明（包括枚举类和记录类）或接口声明（包括注解接口）。
+#[derive(Clone, Debug, PartialEq)]
 pub enum TopLevelClassOrInterfaceDeclaration<'a> {
     /// 表示类声明。
     Class(ClassDeclaration<'a>),
     /// 表示接口声明。
     Interface(InterfaceDeclaration<'a>),
+    /// 表示枚举类声明。
+    Enum(EnumDeclaration<'a>),
+    /// 表示记录类声明。
+    Record(RecordDeclaration<'a>),
+    /// 表示注解接口声明。
+    Annotation(AnnotationDeclaration<'a>),
+    /// 恢复解析时跳过的无法解析的顶层声明。
+    Error(ErrorNode<'a>),
 }
 
 impl<'a> Display for TopLevelClassOrInterfaceDeclaration<'a> {
@@ -16,6 +27,10 @@ impl<'a> Display for TopLevelClassOrInterfaceDeclaration<'a> {
         match self {
             Self::Class(r) => Display::fmt(r, f),
             Self::Interface(r) => Display::fmt(r, f),
+            Self::Enum(r) => Display::fmt(r, f),
+            Self::Record(r) => Display::fmt(r, f),
+            Self::Annotation(r) => Display::fmt(r, f),
+            Self::Error(r) => Display::fmt(r, f),
         }
     }
 }
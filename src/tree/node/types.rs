@@ -0,0 +1,129 @@
+use crate::Token;
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Type表示Java中的类型，可以是基本类型、引用类型或数组类型。
+#[derive(Clone, Debug, PartialEq)]
+pub enum Type<'a> {
+    /// 基本类型，例如`int`。
+    Basic(BasicType),
+    /// 引用类型，例如`java.util.List<String>`。
+    Reference(ReferenceType<'a>),
+    /// 数组类型，参数是元素类型，每一层表示一个维度，例如`int[][]`。
+    Array(Box<Type<'a>>),
+}
+
+impl<'a> Type<'a> {
+    /// 获取数组类型的维度，非数组类型返回0。
+    pub fn dimensions(&self) -> usize {
+        match self {
+            Self::Array(t) => t.dimensions() + 1,
+            _ => 0,
+        }
+    }
+}
+
+impl<'a> Display for Type<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Basic(t) => Display::fmt(t, f),
+            Self::Reference(t) => Display::fmt(t, f),
+            Self::Array(t) => write!(f, "{}[]", t),
+        }
+    }
+}
+
+/// BasicType表示Java中的基本类型。
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BasicType {
+    /// boolean
+    Boolean,
+    /// byte
+    Byte,
+    /// char
+    Char,
+    /// short
+    Short,
+    /// int
+    Int,
+    /// long
+    Long,
+    /// float
+    Float,
+    /// double
+    Double,
+}
+
+impl Display for BasicType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let text = match self {
+            Self::Boolean => Token::BOOLEAN,
+            Self::Byte => Token::BYTE,
+            Self::Char => Token::CHAR,
+            Self::Short => Token::SHORT,
+            Self::Int => Token::INT,
+            Self::Long => Token::LONG,
+            Self::Float => Token::FLOAT,
+            Self::Double => Token::DOUBLE,
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// ReferenceType表示Java中的类或接口类型（包括类型变量）。
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReferenceType<'a> {
+    /// 类型的名称，可以是限定名称，例如`java.util.Map`。
+    pub name: Cow<'a, str>,
+    /// 类型参数，`None`表示没有类型参数，空列表表示菱形`<>`。
+    pub arguments: Option<Vec<TypeArgument<'a>>>,
+    /// 内部类型，例如`Outer<T>.Inner`中的`Inner`。
+    pub sub_type: Option<Box<ReferenceType<'a>>>,
+}
+
+impl<'a> Display for ReferenceType<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{}", self.name)?;
+        if let Some(ref arguments) = self.arguments {
+            write!(f, "<")?;
+            for (i, a) in arguments.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                Display::fmt(a, f)?;
+            }
+            write!(f, ">")?;
+        }
+        if let Some(ref sub_type) = self.sub_type {
+            write!(f, ".{}", sub_type)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// TypeArgument表示泛型类型的类型参数。
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypeArgument<'a> {
+    /// 具体的类型参数，例如`List<String>`中的`String`。
+    Type(Type<'a>),
+    /// 无界通配符`?`。
+    Wildcard,
+    /// 上界通配符`? extends T`。
+    Extends(Type<'a>),
+    /// 下界通配符`? super T`。
+    Super(Type<'a>),
+}
+
+impl<'a> Display for TypeArgument<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Type(t) => Display::fmt(t, f),
+            Self::Wildcard => write!(f, "?"),
+            Self::Extends(t) => write!(f, "? extends {}", t),
+            Self::Super(t) => write!(f, "? super {}", t),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+/// 解析选项，通过[`TokenStream::with_options`](crate::TokenStream::with_options)设置。
+///
+/// 解析不受信任的输入时可以调低各项限制，超过限制时解析会返回[`ParseErrorKind::Limit`](crate::ParseErrorKind::Limit)错误，
+/// 而不是耗尽栈空间或内存：
+///
+/// ```rust
+/// use java_lang::{parse_str_with_options, ParseErrorKind, ParseLimit, ParseOptions};
+/// let options = ParseOptions {
+///     max_depth: 16,
+///     max_source_len: 1024,
+///     ..Default::default()
+/// };
+/// let source = format!("class A {{ int a = {}1{}; }}", "(".repeat(100), ")".repeat(100));
+/// let error = parse_str_with_options(&source, options).unwrap_err();
+/// assert_eq!(error.kind(), ParseErrorKind::Limit(ParseLimit::Depth(16)));
+///
+/// let error = parse_str_with_options(&"/* 很长的文件 */".repeat(100), options).unwrap_err();
+/// assert_eq!(error.to_string(), "第1行第536列：源代码大小超过了限制（1024字节）");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParseOptions {
+    /// 是否启用预览特性，例如模块导入声明`import module java.base;`。
+    pub preview: bool,
+    /// 表达式、语句、类型参数、类体等语法结构的最大嵌套层数，默认为256。
+    ///
+    /// 解析嵌套的语法结构时栈空间不足会自动扩展，所以在任何线程中解析都不会栈溢出，这个限制只约束深度嵌套的输入占用的内存。
+    /// `else if`链按循环解析，不计入嵌套层数。
+    pub max_depth: usize,
+    /// 最多允许的标记数量（不包括普通注释），默认不限制。
+    pub max_tokens: usize,
+    /// 源代码最多允许的字节数，默认不限制。
+    pub max_source_len: usize,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            preview: false,
+            max_depth: 256,
+            max_tokens: usize::MAX,
+            max_source_len: usize::MAX,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// 启用预览特性的解析选项。
+    pub fn preview() -> Self {
+        Self {
+            preview: true,
+            ..Default::default()
+        }
+    }
+}
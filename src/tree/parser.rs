@@ -1,33 +1,363 @@
+mod annotation;
+mod block;
+mod class;
+mod comment;
 mod compilation_unit;
+mod expression;
 mod import;
+mod javadoc;
+mod module;
 mod package;
+mod pattern;
+mod recover;
+mod snippet;
+mod statement;
+mod switch;
+mod types;
 
-pub use {compilation_unit::*, import::*, package::*};
+pub use {
+    annotation::*, block::*, class::*, compilation_unit::*, expression::*, import::*, javadoc::*,
+    module::*, package::*, pattern::*, snippet::*, statement::*, switch::*, types::*,
+};
 
-use super::{CompilationUnitDeclaration,DocumentationComment};
-use crate::{Token, TokenStream};
-use nom::{error::ErrorKind, IResult, Input};
+use super::{CompilationUnitDeclaration, DocumentationComment, ParseOptions};
+use comment::CommentCollector;
+use recover::{insert_missing, is_member_start, is_top_level_start, recover};
+use crate::{
+    syntax::NodeKind, ts, Expectation, ParseError, ParseLimit, RecoveredParse, SyntaxKind, Token,
+    TokenInfo, TokenStream,
+};
+use nom::{
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    IResult, Input, Parser,
+};
+use std::borrow::Cow;
 
+/// 获取声明前面的文档注释，和javac一样只有最后一个（紧挨着声明的）文档注释有效。
+///
+/// 文档注释和普通注释一起记录在第一个标记上（见[`TokenStream::without_comments`]），所以不消耗标记；
+/// 声明前面没有文档注释时返回错误。
 fn documentation_comment<'a>(tokens: TokenStream) -> IResult<TokenStream, DocumentationComment<'a>> {
-    let (tokens, out) = tokens
-        .split_at_position1_complete(|token| !token.is_documentation(), ErrorKind::Complete)?;
-
-    let token = out.iter_elements().next().unwrap();
-    let documentation = if let Token::JavaDoc(d) = token {
-        d
-    } else {
-        Default::default()
+    let last = tokens.comments().iter().rev().find(|(t, _)| t.is_documentation());
+    let documentation = match last {
+        Some((Token::MarkdownDoc(d), _)) => DocumentationComment::markdown(d.clone()),
+        Some((Token::JavaDoc(d), _)) => d.clone().into(),
+        _ => return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Tag))),
     };
-    Ok((tokens, documentation.into()))
+    Ok((tokens, documentation))
+}
+
+/// 匹配`expected`中的标记序列，失败时记录期望的标记，用于生成错误信息。
+fn tag(expected: TokenStream) -> impl Fn(TokenStream) -> IResult<TokenStream, TokenStream> {
+    move |tokens: TokenStream| {
+        let mismatch = expected
+            .as_slice()
+            .iter()
+            .enumerate()
+            .find(|(i, t)| tokens.as_slice().get(*i) != Some(t));
+        match mismatch {
+            Some((i, t)) => {
+                tokens.expect(i, format_args!("`{}`", t));
+                Err(nom::Err::Error(Error::new(tokens, ErrorKind::Tag)))
+            }
+            None => Ok(tokens.take_split(expected.input_len())),
+        }
+    }
 }
 
 fn identifier(tokens: TokenStream) -> IResult<TokenStream, Token> {
-    let (tokens, out) =
-        tokens.split_at_position1_complete(|token| !token.is_identifier(), ErrorKind::Complete)?;
-    let token = out.iter_elements().next().unwrap();
-    Ok((tokens, token))
+    if !tokens.first().is_some_and(Token::is_identifier) {
+        tokens.expect(0, "标识符");
+    }
+    next_token(|token| token.is_identifier().then(|| token.clone()))(tokens)
+}
+
+/// 解析以`.`分隔的限定名称，例如`java.util.List`。
+fn qualified_name<'a>(tokens: TokenStream) -> IResult<TokenStream, Cow<'a, str>> {
+    let (tokens, idents) = separated_list1(tag(ts![Dot]), identifier).parse(tokens)?;
+    let name = idents
+        .into_iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(Token::DOT);
+    Ok((tokens, Cow::Owned(name)))
+}
+
+/// 如果第一个标记能被`f`转换则消费它，否则返回错误。
+fn next_token<T, F>(f: F) -> impl Fn(TokenStream) -> IResult<TokenStream, T>
+where
+    F: Fn(&Token) -> Option<T>,
+{
+    move |tokens: TokenStream| match tokens.first().and_then(&f) {
+        Some(out) => Ok((tokens.take_from(1), out)),
+        None => Err(nom::Err::Error(Error::new(tokens, ErrorKind::Tag))),
+    }
+}
+
+/// 消费一个`>`，必要时把`>>`、`>>>`、`>=`等复合标记拆开，用于闭合类型参数。
+fn greater_than(tokens: TokenStream) -> IResult<TokenStream, ()> {
+    let rest = match tokens.first() {
+        Some(Token::GreaterThan) => return Ok((tokens.take_from(1), ())),
+        Some(Token::ShiftRight) => Token::GreaterThan,
+        Some(Token::UnsignedShiftRight) => Token::ShiftRight,
+        Some(Token::GreaterThanOrEqual) => Token::Assign,
+        Some(Token::ShiftRightAssign) => Token::GreaterThanOrEqual,
+        Some(Token::UnsignedShiftRightAssign) => Token::ShiftRightAssign,
+        _ => {
+            tokens.expect(0, "`>`");
+            return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Tag)));
+        }
+    };
+    Ok((tokens.replace_first(rest), ()))
 }
 
+/// 剩余的栈空间少于这个值时在新分配的栈上继续解析，见[`nested`]。
+const STACK_RED_ZONE: usize = 256 * 1024;
+/// 每次扩展时分配的栈空间。
+const STACK_GROWTH: usize = 4 * 1024 * 1024;
+
+/// 在一层新的嵌套中用`parser`解析，嵌套层数超过[`ParseOptions::max_depth`]时返回`Failure`。
+///
+/// 未优化的构建中每层嵌套会使用几十KB的栈空间，所以剩余的栈空间不足时会在新分配的栈上继续解析，而不是栈溢出。
+fn nested<T>(
+    tokens: TokenStream,
+    parser: impl FnOnce(TokenStream) -> IResult<TokenStream, T>,
+) -> IResult<TokenStream, T> {
+    let _depth = tokens.enter()?;
+    stacker::maybe_grow(STACK_RED_ZONE, STACK_GROWTH, || parser(tokens))
+}
+
+/// 构建具体语法树时把`parser`解析出的内容记录为种类是`kind`的语法节点，见[`crate::parse_syntax`]。
+fn syntax_node<T>(
+    kind: SyntaxKind,
+    mut parser: impl Parser<TokenStream, Output = T, Error = Error<TokenStream>>,
+) -> impl FnMut(TokenStream) -> IResult<TokenStream, T> {
+    move |tokens: TokenStream| {
+        let start = tokens.source_offset();
+        let (tokens, node) = parser.parse(tokens)?;
+        tokens.finish_node(start, kind);
+        Ok((tokens, node))
+    }
+}
+
+/// 和[`syntax_node`]相同，但语法节点的种类由解析出的内容决定，没有对应的种类时不记录。
+fn syntax_node_for<T: NodeKind>(
+    mut parser: impl Parser<TokenStream, Output = T, Error = Error<TokenStream>>,
+) -> impl FnMut(TokenStream) -> IResult<TokenStream, T> {
+    move |tokens: TokenStream| {
+        let start = tokens.source_offset();
+        let (tokens, node) = parser.parse(tokens)?;
+        tokens.finish_node_for(start, &node);
+        Ok((tokens, node))
+    }
+}
+
+/// 解析编译单元，标记流中的普通注释会作为前导、尾随或悬空注释关联到声明和语句上（见[`super::Commented`]）。
+///
+/// 标记数量或嵌套层数超过解析选项（[`ParseOptions`]）中的限制时返回`Failure`。
 pub fn parse<'a>(tokens: TokenStream) -> IResult<TokenStream, CompilationUnitDeclaration<'a>> {
+    let tokens = tokens.without_comments();
+    let max_tokens = tokens.options().max_tokens;
+    if tokens.input_len() > max_tokens {
+        return Err(tokens.exceed(ParseLimit::Tokens(max_tokens), max_tokens));
+    }
     compilation_unit_declaration(tokens)
 }
+
+/// 解析Java源代码
+///
+/// 该函数对源代码进行词法分析和语法分析，返回完整的编译单元；和[`parse`]不同，
+/// 失败时返回的[`ParseError`]指出了期望的内容、实际遇到的内容和出错的行列号。
+///
+/// # 参数
+///
+/// * `source` - Java源代码。
+///
+/// # 返回值
+///
+/// 返回解析后的编译单元，源代码必须被完整地解析。
+///
+/// # 示例
+///
+/// ```rust
+/// use java_lang::parse_str;
+/// let cu = parse_str("package com.example;\nimport java.util.List;").unwrap();
+/// assert_eq!(cu.imports().len(), 1);
+///
+/// let error = parse_str("package com.example;\nimport java.util.List\nclass A {}").unwrap_err();
+/// assert_eq!((error.line(), error.column()), (3, 1));
+/// assert_eq!(error.to_string(), "第3行第1列：期望`.`或`;`，但遇到了`class`");
+/// ```
+///
+/// # 错误处理
+///
+/// 如果源代码中有无法识别的字符或不符合Java语法，将返回一个解析错误。
+pub fn parse_str<'a>(source: &str) -> Result<CompilationUnitDeclaration<'a>, ParseError> {
+    parse_str_with_options(source, Default::default())
+}
+
+/// 使用指定的解析选项解析Java源代码，参见[`parse_str`]。
+pub fn parse_str_with_options<'a>(
+    source: &str,
+    options: ParseOptions,
+) -> Result<CompilationUnitDeclaration<'a>, ParseError> {
+    if source.len() > options.max_source_len {
+        return Err(source_len_exceeded(source, options));
+    }
+    let tokens = TokenStream::from_source(source)?.with_options(options);
+    parse_tokens(source, tokens, parse)
+}
+
+/// 用`parser`解析源代码`source`词法分析得到的标记流`tokens`，标记流必须被完整地解析。
+///
+/// 失败时返回的[`ParseError`]指出了超过的限制，或者最靠后的失败位置上期望的内容。
+pub(crate) fn parse_tokens<T>(
+    source: &str,
+    tokens: TokenStream,
+    parser: impl FnOnce(TokenStream) -> IResult<TokenStream, T>,
+) -> Result<T, ParseError> {
+    let at = match parser(tokens.clone()) {
+        Ok((remaining, node)) if remaining.is_empty() => return Ok(node),
+        Ok((remaining, _)) => remaining,
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => e.input,
+        Err(nom::Err::Incomplete(_)) => TokenStream::default(),
+    };
+    if let Some((kind, info)) = tokens.fatal() {
+        return Err(ParseError::fatal(source, kind, info));
+    }
+    // 优先报告最靠后的失败位置上期望的内容，它通常比回溯后的位置更接近真正的错误
+    let error = match tokens.expectation() {
+        Some(e) if e.remaining <= at.input_len() => ParseError::syntax(source, e.expected, e.found),
+        _ => ParseError::syntax(
+            source,
+            Vec::new(),
+            at.first().map(|t| (t.clone(), at.info().cloned())),
+        ),
+    };
+
+    Err(error)
+}
+
+/// 以恢复模式解析Java源代码
+///
+/// 和[`parse_str`]不同，该函数不会在第一个错误处停止：无法识别的字符会被跳过；
+/// 缺少的`;`、`)`、`]`、`}`会被补上并记录在[`RecoveredParse::missing`]中；
+/// 其余无法解析的语句、成员声明和顶层声明会跳过到下一个`;`、`}`或声明的开头，用[`super::ErrorNode`]代替。
+/// 适合在编辑器等需要处理不完整代码的场景中使用。
+///
+/// # 参数
+///
+/// * `source` - Java源代码。
+/// * `options` - 解析选项。
+///
+/// # 返回值
+///
+/// 返回尽可能解析出的编译单元和遇到的所有错误，错误按出现位置排序。
+///
+/// # 示例
+///
+/// ```rust
+/// use java_lang::{
+///     parse_str_recovering, ClassBodyDeclaration, CompilationUnitDeclaration,
+///     TopLevelClassOrInterfaceDeclaration,
+/// };
+/// let result = parse_str_recovering(
+///     "class A {\n    int a = 1\n    int b = ;\n    void f() {}\n}",
+///     Default::default(),
+/// );
+/// let errors: Vec<_> = result.diagnostics.iter().map(|e| e.to_string()).collect();
+/// assert_eq!(
+///     errors,
+///     [
+///         "第3行第5列：期望`,`或`;`，但遇到了`int`",
+///         "第3行第13列：期望标识符、`(`或表达式，但遇到了`;`",
+///     ]
+/// );
+///
+/// let CompilationUnitDeclaration::Ordinary { top_level_class_or_interfaces, .. } = result.unit else {
+///     panic!();
+/// };
+/// let TopLevelClassOrInterfaceDeclaration::Class(ref class) = top_level_class_or_interfaces[0] else {
+///     panic!();
+/// };
+/// let declarations = &class.body.declarations;
+/// assert!(matches!(declarations[0], ClassBodyDeclaration::Field(_)));
+/// assert!(matches!(declarations[1], ClassBodyDeclaration::Error(_)));
+/// assert!(matches!(declarations[2], ClassBodyDeclaration::Method(_)));
+/// ```
+///
+/// # 错误处理
+///
+/// 该函数总是返回一个编译单元，完全无法解析时返回空的编译单元，错误都记录在[`RecoveredParse::diagnostics`]中。
+pub fn parse_str_recovering<'a>(source: &str, options: ParseOptions) -> RecoveredParse<'a> {
+    let empty = || CompilationUnitDeclaration::Ordinary {
+        package: None,
+        imports: Vec::new(),
+        import_comments: Vec::new(),
+        top_level_class_or_interfaces: Vec::new(),
+    };
+    if source.len() > options.max_source_len {
+        return RecoveredParse {
+            unit: empty(),
+            diagnostics: vec![source_len_exceeded(source, options)],
+            missing: Vec::new(),
+        };
+    }
+    let (tokens, mut diagnostics) = TokenStream::from_str_recovering(source);
+    let tokens = tokens.with_options(options).recovering();
+    let (rest, unit) = match parse(tokens.clone()) {
+        Ok((rest, unit)) => (rest, unit),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => (e.input, empty()),
+        Err(nom::Err::Incomplete(_)) => (TokenStream::default(), empty()),
+    };
+    if let Some((kind, info)) = tokens.fatal() {
+        // 超过限制或遇到字面量错误时解析被终止，之前记录的语法错误没有意义
+        diagnostics.push(ParseError::fatal(source, kind, info));
+        return RecoveredParse {
+            unit,
+            diagnostics,
+            missing: Vec::new(),
+        };
+    }
+    let mut errors = tokens.diagnostics();
+    if let Some(token) = rest.first() {
+        errors.push(match tokens.expectation() {
+            Some(e) if e.remaining <= rest.input_len() => e,
+            _ => Expectation {
+                remaining: rest.input_len(),
+                expected: Vec::new(),
+                found: Some((token.clone(), rest.info().cloned())),
+                missing: Vec::new(),
+            },
+        });
+    }
+    let mut missing: Vec<_> = errors.iter().flat_map(|e| e.missing.clone()).collect();
+    missing.sort_by_key(|m| m.offset);
+    diagnostics.extend(
+        errors
+            .into_iter()
+            .map(|e| ParseError::syntax(source, e.expected, e.found)),
+    );
+    diagnostics.sort_by_key(|e| e.span().start);
+
+    RecoveredParse {
+        unit,
+        diagnostics,
+        missing,
+    }
+}
+
+/// 创建源代码大小超过限制的错误，位置是第一个超出限制的字符。
+pub(crate) fn source_len_exceeded(source: &str, options: ParseOptions) -> ParseError {
+    let limit = options.max_source_len;
+    let mut offset = limit;
+    while !source.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let info = TokenInfo {
+        span: offset..offset,
+        newline_before: false,
+    };
+    ParseError::limit(source, ParseLimit::SourceLen(limit), Some(info))
+}
@@ -0,0 +1,231 @@
+use super::{
+    super::{Annotation, AnnotationElement, ElementValue, ElementValuePair, Modifier},
+    expression, identifier, nested, syntax_node, tag,
+};
+use crate::{ts, SyntaxKind, Token, TokenStream};
+use nom::{
+    branch::alt,
+    combinator::{map, opt},
+    multi::{separated_list0, separated_list1},
+    sequence::{delimited, separated_pair, terminated},
+    IResult, Input, Parser,
+};
+use std::borrow::Cow;
+
+/// 解析注解
+///
+/// 该函数解析一个注解，例如`@Override`、`@SuppressWarnings("unchecked")`或`@Target(value = {TYPE, METHOD})`，
+/// 并返回解析后的注解和剩余的标记流。
+///
+/// # 参数
+///
+/// * `tokens` - 标记流，包含待解析的标记。
+///
+/// # 返回值
+///
+/// 返回一个 `IResult`，其中包含解析后剩余的标记流和注解。
+///
+/// # 示例
+///
+/// ```rust
+/// fn main() -> anyhow::Result<()> {
+/// use java_lang::{TokenStream, annotation};
+/// let (_, tokens) = TokenStream::from_str("@SuppressWarnings(\"unchecked\")")?;
+/// let (tokens, a) = annotation(tokens)?;
+/// assert_eq!(a.name, "SuppressWarnings");
+/// assert!(a.element.is_some());
+/// assert!(tokens.is_empty());
+/// Ok(())
+/// }
+/// ```
+///
+/// # 错误处理
+///
+/// 如果解析过程中出现错误，将返回一个解析错误。
+pub fn annotation<'a>(tokens: TokenStream) -> IResult<TokenStream, Annotation<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, _) = tag(ts![At]).parse(tokens)?;
+    let (tokens, idents) = separated_list1(tag(ts![Dot]), identifier).parse(tokens)?;
+    let name = idents
+        .into_iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(Token::DOT);
+    let (tokens, element) = opt(delimited(
+        tag(ts![LeftParen]),
+        annotation_element,
+        tag(ts![RightParen]),
+    ))
+    .parse(tokens)?;
+    tokens.finish_node(start, SyntaxKind::Annotation);
+
+    Ok((
+        tokens,
+        Annotation {
+            name: Cow::Owned(name),
+            element,
+        },
+    ))
+}
+
+fn annotation_element<'a>(tokens: TokenStream) -> IResult<TokenStream, AnnotationElement<'a>> {
+    if let Some(Token::RightParen) = tokens.first() {
+        return Ok((tokens, AnnotationElement::Pairs(Vec::new())));
+    }
+    alt((
+        map(
+            separated_list1(
+                tag(ts![Comma]),
+                syntax_node(SyntaxKind::ElementValuePair, element_value_pair),
+            ),
+            AnnotationElement::Pairs,
+        ),
+        map(element_value, AnnotationElement::Value),
+    ))
+    .parse(tokens)
+}
+
+fn element_value_pair<'a>(tokens: TokenStream) -> IResult<TokenStream, ElementValuePair<'a>> {
+    map(
+        separated_pair(identifier, tag(ts![Assign]), element_value),
+        |(name, value)| ElementValuePair {
+            name: Cow::Owned(name.to_string()),
+            value,
+        },
+    )
+    .parse(tokens)
+}
+
+/// 解析注解元素的值：表达式、嵌套注解或`{...}`数组。
+pub(crate) fn element_value<'a>(tokens: TokenStream) -> IResult<TokenStream, ElementValue<'a>> {
+    nested(tokens, |tokens| {
+        let start = tokens.source_offset();
+        let (tokens, value) = match tokens.first() {
+            Some(Token::At) => {
+                map(annotation, |a| ElementValue::Annotation(Box::new(a))).parse(tokens)
+            }
+            Some(Token::LeftBrace) => map(
+                delimited(
+                    tag(ts![LeftBrace]),
+                    terminated(
+                        separated_list0(tag(ts![Comma]), element_value),
+                        opt(tag(ts![Comma])),
+                    ),
+                    tag(ts![RightBrace]),
+                ),
+                ElementValue::Array,
+            )
+            .parse(tokens),
+            _ => map(expression, ElementValue::Expression).parse(tokens),
+        }?;
+        tokens.finish_node_for(start, &value);
+        Ok((tokens, value))
+    })
+}
+
+/// 解析声明前面的注解和修饰符，例如`@Deprecated public static final`。
+///
+/// 返回的注解和修饰符分别保存，注解接口声明的`@interface`不会被当作注解消费。
+pub fn modifiers<'a>(
+    mut tokens: TokenStream,
+) -> IResult<TokenStream, (Vec<Annotation<'a>>, Vec<Modifier>)> {
+    let start = tokens.source_offset();
+    let mut annotations = Vec::new();
+    let mut modifiers = Vec::new();
+    loop {
+        match tokens.first() {
+            Some(Token::At) => {
+                if let Ok((remaining, a)) = annotation(tokens.clone()) {
+                    annotations.push(a);
+                    tokens = remaining;
+                    continue;
+                }
+                break;
+            }
+            Some(Token::Identifier(i)) if i == "non" => {
+                // `non-sealed`由三个标记组成
+                let non_sealed = TokenStream::from_vec(vec![
+                    Token::Identifier("non".into()),
+                    Token::Minus,
+                    Token::Identifier(Modifier::SEALED.into()),
+                ]);
+                let Ok((remaining, _)) = tag(non_sealed).parse(tokens.clone()) else {
+                    break;
+                };
+                modifiers.push(Modifier::NonSealed);
+                tokens = remaining;
+            }
+            Some(Token::Identifier(i)) if i == Modifier::SEALED => {
+                // 只有后面还跟着声明的其余部分时，`sealed`才是修饰符
+                let next = tokens.take_from(1);
+                if !next
+                    .first()
+                    .is_some_and(|t| t.is_keyword() || t.is_identifier() || t.is_annotation())
+                {
+                    break;
+                }
+                modifiers.push(Modifier::Sealed);
+                tokens = next;
+            }
+            Some(token) => {
+                let Ok(modifier) = Modifier::try_from(token) else {
+                    break;
+                };
+                modifiers.push(modifier);
+                tokens = tokens.take_from(1);
+            }
+            None => break,
+        }
+    }
+    if !annotations.is_empty() || !modifiers.is_empty() {
+        tokens.finish_node(start, SyntaxKind::Modifiers);
+    }
+
+    Ok((tokens, (annotations, modifiers)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Expression, Literal};
+
+    #[test]
+    fn test_annotation() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str(
+            "@Target(value = {ElementType.TYPE, ElementType.METHOD}, inner = @Inner(1))",
+        )?;
+        let (tokens, a) = annotation(tokens)?;
+        assert_eq!(a.name, "Target");
+        let Some(AnnotationElement::Pairs(pairs)) = a.element else {
+            panic!();
+        };
+        assert_eq!(pairs.len(), 2);
+        assert!(matches!(&pairs[0].value, ElementValue::Array(v) if v.len() == 2));
+        let ElementValue::Annotation(ref inner) = pairs[1].value else {
+            panic!();
+        };
+        assert_eq!(
+            inner.element,
+            Some(AnnotationElement::Value(ElementValue::Expression(
+                Expression::Literal(Literal::Integer(1))
+            )))
+        );
+        assert!(tokens.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_modifiers() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str("@Deprecated public static non-sealed @interface")?;
+        let (tokens, (annotations, modifiers)) = modifiers(tokens)?;
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(
+            modifiers,
+            vec![Modifier::Public, Modifier::Static, Modifier::NonSealed]
+        );
+        assert_eq!(tokens.first(), Some(&Token::At));
+
+        Ok(())
+    }
+}
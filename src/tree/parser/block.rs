@@ -0,0 +1,169 @@
+use super::{
+    super::{Block, ConstructorBody, Statement},
+    block_statement, explicit_constructor_invocation, recover, syntax_node, tag, CommentCollector,
+};
+use crate::{ts, SyntaxKind, Token, TokenStream};
+use nom::{IResult, Parser};
+
+/// 解析代码块
+///
+/// 该函数解析由大括号包围的代码块（例如方法体），并返回解析后的代码块和剩余的标记流。
+///
+/// # 参数
+///
+/// * `tokens` - 标记流，包含待解析的标记。
+///
+/// # 返回值
+///
+/// 返回一个 `IResult`，其中包含解析后剩余的标记流和代码块。
+///
+/// # 示例
+///
+/// ```rust
+/// fn main() -> anyhow::Result<()> {
+/// use java_lang::{TokenStream, block, Statement};
+/// let (_, tokens) = TokenStream::from_str("{ int a = 1; if (a > 0) { a--; } } c")?;
+/// let (tokens, b) = block(tokens)?;
+/// assert_eq!(b.statements.len(), 2);
+/// assert!(matches!(b.statements[1], Statement::If { .. }));
+/// assert_eq!(tokens.first(), Some(&java_lang::Token::Identifier("c".into())));
+/// Ok(())
+/// }
+/// ```
+///
+/// # 错误处理
+///
+/// 如果代码块中的语句无法解析或大括号不匹配，将返回一个解析错误。
+pub fn block<'a>(tokens: TokenStream) -> IResult<TokenStream, Block<'a>> {
+    let start = tokens.source_offset();
+    let (mut tokens, _) = tag(ts![LeftBrace]).parse(tokens)?;
+    let mut block = Block::default();
+    let mut comments = CommentCollector::default();
+    loop {
+        comments.collect(&tokens, block.comments.last_mut());
+        if matches!(tokens.first(), Some(Token::RightBrace) | None) {
+            break;
+        }
+        let (remaining, statement) = recover(tokens, block_statement, |_| false, Statement::Error)?;
+        block.statements.push(statement);
+        block.comments.push(comments.leading());
+        tokens = remaining;
+    }
+    let (tokens, _) = tag(ts![RightBrace]).parse(tokens)?;
+    block.dangling = comments.dangling();
+    tokens.finish_node(start, SyntaxKind::Block);
+
+    Ok((tokens, block))
+}
+
+/// 解析构造函数体
+///
+/// 该函数解析由大括号包围的构造函数体，构造函数体中最多有一个显式构造函数调用（`this(...)`或`super(...)`），
+/// 启用预览特性时，它前面可以有不引用当前实例的语句（灵活的构造函数体）。
+/// 返回解析后的构造函数体和剩余的标记流。
+///
+/// # 参数
+///
+/// * `tokens` - 标记流，包含待解析的标记。
+///
+/// # 返回值
+///
+/// 返回一个 `IResult`，其中包含解析后剩余的标记流和构造函数体。
+///
+/// # 示例
+///
+/// ```rust
+/// fn main() -> anyhow::Result<()> {
+/// use java_lang::{ParseOptions, TokenStream, constructor_body};
+/// let (_, tokens) = TokenStream::from_str("{
+///     if (value < 0) throw new IllegalArgumentException();
+///     super(value);
+///     this.cache = null;
+/// }")?;
+/// let (tokens, b) = constructor_body(tokens.with_options(ParseOptions::preview()))?;
+/// assert_eq!(b.prologue.len(), 1);
+/// assert!(b.invocation.is_some());
+/// assert_eq!(b.epilogue.len(), 1);
+/// assert!(tokens.is_empty());
+/// Ok(())
+/// }
+/// ```
+///
+/// # 错误处理
+///
+/// 如果语句无法解析、出现多个显式构造函数调用、未启用预览特性时显式构造函数调用前面有语句或大括号不匹配，
+/// 将返回一个解析错误。
+pub fn constructor_body<'a>(tokens: TokenStream) -> IResult<TokenStream, ConstructorBody<'a>> {
+    let start = tokens.source_offset();
+    let (mut tokens, _) = tag(ts![LeftBrace]).parse(tokens)?;
+    let mut body = ConstructorBody::default();
+    let mut comments = CommentCollector::default();
+    loop {
+        comments.collect(&tokens, body.comments.last_mut());
+        if matches!(tokens.first(), Some(Token::RightBrace) | None) {
+            break;
+        }
+        // 显式构造函数调用前面的语句是预览特性
+        if body.invocation.is_none() && (body.prologue.is_empty() || tokens.options().preview) {
+            let invocation = syntax_node(
+                SyntaxKind::ExplicitConstructorInvocation,
+                explicit_constructor_invocation,
+            )
+            .parse(tokens.clone());
+            if let Ok((remaining, i)) = invocation {
+                body.invocation = Some(i);
+                body.comments.push(comments.leading());
+                tokens = remaining;
+                continue;
+            }
+        }
+        let (remaining, statement) = recover(tokens, block_statement, |_| false, Statement::Error)?;
+        match body.invocation {
+            Some(_) => body.epilogue.push(statement),
+            None => body.prologue.push(statement),
+        }
+        body.comments.push(comments.leading());
+        tokens = remaining;
+    }
+    let (tokens, _) = tag(ts![RightBrace]).parse(tokens)?;
+    body.dangling = comments.dangling();
+    if body.invocation.is_none() {
+        // 没有显式构造函数调用时，所有语句都在隐式的`super()`之后执行
+        body.epilogue = std::mem::take(&mut body.prologue);
+    }
+    tokens.finish_node(start, SyntaxKind::ConstructorBody);
+
+    Ok((tokens, body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConstructorInvocationKind, ParseOptions};
+
+    #[test]
+    fn test_constructor_body() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str("{ this.x = x; init(); }")?;
+        let (_, b) = constructor_body(tokens)?;
+        assert!(b.prologue.is_empty());
+        assert!(b.invocation.is_none());
+        assert_eq!(b.epilogue.len(), 2);
+
+        let (_, tokens) = TokenStream::from_str(
+            "{ var checked = check(a); outer.<T>super(checked, b); this.a = a; log(); }",
+        )?;
+        assert!(constructor_body(tokens.clone()).is_err());
+        let (_, b) = constructor_body(tokens.with_options(ParseOptions::preview()))?;
+        assert_eq!(b.prologue.len(), 1);
+        let invocation = b.invocation.unwrap();
+        assert_eq!(invocation.kind, ConstructorInvocationKind::Super);
+        assert_eq!(invocation.to_string(), "outer.<T>super(checked, b);");
+        assert_eq!(b.epilogue.len(), 2);
+
+        // 只能有一个显式构造函数调用
+        let (_, tokens) = TokenStream::from_str("{ this(1); super(); }")?;
+        assert!(constructor_body(tokens).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,817 @@
+use super::{
+    super::{
+        Annotation, AnnotationDeclaration, AnnotationMethod, ClassBody, ClassBodyDeclaration,
+        ClassDeclaration, Comment, Commented, ConstructorDeclaration, DocumentationComment,
+        EnumConstantDeclaration, EnumDeclaration, FieldDeclaration, FormalParameter, Initializer,
+        InterfaceDeclaration, MethodDeclaration, Modifier, RecordComponent, RecordDeclaration,
+        ReferenceType, TopLevelClassOrInterfaceDeclaration, TypeParameter, VariableDeclarator,
+    },
+    annotation, arguments, array_of, block, constructor_body, dimensions, documentation_comment,
+    element_value, greater_than, identifier, is_member_start, modifiers, nested, r#type, recover,
+    reference_type, syntax_node, tag, variable_initializer, CommentCollector,
+};
+use crate::{ts, SyntaxKind, Token, TokenStream};
+use nom::{
+    combinator::opt,
+    error::{Error, ErrorKind},
+    multi::{many0, separated_list0, separated_list1},
+    sequence::preceded,
+    IResult, Input, Parser,
+};
+use std::borrow::Cow;
+
+const RECORD: &str = "record";
+const PERMITS: &str = "permits";
+
+/// 声明前面的文档注释、注解和修饰符。
+type Header<'a> = (
+    Option<DocumentationComment<'a>>,
+    Vec<Annotation<'a>>,
+    Vec<Modifier>,
+);
+
+/// 解析顶层类或接口声明
+///
+/// 该函数解析一个顶层的类、接口、枚举、记录或注解接口声明（包括前面的文档注释、注解和修饰符），
+/// 并返回解析后的声明和剩余的标记流。
+///
+/// # 参数
+///
+/// * `tokens` - 标记流，包含待解析的标记。
+///
+/// # 返回值
+///
+/// 返回一个 `IResult`，其中包含解析后剩余的标记流和顶层类或接口声明。
+///
+/// # 示例
+///
+/// ```rust
+/// fn main() -> anyhow::Result<()> {
+/// use java_lang::{TokenStream, TopLevelClassOrInterfaceDeclaration, top_level_class_or_interface_declaration};
+/// let (_, tokens) = TokenStream::from_str("
+/// public final class Point<T extends Number> implements Comparable<Point<T>> {
+///     private T x, y;
+///     public Point(T x, T y) { this.x = x; this.y = y; }
+///     public int compareTo(Point<T> o) { return 0; }
+/// }
+/// ")?;
+/// let (tokens, d) = top_level_class_or_interface_declaration(tokens)?;
+/// let TopLevelClassOrInterfaceDeclaration::Class(c) = d else { panic!() };
+/// assert_eq!(c.name, "Point");
+/// assert_eq!(c.body.declarations.len(), 3);
+/// assert!(tokens.is_empty());
+/// Ok(())
+/// }
+/// ```
+///
+/// # 错误处理
+///
+/// 如果解析过程中出现错误，将返回一个解析错误。
+pub fn top_level_class_or_interface_declaration<'a>(
+    tokens: TokenStream,
+) -> IResult<TokenStream, TopLevelClassOrInterfaceDeclaration<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, header) = header(tokens)?;
+    let (tokens, declaration) = type_declaration_rest(tokens, header)?;
+    let declaration = match declaration {
+        ClassBodyDeclaration::Class(d) => TopLevelClassOrInterfaceDeclaration::Class(d),
+        ClassBodyDeclaration::Interface(d) => TopLevelClassOrInterfaceDeclaration::Interface(d),
+        ClassBodyDeclaration::Enum(d) => TopLevelClassOrInterfaceDeclaration::Enum(d),
+        ClassBodyDeclaration::Record(d) => TopLevelClassOrInterfaceDeclaration::Record(d),
+        ClassBodyDeclaration::Annotation(d) => TopLevelClassOrInterfaceDeclaration::Annotation(d),
+        _ => unreachable!(),
+    };
+    tokens.finish_node_for(start, &declaration);
+
+    Ok((tokens, declaration))
+}
+
+/// 解析类体`{ ... }`，类体中多余的分号会被忽略。
+pub fn class_body<'a>(tokens: TokenStream) -> IResult<TokenStream, ClassBody<'a>> {
+    body(tokens, false)
+}
+
+/// 解析注解接口体`{ ... }`，其中没有参数和方法体的方法是注解元素。
+fn annotation_body<'a>(tokens: TokenStream) -> IResult<TokenStream, ClassBody<'a>> {
+    body(tokens, true)
+}
+
+/// 解析类体或注解接口体（`annotation`为`true`时），见[`class_body_declarations`]。
+fn body<'a>(tokens: TokenStream, annotation: bool) -> IResult<TokenStream, ClassBody<'a>> {
+    nested(tokens, |tokens| {
+        let start = tokens.source_offset();
+        let (tokens, _) = tag(ts![LeftBrace]).parse(tokens)?;
+        let (tokens, body) = class_body_declarations(tokens, annotation)?;
+        let (tokens, _) = tag(ts![RightBrace]).parse(tokens)?;
+        tokens.finish_node(start, SyntaxKind::ClassBody);
+        Ok((tokens, body))
+    })
+}
+
+/// 解析类体中的成员声明直到`}`或输入结束，并把普通注释关联到成员上。
+///
+/// `annotation`为`true`时成员属于注解接口，没有参数和方法体的方法会作为注解元素记录。
+pub(crate) fn class_body_declarations<'a>(
+    mut tokens: TokenStream,
+    annotation: bool,
+) -> IResult<TokenStream, ClassBody<'a>> {
+    let mut declarations: Vec<ClassBodyDeclaration> = Vec::new();
+    let mut comments = CommentCollector::default();
+    loop {
+        comments.collect(&tokens, declarations.last_mut().map(|d| d.comments_mut()));
+        match tokens.first() {
+            Some(Token::SemiColon) => tokens = tokens.take_from(1),
+            Some(Token::RightBrace) | None => break,
+            Some(_) => {
+                let start = tokens.source_offset();
+                let (remaining, mut d) = recover(
+                    tokens,
+                    class_body_declaration,
+                    is_member_start,
+                    ClassBodyDeclaration::Error,
+                )?;
+                if annotation {
+                    d = annotation_element(d);
+                }
+                remaining.finish_node_for(start, &d);
+                *d.comments_mut() = comments.leading();
+                declarations.push(d);
+                tokens = remaining;
+            }
+        }
+    }
+
+    Ok((
+        tokens,
+        ClassBody {
+            declarations,
+            dangling: comments.dangling(),
+        },
+    ))
+}
+
+/// 把注解接口中没有参数和方法体的方法转换为注解元素，其他成员保持不变。
+fn annotation_element(declaration: ClassBodyDeclaration) -> ClassBodyDeclaration {
+    match declaration {
+        ClassBodyDeclaration::Method(MethodDeclaration {
+            modifiers,
+            annotations,
+            return_type: Some(return_type),
+            name,
+            parameters,
+            body: None,
+            documentation,
+            comments,
+            ..
+        }) if parameters.is_empty() => ClassBodyDeclaration::AnnotationMethod(AnnotationMethod {
+            modifiers,
+            annotations,
+            return_type,
+            name,
+            default: None,
+            documentation,
+            comments,
+        }),
+        d => d,
+    }
+}
+
+/// 解析类体中的一个成员声明：字段、方法、构造函数、初始化块或成员类型。
+pub fn class_body_declaration<'a>(
+    tokens: TokenStream,
+) -> IResult<TokenStream, ClassBodyDeclaration<'a>> {
+    let (tokens, header) = header(tokens)?;
+    if let Ok(out) = type_declaration_rest(tokens.clone(), header.clone()) {
+        return Ok(out);
+    }
+    let (documentation, annotations, modifiers) = header;
+
+    if let Some(Token::LeftBrace) = tokens.first() {
+        let (tokens, block) = block(tokens)?;
+        return Ok((
+            tokens,
+            ClassBodyDeclaration::Initializer(Initializer {
+                r#static: modifiers == [Modifier::Static],
+                block,
+                comments: Default::default(),
+            }),
+        ));
+    }
+
+    let (tokens, type_parameters) = opt(type_parameters).parse(tokens)?;
+    let type_parameters = type_parameters.unwrap_or_default();
+
+    // 构造函数：名称后面紧跟`(`，或者记录类的紧凑构造函数名称后面紧跟`{`
+    if let [Token::Identifier(name), Token::LeftParen | Token::LeftBrace, ..] = tokens.as_slice() {
+        let name = Cow::Owned(name.clone());
+        let tokens = tokens.take_from(1);
+        let (tokens, parameters) = match tokens.first() {
+            Some(Token::LeftParen) => {
+                let (tokens, parameters) = formal_parameters(tokens)?;
+                (tokens, Some(parameters))
+            }
+            _ => (tokens, None),
+        };
+        let (tokens, throws) = throws(tokens)?;
+        let (tokens, body) = constructor_body(tokens)?;
+        return Ok((
+            tokens,
+            ClassBodyDeclaration::Constructor(ConstructorDeclaration {
+                modifiers,
+                annotations,
+                type_parameters,
+                name,
+                parameters,
+                throws,
+                body,
+                documentation,
+                comments: Default::default(),
+            }),
+        ));
+    }
+
+    let (tokens, return_type) = match tokens.first() {
+        Some(Token::Void) => (tokens.take_from(1), None),
+        _ => {
+            let (tokens, t) = r#type(tokens)?;
+            (tokens, Some(t))
+        }
+    };
+    let name_start = tokens.source_offset();
+    let (tokens, name) = identifier(tokens)?;
+    let name = Cow::Owned(name.to_string());
+
+    if let Some(Token::LeftParen) = tokens.first() {
+        let (tokens, parameters) = formal_parameters(tokens)?;
+        // 旧式的数组返回类型`int foo()[]`
+        let (tokens, dimensions) = dimensions(tokens)?;
+        let return_type = return_type.map(|t| array_of(t, dimensions));
+        if let (Some(Token::Default), Some(return_type), true) =
+            (tokens.first(), &return_type, parameters.is_empty())
+        {
+            // 注解元素的默认值
+            let (tokens, default) = element_value(tokens.take_from(1))?;
+            let (tokens, _) = tag(ts![SemiColon]).parse(tokens)?;
+            return Ok((
+                tokens,
+                ClassBodyDeclaration::AnnotationMethod(AnnotationMethod {
+                    modifiers,
+                    annotations,
+                    return_type: return_type.clone(),
+                    name,
+                    default: Some(default),
+                    documentation,
+                    comments: Default::default(),
+                }),
+            ));
+        }
+        let (tokens, throws) = throws(tokens)?;
+        let (tokens, body) = match tokens.first() {
+            Some(Token::SemiColon) => (tokens.take_from(1), None),
+            _ => {
+                let (tokens, body) = block(tokens)?;
+                (tokens, Some(body))
+            }
+        };
+        return Ok((
+            tokens,
+            ClassBodyDeclaration::Method(MethodDeclaration {
+                modifiers,
+                annotations,
+                type_parameters,
+                return_type,
+                name,
+                parameters,
+                throws,
+                body,
+                documentation,
+                comments: Default::default(),
+            }),
+        ));
+    }
+
+    let Some(r#type) = return_type else {
+        return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+    };
+    let (tokens, first) = variable_declarator_rest(tokens, name_start, name)?;
+    let (tokens, mut declarators) =
+        many0(preceded(tag(ts![Comma]), variable_declarator)).parse(tokens)?;
+    declarators.insert(0, first);
+    let (tokens, _) = tag(ts![SemiColon]).parse(tokens)?;
+
+    Ok((
+        tokens,
+        ClassBodyDeclaration::Field(FieldDeclaration {
+            modifiers,
+            annotations,
+            r#type,
+            declarators,
+            documentation,
+            comments: Default::default(),
+        }),
+    ))
+}
+
+/// 解析代码块中的局部类、接口、枚举或记录声明。
+pub(crate) fn local_class_declaration<'a>(
+    tokens: TokenStream,
+) -> IResult<TokenStream, ClassBodyDeclaration<'a>> {
+    let (tokens, header) = header(tokens)?;
+    type_declaration_rest(tokens, header)
+}
+
+fn header<'a>(tokens: TokenStream) -> IResult<TokenStream, Header<'a>> {
+    let (tokens, documentation) = opt(documentation_comment).parse(tokens)?;
+    let (tokens, (annotations, modifiers)) = modifiers(tokens)?;
+    Ok((tokens, (documentation, annotations, modifiers)))
+}
+
+/// 解析修饰符之后的类、接口、枚举、记录或注解接口声明。
+fn type_declaration_rest<'a>(
+    tokens: TokenStream,
+    (documentation, annotations, modifiers): Header<'a>,
+) -> IResult<TokenStream, ClassBodyDeclaration<'a>> {
+    match tokens.first() {
+        Some(Token::Class) => {
+            let (tokens, name) = identifier(tokens.take_from(1))?;
+            let (tokens, type_parameters) = opt(type_parameters).parse(tokens)?;
+            let (tokens, extends) =
+                opt(preceded(tag(ts![Extends]), reference_type)).parse(tokens)?;
+            let (tokens, implements) =
+                opt(preceded(tag(ts![Implements]), reference_types)).parse(tokens)?;
+            let (tokens, permits) = permits(tokens)?;
+            let (tokens, body) = class_body(tokens)?;
+            Ok((
+                tokens,
+                ClassBodyDeclaration::Class(ClassDeclaration {
+                    name: Cow::Owned(name.to_string()),
+                    modifiers,
+                    annotations,
+                    type_parameters: type_parameters.unwrap_or_default(),
+                    extends,
+                    implements: implements.unwrap_or_default(),
+                    permits,
+                    body,
+                    documentation,
+                    comments: Default::default(),
+                }),
+            ))
+        }
+        Some(Token::Interface) => {
+            let (tokens, name) = identifier(tokens.take_from(1))?;
+            let (tokens, type_parameters) = opt(type_parameters).parse(tokens)?;
+            let (tokens, extends) =
+                opt(preceded(tag(ts![Extends]), reference_types)).parse(tokens)?;
+            let (tokens, permits) = permits(tokens)?;
+            let (tokens, body) = class_body(tokens)?;
+            Ok((
+                tokens,
+                ClassBodyDeclaration::Interface(InterfaceDeclaration {
+                    name: Cow::Owned(name.to_string()),
+                    modifiers,
+                    annotations,
+                    type_parameters: type_parameters.unwrap_or_default(),
+                    extends: extends.unwrap_or_default(),
+                    permits,
+                    body,
+                    documentation,
+                    comments: Default::default(),
+                }),
+            ))
+        }
+        Some(Token::Enum) => {
+            let (tokens, name) = identifier(tokens.take_from(1))?;
+            let (tokens, implements) =
+                opt(preceded(tag(ts![Implements]), reference_types)).parse(tokens)?;
+            let (tokens, _) = tag(ts![LeftBrace]).parse(tokens)?;
+            let (tokens, (constants, dangling)) = enum_constants(tokens)?;
+            let (tokens, mut body) = match tokens.first() {
+                Some(Token::SemiColon) => class_body_declarations(tokens.take_from(1), false)?,
+                _ => (tokens, ClassBody::default()),
+            };
+            // 最后一个常量之后的注释属于后面的第一个成员
+            match body.declarations.first_mut() {
+                Some(d) => {
+                    d.comments_mut().leading.splice(0..0, dangling);
+                }
+                None => {
+                    body.dangling.splice(0..0, dangling);
+                }
+            }
+            let (tokens, _) = tag(ts![RightBrace]).parse(tokens)?;
+            Ok((
+                tokens,
+                ClassBodyDeclaration::Enum(EnumDeclaration {
+                    name: Cow::Owned(name.to_string()),
+                    modifiers,
+                    annotations,
+                    implements: implements.unwrap_or_default(),
+                    constants,
+                    body,
+                    documentation,
+                    comments: Default::default(),
+                }),
+            ))
+        }
+        Some(Token::At) => {
+            let (tokens, _) = tag(ts![At, Interface]).parse(tokens)?;
+            let (tokens, name) = identifier(tokens)?;
+            let (tokens, body) = annotation_body(tokens)?;
+            Ok((
+                tokens,
+                ClassBodyDeclaration::Annotation(AnnotationDeclaration {
+                    name: Cow::Owned(name.to_string()),
+                    modifiers,
+                    annotations,
+                    body,
+                    documentation,
+                    comments: Default::default(),
+                }),
+            ))
+        }
+        Some(Token::Identifier(i)) if i == RECORD => {
+            let (tokens, name) = identifier(tokens.take_from(1))?;
+            let (tokens, type_parameters) = opt(type_parameters).parse(tokens)?;
+            let (tokens, _) = tag(ts![LeftParen]).parse(tokens)?;
+            let (tokens, components) = separated_list0(
+                tag(ts![Comma]),
+                syntax_node(SyntaxKind::RecordComponent, record_component),
+            )
+            .parse(tokens)?;
+            let (tokens, _) = tag(ts![RightParen]).parse(tokens)?;
+            let (tokens, implements) =
+                opt(preceded(tag(ts![Implements]), reference_types)).parse(tokens)?;
+            let (tokens, body) = class_body(tokens)?;
+            Ok((
+                tokens,
+                ClassBodyDeclaration::Record(RecordDeclaration {
+                    name: Cow::Owned(name.to_string()),
+                    modifiers,
+                    annotations,
+                    type_parameters: type_parameters.unwrap_or_default(),
+                    components,
+                    implements: implements.unwrap_or_default(),
+                    body,
+                    documentation,
+                    comments: Default::default(),
+                }),
+            ))
+        }
+        _ => Err(nom::Err::Error(Error::new(tokens, ErrorKind::Alt))),
+    }
+}
+
+fn reference_types<'a>(tokens: TokenStream) -> IResult<TokenStream, Vec<ReferenceType<'a>>> {
+    separated_list1(tag(ts![Comma]), reference_type).parse(tokens)
+}
+
+fn permits<'a>(tokens: TokenStream) -> IResult<TokenStream, Vec<ReferenceType<'a>>> {
+    match tokens.first() {
+        Some(Token::Identifier(i)) if i == PERMITS => reference_types(tokens.take_from(1)),
+        _ => Ok((tokens, Vec::new())),
+    }
+}
+
+fn throws<'a>(tokens: TokenStream) -> IResult<TokenStream, Vec<ReferenceType<'a>>> {
+    let (tokens, throws) = opt(preceded(tag(ts![Throws]), reference_types)).parse(tokens)?;
+    Ok((tokens, throws.unwrap_or_default()))
+}
+
+/// 解析类型参数声明`<T extends Comparable<T>, U>`。
+pub fn type_parameters<'a>(tokens: TokenStream) -> IResult<TokenStream, Vec<TypeParameter<'a>>> {
+    let start = tokens.source_offset();
+    let (tokens, _) = tag(ts![LessThan]).parse(tokens)?;
+    let (tokens, type_parameters) = separated_list1(
+        tag(ts![Comma]),
+        syntax_node(SyntaxKind::TypeParameter, type_parameter),
+    )
+    .parse(tokens)?;
+    let (tokens, _) = greater_than(tokens)?;
+    tokens.finish_node(start, SyntaxKind::TypeParameters);
+    Ok((tokens, type_parameters))
+}
+
+fn type_parameter<'a>(tokens: TokenStream) -> IResult<TokenStream, TypeParameter<'a>> {
+    let (tokens, _) = many0(annotation).parse(tokens)?;
+    let (tokens, name) = identifier(tokens)?;
+    let (tokens, extends) = opt(preceded(
+        tag(ts![Extends]),
+        separated_list1(tag(ts![And]), reference_type),
+    ))
+    .parse(tokens)?;
+
+    Ok((
+        tokens,
+        TypeParameter {
+            name: Cow::Owned(name.to_string()),
+            extends: extends.unwrap_or_default(),
+        },
+    ))
+}
+
+/// 解析形式参数列表`(final int a, String... b)`。
+pub fn formal_parameters<'a>(
+    tokens: TokenStream,
+) -> IResult<TokenStream, Vec<FormalParameter<'a>>> {
+    let start = tokens.source_offset();
+    let (tokens, _) = tag(ts![LeftParen]).parse(tokens)?;
+    let (tokens, parameters) = separated_list0(
+        tag(ts![Comma]),
+        syntax_node(SyntaxKind::FormalParameter, formal_parameter),
+    )
+    .parse(tokens)?;
+    let (tokens, _) = tag(ts![RightParen]).parse(tokens)?;
+    tokens.finish_node(start, SyntaxKind::ParameterList);
+    Ok((tokens, parameters))
+}
+
+fn formal_parameter<'a>(tokens: TokenStream) -> IResult<TokenStream, FormalParameter<'a>> {
+    let (tokens, (annotations, modifiers)) = modifiers(tokens)?;
+    let (tokens, t) = r#type(tokens)?;
+    let (tokens, varargs) = opt(tag(ts![TripleDot])).parse(tokens)?;
+    let (tokens, name) = identifier(tokens)?;
+    // 旧式的数组参数`int a[]`
+    let (tokens, dimensions) = dimensions(tokens)?;
+
+    Ok((
+        tokens,
+        FormalParameter {
+            modifiers,
+            annotations,
+            r#type: array_of(t, dimensions),
+            varargs: varargs.is_some(),
+            name: Cow::Owned(name.to_string()),
+        },
+    ))
+}
+
+fn record_component<'a>(tokens: TokenStream) -> IResult<TokenStream, RecordComponent<'a>> {
+    let (tokens, documentation) = opt(documentation_comment).parse(tokens)?;
+    let (tokens, annotations) = many0(annotation).parse(tokens)?;
+    let (tokens, r#type) = r#type(tokens)?;
+    let (tokens, varargs) = opt(tag(ts![TripleDot])).parse(tokens)?;
+    let (tokens, name) = identifier(tokens)?;
+
+    Ok((
+        tokens,
+        RecordComponent {
+            annotations,
+            r#type,
+            varargs: varargs.is_some(),
+            name: Cow::Owned(name.to_string()),
+            documentation,
+        },
+    ))
+}
+
+/// 解析以逗号分隔的枚举常量（允许末尾多一个逗号），同时返回最后一个常量之后未被认领的注释。
+fn enum_constants<'a>(
+    mut tokens: TokenStream,
+) -> IResult<TokenStream, (Vec<EnumConstantDeclaration<'a>>, Vec<Comment<'a>>)> {
+    let mut constants: Vec<EnumConstantDeclaration> = Vec::new();
+    let mut comments = CommentCollector::default();
+    loop {
+        comments.collect(&tokens, constants.last_mut().map(|c| &mut c.comments));
+        let constant = syntax_node(SyntaxKind::EnumConstant, enum_constant).parse(tokens.clone());
+        let Ok((remaining, mut constant)) = constant else {
+            break;
+        };
+        constant.comments = comments.leading();
+        constants.push(constant);
+        tokens = remaining;
+        comments.collect(&tokens, constants.last_mut().map(|c| &mut c.comments));
+        match tokens.first() {
+            Some(Token::Comma) => tokens = tokens.take_from(1),
+            _ => break,
+        }
+    }
+
+    Ok((tokens, (constants, comments.dangling())))
+}
+
+fn enum_constant<'a>(tokens: TokenStream) -> IResult<TokenStream, EnumConstantDeclaration<'a>> {
+    let (tokens, documentation) = opt(documentation_comment).parse(tokens)?;
+    let (tokens, annotations) = many0(annotation).parse(tokens)?;
+    let (tokens, name) = identifier(tokens)?;
+    let (tokens, arguments) = opt(arguments).parse(tokens)?;
+    let (tokens, body) = opt(class_body).parse(tokens)?;
+
+    Ok((
+        tokens,
+        EnumConstantDeclaration {
+            name: Cow::Owned(name.to_string()),
+            annotations,
+            arguments,
+            body,
+            documentation,
+            comments: Default::default(),
+        },
+    ))
+}
+
+/// 解析变量声明中的一个变量`name[] = initializer`。
+pub fn variable_declarator<'a>(
+    tokens: TokenStream,
+) -> IResult<TokenStream, VariableDeclarator<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, name) = identifier(tokens)?;
+    variable_declarator_rest(tokens, start, Cow::Owned(name.to_string()))
+}
+
+/// 解析变量名称之后的部分，`start`是变量名称的字节偏移。
+fn variable_declarator_rest<'a>(
+    tokens: TokenStream,
+    start: usize,
+    name: Cow<'a, str>,
+) -> IResult<TokenStream, VariableDeclarator<'a>> {
+    let (tokens, dimensions) = dimensions(tokens)?;
+    let (tokens, initializer) =
+        opt(preceded(tag(ts![Assign]), variable_initializer)).parse(tokens)?;
+    tokens.finish_node(start, SyntaxKind::VariableDeclarator);
+
+    Ok((
+        tokens,
+        VariableDeclarator {
+            name,
+            dimensions,
+            initializer,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DocumentationFlavor, ElementValue, Expression, Literal};
+
+    #[test]
+    fn test_class_declaration() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str(
+            "
+            /** 文档 */
+            @Deprecated
+            public abstract sealed class Shape<T> extends Base implements A, B<T> permits Circle {
+                private static final int[] SIZES = {1, 2}, EMPTY = {};
+                static { init(); }
+                protected Shape() throws Exception { super(); }
+                public abstract <R> R accept(Visitor<R> v);
+                class Inner {}
+                enum Kind { A, B }
+            }
+            ",
+        )?;
+        let (tokens, d) = top_level_class_or_interface_declaration(tokens.without_comments())?;
+        assert!(tokens.is_empty());
+        let TopLevelClassOrInterfaceDeclaration::Class(c) = d else {
+            panic!();
+        };
+        assert_eq!(c.documentation, Some(" 文档 ".into()));
+        assert_eq!(c.annotations.len(), 1);
+        assert_eq!(
+            c.modifiers,
+            vec![Modifier::Public, Modifier::Abstract, Modifier::Sealed]
+        );
+        assert_eq!(c.type_parameters.len(), 1);
+        assert_eq!(c.extends.unwrap().name, "Base");
+        assert_eq!(c.implements.len(), 2);
+        assert_eq!(c.permits.len(), 1);
+
+        let [ClassBodyDeclaration::Field(field), ClassBodyDeclaration::Initializer(init), ClassBodyDeclaration::Constructor(ctor), ClassBodyDeclaration::Method(method), ClassBodyDeclaration::Class(_), ClassBodyDeclaration::Enum(kind)] =
+            &c.body.declarations[..]
+        else {
+            panic!("{:?}", c.body.declarations);
+        };
+        assert_eq!(field.declarators.len(), 2);
+        assert!(init.r#static);
+        assert_eq!(ctor.throws.len(), 1);
+        assert!(method.body.is_none());
+        assert_eq!(method.type_parameters.len(), 1);
+        assert_eq!(kind.constants.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_other_declarations() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str(
+            "
+            interface Visitor<R> extends Base {
+                R visit(Node n);
+                default void close() {}
+            }
+            ",
+        )?;
+        let (tokens, d) = top_level_class_or_interface_declaration(tokens)?;
+        assert!(tokens.is_empty());
+        let TopLevelClassOrInterfaceDeclaration::Interface(i) = d else {
+            panic!();
+        };
+        assert_eq!(i.body.declarations.len(), 2);
+
+        let (_, tokens) = TokenStream::from_str(
+            "
+            enum Planet implements Body {
+                MERCURY(3.303), EARTH(5.976) { double g() { return 9.8; } },;
+                private final double mass;
+                Planet(double mass) { this.mass = mass; }
+            }
+            ",
+        )?;
+        let (tokens, d) = top_level_class_or_interface_declaration(tokens)?;
+        assert!(tokens.is_empty());
+        let TopLevelClassOrInterfaceDeclaration::Enum(e) = d else {
+            panic!();
+        };
+        assert_eq!(e.constants.len(), 2);
+        assert!(e.constants[1].body.is_some());
+        assert_eq!(e.body.declarations.len(), 2);
+
+        let (_, tokens) = TokenStream::from_str(
+            "
+            public record Pair<A, B>(@NonNull A first, B... rest) implements Serializable {
+                public Pair { Objects.requireNonNull(first); }
+                static int count;
+            }
+            ",
+        )?;
+        let (tokens, d) = top_level_class_or_interface_declaration(tokens)?;
+        assert!(tokens.is_empty());
+        let TopLevelClassOrInterfaceDeclaration::Record(r) = d else {
+            panic!();
+        };
+        assert_eq!(r.components.len(), 2);
+        assert!(r.components[1].varargs);
+        assert!(matches!(
+            &r.body.declarations[0],
+            ClassBodyDeclaration::Constructor(ConstructorDeclaration {
+                parameters: None,
+                ..
+            })
+        ));
+
+        let (_, tokens) = TokenStream::from_str(
+            "
+            @Retention(RetentionPolicy.RUNTIME)
+            public @interface Info {
+                String value() default \"\";
+                int[] ids();
+            }
+            ",
+        )?;
+        let (tokens, d) = top_level_class_or_interface_declaration(tokens)?;
+        assert!(tokens.is_empty());
+        let TopLevelClassOrInterfaceDeclaration::Annotation(a) = d else {
+            panic!();
+        };
+        let [ClassBodyDeclaration::AnnotationMethod(value), ClassBodyDeclaration::AnnotationMethod(ids)] =
+            &a.body.declarations[..]
+        else {
+            panic!("{:?}", a.body.declarations);
+        };
+        assert_eq!(
+            value.default,
+            Some(ElementValue::Expression(Expression::Literal(
+                Literal::String("".into())
+            )))
+        );
+        assert_eq!(ids.return_type.dimensions(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_markdown_documentation() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str(
+            "
+            /// 计算器
+            ///
+            /// - 支持**加法**
+            class Calculator {
+                /** 传统注释 */
+                int a;
+                /// 返回和
+                int add(int x) { return a + x; }
+            }
+            ",
+        )?;
+        let (tokens, d) = top_level_class_or_interface_declaration(tokens.without_comments())?;
+        assert!(tokens.is_empty());
+        let TopLevelClassOrInterfaceDeclaration::Class(c) = d else {
+            panic!();
+        };
+        let doc = c.documentation.as_ref().unwrap();
+        assert_eq!(doc.flavor(), DocumentationFlavor::Markdown);
+        assert_eq!(doc.text(), " 计算器\n\n - 支持**加法**");
+        assert_eq!(doc.to_string(), "/// 计算器\n///\n/// - 支持**加法**\n");
+        let [ClassBodyDeclaration::Field(_), ClassBodyDeclaration::Method(m)] =
+            &c.body.declarations[..]
+        else {
+            panic!("{:?}", c.body.declarations);
+        };
+        let doc = m.documentation.as_ref().unwrap();
+        assert_eq!(doc.flavor(), DocumentationFlavor::Markdown);
+        assert_eq!(doc.text(), " 返回和");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use super::super::{Comment, Comments};
+use crate::{Token, TokenStream};
+use nom::Input;
+use std::{borrow::Cow, mem::take};
+
+/// 把标记前面的普通注释分配给一系列相邻的节点（例如类体中的成员、代码块中的语句）。
+///
+/// 和上一个标记在同一行的注释是上一个节点的尾随注释，其余注释是下一个节点的前导注释，
+/// 列表结束时仍没有节点认领的注释是悬空注释。
+#[derive(Clone, Default)]
+pub(crate) struct CommentCollector<'a> {
+    pending: Vec<Comment<'a>>,
+    /// 已经收集过的最靠后的位置（用剩余标记数量表示），避免重复收集同一个标记前面的注释。
+    position: Option<usize>,
+}
+
+impl<'a> CommentCollector<'a> {
+    /// 收集第一个标记前面的注释。
+    ///
+    /// `previous`是上一个节点的注释，没有上一个节点时为`None`。第一个标记可以开始一个声明时，
+    /// 最后一个文档注释属于该声明（见[`super::documentation_comment`]），不会被收集；其余文档注释按普通注释处理。
+    pub(crate) fn collect(
+        &mut self,
+        tokens: &TokenStream,
+        mut previous: Option<&mut Comments<'a>>,
+    ) {
+        let position = tokens.input_len();
+        if self.position.is_some_and(|p| position >= p) {
+            return;
+        }
+        self.position = Some(position);
+        let comments = tokens.comments();
+        let documentation = match tokens.first() {
+            Some(t) if starts_declaration(t) => {
+                comments.iter().rposition(|(t, _)| t.is_documentation())
+            }
+            _ => None,
+        };
+        for (i, (token, info)) in comments.iter().enumerate() {
+            if Some(i) == documentation {
+                continue;
+            }
+            for (j, comment) in plain_comments(token).into_iter().enumerate() {
+                match previous {
+                    Some(ref mut p) if j == 0 && !info.newline_before => p.trailing.push(comment),
+                    _ => {
+                        previous = None;
+                        self.pending.push(comment);
+                    }
+                }
+            }
+        }
+    }
+
+    /// 取出已收集的注释，作为下一个节点的前导注释。
+    pub(crate) fn leading(&mut self) -> Comments<'a> {
+        Comments {
+            leading: take(&mut self.pending),
+            trailing: Vec::new(),
+        }
+    }
+
+    /// 取出剩余的注释，作为悬空注释。
+    pub(crate) fn dangling(self) -> Vec<Comment<'a>> {
+        self.pending
+    }
+}
+
+/// 标记是否可以是声明（包括记录组件和枚举常量）的开头。
+fn starts_declaration(token: &Token) -> bool {
+    token.is_modifier()
+        || token.is_basic_type()
+        || matches!(
+            token,
+            Token::Identifier(_)
+                | Token::At
+                | Token::Class
+                | Token::Interface
+                | Token::Enum
+                | Token::Void
+                | Token::LessThan
+                | Token::Package
+        )
+}
+
+/// 把注释标记转换为普通注释，不属于声明的`///`注释的每一行都是一个单行注释。
+fn plain_comments<'a>(token: &Token) -> Vec<Comment<'a>> {
+    match token {
+        Token::Comment { text, single_line } => vec![Comment {
+            text: Cow::Owned(text.clone()),
+            single_line: *single_line,
+        }],
+        Token::JavaDoc(text) => vec![Comment {
+            text: Cow::Owned(format!("*{}", text)),
+            single_line: false,
+        }],
+        Token::MarkdownDoc(text) => text
+            .split('\n')
+            .map(|line| Comment {
+                text: Cow::Owned(format!("/{}", line)),
+                single_line: true,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
@@ -1,9 +1,18 @@
 use super::{
-    super::{import_declarations, package_declaration},
-    CompilationUnitDeclaration,
+    super::{
+        import_declaration, module_declaration, package_declaration,
+        top_level_class_or_interface_declaration, ClassBodyDeclaration, ClassDeclaration,
+        Commented, Comments, Modifier, TopLevelClassOrInterfaceDeclaration,
+    },
+    class_body_declarations, insert_missing, is_top_level_start, recover, syntax_node, tag,
+    CommentCollector, CompilationUnitDeclaration,
+};
+use crate::{ts, SyntaxKind, TokenStream};
+use nom::{
+    combinator::opt,
+    error::{Error, ErrorKind},
+    IResult, Input, Parser,
 };
-use crate::TokenStream;
-use nom::{combinator::opt, IResult, Parser};
 
 /// 解析编译单元声明从给定的标记流中。
 ///
@@ -47,12 +56,171 @@ use nom::{combinator::opt, IResult, Parser};
 pub fn compilation_unit_declaration<'a>(
     tokens: TokenStream,
 ) -> IResult<TokenStream, CompilationUnitDeclaration<'a>> {
-    let (tokens, package) = opt(package_declaration).parse(tokens)?;
-    let (tokens, imports) = import_declarations(tokens)?;
+    let mut comments = CommentCollector::default();
+    comments.collect(&tokens, None);
+    let (tokens, mut package) = opt(package_declaration).parse(tokens)?;
+    if let Some(ref mut package) = package {
+        package.comments = comments.leading();
+    }
+    // 导入声明和代码块中的语句一样关联注释，文件开头的注释在没有包声明时属于第一个导入声明
+    let mut imports = Vec::new();
+    let mut import_comments: Vec<Comments> = Vec::new();
+    let mut tokens = tokens;
+    loop {
+        let previous = match import_comments.last_mut() {
+            Some(c) => Some(c),
+            None => package.as_mut().map(|p| &mut p.comments),
+        };
+        comments.collect(&tokens, previous);
+        // 恢复模式下补上导入声明缺少的`;`，而不是把它和后面的声明一起跳过
+        let import = insert_missing(tokens.clone(), |t| {
+            syntax_node(SyntaxKind::ImportDeclaration, import_declaration).parse(t)
+        });
+        match import {
+            Ok((rest, import)) => {
+                imports.push(import);
+                import_comments.push(comments.leading());
+                tokens = rest;
+            }
+            Err(nom::Err::Error(_)) => break,
+            Err(e) => return Err(e),
+        }
+    }
+    // 没有包声明时，如果能解析出模块声明，则视为模块编译单元（module-info.java）
+    if package.is_none() {
+        if let Ok((rest, mut module)) = module_declaration(tokens.clone()) {
+            comments.collect(&tokens, None);
+            module.comments = comments.leading();
+            comments.collect(&rest, Some(&mut module.comments));
+            module.comments.trailing.extend(comments.dangling());
+            return Ok((
+                rest,
+                CompilationUnitDeclaration::Modular {
+                    imports,
+                    import_comments,
+                    module,
+                },
+            ));
+        }
+    }
+    // 顶层声明之间多余的分号会被忽略
+    let mut declarations: Vec<TopLevelClassOrInterfaceDeclaration> = Vec::new();
+    let mut implicit_comments = comments.clone();
+    let mut remaining = tokens.clone();
+    loop {
+        let previous = match declarations.last_mut() {
+            Some(d) => Some(d.comments_mut()),
+            None => package.as_mut().map(|p| &mut p.comments),
+        };
+        comments.collect(&remaining, previous);
+        match tag(ts![SemiColon]).parse(remaining.clone()) {
+            Ok((rest, _)) => {
+                remaining = rest;
+                continue;
+            }
+            Err(nom::Err::Error(_)) => {}
+            Err(e) => return Err(e),
+        }
+        // 恢复模式下，放错位置的导入声明照常解析，其余无法解析的标记作为错误节点；
+        // 可能是隐式声明的类时交给下面的隐式类处理
+        if remaining.is_recovering()
+            && !remaining.is_empty()
+            && (package.is_some() || !tokens.options().preview)
+        {
+            if let Ok((rest, import)) = import_declaration(remaining.clone()) {
+                imports.push(import);
+                import_comments.push(comments.leading());
+                remaining = rest;
+                continue;
+            }
+            let (rest, mut declaration) = recover(
+                remaining,
+                top_level_class_or_interface_declaration,
+                is_top_level_start,
+                TopLevelClassOrInterfaceDeclaration::Error,
+            )?;
+            *declaration.comments_mut() = comments.leading();
+            declarations.push(declaration);
+            remaining = rest;
+            continue;
+        }
+        match top_level_class_or_interface_declaration(remaining.clone()) {
+            Ok((rest, mut declaration)) => {
+                *declaration.comments_mut() = comments.leading();
+                declarations.push(declaration);
+                remaining = rest;
+            }
+            Err(nom::Err::Error(_)) => break,
+            Err(e) => return Err(e),
+        }
+    }
+    // 启用预览特性时，不属于任何类声明的顶层字段和方法组成一个隐式声明的类
+    if package.is_none() && !remaining.is_empty() && tokens.options().preview {
+        if let Ok((tokens, mut class)) = implicit_class(tokens) {
+            if tokens.input_len() < remaining.input_len() {
+                // 成员的注释由类体关联，合成的类只关联成员之前（文件开头和导入声明之间）的注释
+                class.comments = implicit_comments.leading();
+                return Ok((
+                    tokens,
+                    CompilationUnitDeclaration::Implicit {
+                        imports,
+                        import_comments,
+                        class,
+                    },
+                ));
+            }
+        }
+    }
+    let tokens = remaining;
+    // 文件末尾剩余的注释作为最后一个声明的尾随注释
+    let dangling = comments.dangling();
+    match declarations.last_mut() {
+        Some(d) => d.comments_mut().trailing.extend(dangling),
+        None => {
+            if let Some(ref mut package) = package {
+                package.comments.trailing.extend(dangling);
+            }
+        }
+    }
+    let top_level_class_or_interfaces = declarations;
+
+    Ok((
+        tokens,
+        CompilationUnitDeclaration::Ordinary {
+            package,
+            imports,
+            import_comments,
+            top_level_class_or_interfaces,
+        },
+    ))
+}
+
+/// 把顶层成员当作类体中的成员解析，放入一个合成的`final`类中。
+fn implicit_class<'a>(tokens: TokenStream) -> IResult<TokenStream, ClassDeclaration<'a>> {
+    let (tokens, body) = class_body_declarations(tokens, false)?;
+    if !body.declarations.iter().any(|d| {
+        matches!(
+            d,
+            ClassBodyDeclaration::Field(_) | ClassBodyDeclaration::Method(_)
+        )
+    }) {
+        return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+    }
 
     Ok((
         tokens,
-        CompilationUnitDeclaration::Ordinary {package, imports, top_level_class_or_interfaces: Default::default()},
+        ClassDeclaration {
+            name: Default::default(),
+            modifiers: vec![Modifier::Final],
+            annotations: Vec::new(),
+            type_parameters: Vec::new(),
+            extends: None,
+            implements: Vec::new(),
+            permits: Vec::new(),
+            body,
+            documentation: None,
+            comments: Default::default(),
+        },
     ))
 }
 
@@ -60,12 +228,323 @@ pub fn compilation_unit_declaration<'a>(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_documentation() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str(
+            "
+            /** 包 */
+            package com.test;
+            /** 悬空的注释 */
+            import java.util.List;
+            /** 被覆盖的注释 */
+            /** 类 */
+            @Deprecated /** 修饰符之间的注释 */ public
+            record Point(/** 横坐标 */ int x, int y) {
+                /** 常量 */ static final int ZERO = 0;
+                /** 悬空的注释 */
+            }
+            /** 枚举 */
+            enum Color { /** 红色 */ RED, GREEN }
+            /** 悬空的注释 */
+            ",
+        )?;
+        let (tokens, cu) = crate::parse(tokens)?;
+        assert!(tokens.is_empty(), "剩余标记：{:?}", tokens);
+        let doc = |d: Option<&crate::DocumentationComment>| d.map(|d| d.text().trim().to_string());
+        assert_eq!(
+            doc(cu.package().unwrap().documentation.as_ref()).unwrap(),
+            "包"
+        );
+        let CompilationUnitDeclaration::Ordinary {
+            top_level_class_or_interfaces,
+            ..
+        } = cu
+        else {
+            panic!();
+        };
+        let [crate::TopLevelClassOrInterfaceDeclaration::Record(r), crate::TopLevelClassOrInterfaceDeclaration::Enum(e)] =
+            &top_level_class_or_interfaces[..]
+        else {
+            panic!("{:?}", top_level_class_or_interfaces);
+        };
+        assert_eq!(doc(r.documentation.as_ref()).unwrap(), "类");
+        assert_eq!(
+            doc(r.components[0].documentation.as_ref()).unwrap(),
+            "横坐标"
+        );
+        assert!(r.components[1].documentation.is_none());
+        let ClassBodyDeclaration::Field(field) = &r.body.declarations[0] else {
+            panic!();
+        };
+        assert_eq!(doc(field.documentation.as_ref()).unwrap(), "常量");
+        assert_eq!(doc(e.documentation.as_ref()).unwrap(), "枚举");
+        assert_eq!(doc(e.constants[0].documentation.as_ref()).unwrap(), "红色");
+        assert!(e.constants[1].documentation.is_none());
+
+        let (_, tokens) = TokenStream::from_str(
+            "/** 模块 */ module m { /** 悬空的注释 */ requires a; /** 悬空的注释 */ }",
+        )?;
+        let (tokens, cu) = crate::parse(tokens)?;
+        assert!(tokens.is_empty());
+        let CompilationUnitDeclaration::Modular { module, .. } = cu else {
+            panic!();
+        };
+        assert_eq!(doc(module.documentation.as_ref()).unwrap(), "模块");
+        assert_eq!(module.directives.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_comments() -> anyhow::Result<()> {
+        use crate::{parse, Comment, Statement};
+
+        let (_, tokens) = TokenStream::from_str(
+            "
+            // 许可证
+            package com.example; // 包
+
+            import java.util.List;
+            // 导入之后
+
+            /** 文档 */
+            // 类
+            public class A { // 类体开始
+                // 字段
+                int a = 1; // 尾随
+                /* 方法 */ void f() {
+                    Object obj = null; // 空值
+                    // 悬空
+                }
+                enum E { RED, // 红色
+                    GREEN }
+                // 类体末尾
+            }
+            // 文件末尾
+            ",
+        )?;
+        let (tokens, cu) = parse(tokens)?;
+        assert!(tokens.is_empty());
+        let texts = |comments: &[Comment]| -> Vec<String> {
+            comments.iter().map(|c| c.text.trim().to_string()).collect()
+        };
+        let package = cu.package().unwrap();
+        assert_eq!(texts(&package.comments.leading), ["许可证"]);
+        assert_eq!(texts(&package.comments.trailing), ["包"]);
+        let CompilationUnitDeclaration::Ordinary {
+            top_level_class_or_interfaces,
+            ..
+        } = &cu
+        else {
+            panic!();
+        };
+        let TopLevelClassOrInterfaceDeclaration::Class(class) = &top_level_class_or_interfaces[0]
+        else {
+            panic!();
+        };
+        assert_eq!(texts(&class.comments.leading), ["导入之后", "类"]);
+        assert_eq!(texts(&class.comments.trailing), ["文件末尾"]);
+        assert_eq!(texts(&class.body.dangling), ["类体末尾"]);
+        let [ClassBodyDeclaration::Field(field), ClassBodyDeclaration::Method(method), ClassBodyDeclaration::Enum(e)] =
+            class.body.declarations.as_slice()
+        else {
+            panic!();
+        };
+        assert_eq!(texts(&field.comments.leading), ["类体开始", "字段"]);
+        assert_eq!(texts(&field.comments.trailing), ["尾随"]);
+        assert_eq!(texts(&method.comments.leading), ["方法"]);
+        let body = method.body.as_ref().unwrap();
+        assert!(matches!(body.statements[0], Statement::LocalVariable(_)));
+        assert_eq!(texts(&body.comments[0].trailing), ["空值"]);
+        assert_eq!(texts(&body.dangling), ["悬空"]);
+        assert_eq!(texts(&e.constants[0].comments.trailing), ["红色"]);
+        assert!(e.constants[1].comments.is_empty());
+        assert!(cu.to_string().contains("Object obj = null; // 空值\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_import_comments() -> anyhow::Result<()> {
+        use crate::{parse, Comment};
+
+        let (_, tokens) = TokenStream::from_str(
+            "// license header\nimport a.B; // needed for B\n// before C\nimport c.D;\nclass X {}",
+        )?;
+        let (tokens, cu) = parse(tokens)?;
+        assert!(tokens.is_empty());
+        let texts = |comments: &[Comment]| -> Vec<String> {
+            comments.iter().map(|c| c.text.trim().to_string()).collect()
+        };
+        let comments = cu.import_comments();
+        assert_eq!(comments.len(), 2);
+        assert_eq!(texts(&comments[0].leading), ["license header"]);
+        assert_eq!(texts(&comments[0].trailing), ["needed for B"]);
+        assert_eq!(texts(&comments[1].leading), ["before C"]);
+        assert!(comments[1].trailing.is_empty());
+        let CompilationUnitDeclaration::Ordinary {
+            top_level_class_or_interfaces,
+            ..
+        } = &cu
+        else {
+            panic!();
+        };
+        assert!(top_level_class_or_interfaces[0].comments().is_empty());
+        // 输出时注释仍然在原来的位置
+        assert!(cu.to_string().starts_with(
+            "// license header\nimport a.B; // needed for B\n// before C\nimport c.D;\n"
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stray_documentation() -> anyhow::Result<()> {
+        use crate::{parse, Comment};
+
+        // 不在声明前面的文档注释按普通注释处理
+        let (_, tokens) = TokenStream::from_str(
+            "
+            class A {
+                void f() {
+                    foo(1, /// x
+                        2);
+                    foo(1, /** y */ 2);
+                    int a /** z */ = 1; /// 说明
+                }
+            }
+            ",
+        )?;
+        let (tokens, cu) = parse(tokens)?;
+        assert!(tokens.is_empty());
+        let CompilationUnitDeclaration::Ordinary {
+            top_level_class_or_interfaces,
+            ..
+        } = &cu
+        else {
+            panic!();
+        };
+        let TopLevelClassOrInterfaceDeclaration::Class(class) = &top_level_class_or_interfaces[0]
+        else {
+            panic!();
+        };
+        let ClassBodyDeclaration::Method(method) = &class.body.declarations[0] else {
+            panic!();
+        };
+        let body = method.body.as_ref().unwrap();
+        assert_eq!(body.statements.len(), 3);
+        assert_eq!(
+            body.comments[2].trailing,
+            [Comment {
+                text: "/ 说明".into(),
+                single_line: true,
+            }]
+        );
+        assert!(cu.to_string().contains("int a = 1; /// 说明\n"));
+        let comments =
+            comments_of("foo(1, /// x\n2);foo(a, /** y */ b);int a /** z */ = {/** w */};");
+        assert_eq!(comments, ["/// x", "/** y */", "/** z */", "/** w */"]);
+
+        // 只有紧挨着声明的文档注释属于声明，导入声明前面和更靠前的文档注释按普通注释处理
+        let cu = crate::parse_str("/** 导入 */\nimport a.B;\n/** 旧 */\n/** 新 */\nclass C {}")?;
+        let block = |text: &str| Comment {
+            text: text.to_string().into(),
+            single_line: false,
+        };
+        assert_eq!(cu.import_comments()[0].leading, [block("* 导入 ")]);
+        let CompilationUnitDeclaration::Ordinary {
+            top_level_class_or_interfaces,
+            ..
+        } = &cu
+        else {
+            panic!();
+        };
+        let TopLevelClassOrInterfaceDeclaration::Class(class) = &top_level_class_or_interfaces[0]
+        else {
+            panic!();
+        };
+        assert_eq!(class.comments.leading, [block("* 旧 ")]);
+        assert_eq!(class.documentation, Some(" 新 ".into()));
+        assert!(cu.to_string().contains("/** 旧 */\n/** 新 */\nclass C"));
+
+        Ok(())
+    }
+
+    /// 去掉注释后记录在各个标记上的注释。
+    fn comments_of(source: &str) -> Vec<String> {
+        let (_, tokens) = TokenStream::from_str(source).unwrap();
+        let tokens = tokens.without_comments();
+        (0..=tokens.input_len())
+            .flat_map(|i| tokens.comments_at(i).iter().map(|(t, _)| t.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_implicit_class() -> anyhow::Result<()> {
+        const SOURCE: &str = "
+            import java.util.List;
+            String greeting = \"hi\";
+            void main() { println(greeting); }
+            record Point(int x, int y) {}
+            ";
+        let (_, tokens) = TokenStream::from_str(SOURCE)?;
+        // 未启用预览特性时无法解析顶层方法
+        let (remaining, _) = compilation_unit_declaration(tokens.clone())?;
+        assert!(!remaining.is_empty());
+
+        let (tokens, cu) =
+            compilation_unit_declaration(tokens.with_options(crate::ParseOptions::preview()))?;
+        assert!(tokens.is_empty());
+        let CompilationUnitDeclaration::Implicit { imports, class, .. } = cu else {
+            panic!();
+        };
+        assert_eq!(imports.len(), 1);
+        assert_eq!(class.modifiers, vec![Modifier::Final]);
+        assert_eq!(class.body.declarations.len(), 3);
+        assert!(matches!(
+            class.body.declarations[1],
+            ClassBodyDeclaration::Method(_)
+        ));
+
+        // 只有类型声明时仍然是普通编译单元
+        let (_, tokens) = TokenStream::from_str("class A {}")?;
+        let (_, cu) =
+            compilation_unit_declaration(tokens.with_options(crate::ParseOptions::preview()))?;
+        assert!(matches!(cu, CompilationUnitDeclaration::Ordinary { .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_modular_compile_unit() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str(
+            "import com.example.spi.Plugin;
+            module com.example { uses Plugin; }",
+        )?;
+        let (tokens, cu) = compilation_unit_declaration(tokens)?;
+        assert!(tokens.is_empty());
+        let CompilationUnitDeclaration::Modular {
+            imports, module, ..
+        } = cu
+        else {
+            panic!();
+        };
+        assert_eq!(imports.len(), 1);
+        assert_eq!(module.name, "com.example");
+
+        Ok(())
+    }
+
     #[test]
     fn test_compile_unit() -> anyhow::Result<()> {
         let (_, tokens) = TokenStream::from_str(
             "\
             package com.test;\
             import java.io.File;
+            public class Main {
+                public static void main(String[] args) {}
+            };
+            interface Marker {}
             ",
         )?;
         assert!(!tokens.is_empty());
@@ -73,6 +552,14 @@ mod tests {
         let (tokens, cu) = compilation_unit_declaration(tokens)?;
         assert_eq!(cu.package().unwrap().name, "com.test");
         assert_eq!(cu.imports().len(), 1);
+        let CompilationUnitDeclaration::Ordinary {
+            top_level_class_or_interfaces,
+            ..
+        } = cu
+        else {
+            panic!();
+        };
+        assert_eq!(top_level_class_or_interfaces.len(), 2);
 
         assert!(tokens.is_empty());
 
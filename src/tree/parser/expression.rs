@@ -0,0 +1,1216 @@
+use super::{
+    super::{
+        ArrayInitializer, AssignmentOperator, BinaryOperator, Expression, LambdaBody,
+        LambdaParameters, Literal, MethodReferenceTarget, PostfixOperator, ReferenceType, Type,
+        TypeArgument, UnaryOperator, VariableInitializer,
+    },
+    array_of, basic_type, block, class_body, dimensions, formal_parameters, identifier,
+    instanceof_target, nested, next_token, r#type, reference_type, switch, syntax_node,
+    syntax_node_for, tag, type_arguments,
+};
+use crate::{
+    ts, LiteralError, ParseErrorKind, SyntaxKind, Token, TokenStream, INT_LITERAL_MAX,
+    LONG_LITERAL_MAX,
+};
+use nom::{
+    combinator::{map, opt},
+    error::{Error, ErrorKind},
+    multi::{many0, separated_list0},
+    sequence::{delimited, preceded},
+    IResult, Input, Parser,
+};
+use std::borrow::Cow;
+
+/// `instanceof`的优先级，与关系运算符相同。
+const INSTANCEOF_PRECEDENCE: u8 = 7;
+
+/// 解析表达式
+///
+/// 该函数按照Java的运算符优先级解析一个完整的表达式（包括赋值表达式），并返回解析后的表达式和剩余的标记流。
+///
+/// # 参数
+///
+/// * `tokens` - 标记流，包含待解析的标记。
+///
+/// # 返回值
+///
+/// 返回一个 `IResult`，其中包含解析后剩余的标记流和表达式。
+///
+/// # 示例
+///
+/// ```rust
+/// fn main() -> anyhow::Result<()> {
+/// use java_lang::{TokenStream, expression, Expression, BinaryOperator};
+/// let (_, tokens) = TokenStream::from_str("1 + 2 * 3")?;
+/// let (tokens, e) = expression(tokens)?;
+/// assert!(matches!(e, Expression::Binary { operator: BinaryOperator::Plus, .. }));
+/// assert!(tokens.is_empty());
+/// Ok(())
+/// }
+/// ```
+///
+/// # 错误处理
+///
+/// 如果解析过程中出现错误，将返回一个解析错误。
+pub fn expression<'a>(tokens: TokenStream) -> IResult<TokenStream, Expression<'a>> {
+    nested(tokens, |tokens| {
+        if let Ok(out) = lambda_expression(tokens.clone()) {
+            return Ok(out);
+        }
+        let start = tokens.source_offset();
+        let (tokens, target) = ternary_expression(tokens)?;
+        let Some(operator) = tokens
+            .first()
+            .and_then(|t| AssignmentOperator::try_from(t).ok())
+        else {
+            return Ok((tokens, target));
+        };
+        if !is_assignable(&target) {
+            return Ok((tokens, target));
+        }
+        // 赋值运算是右结合的
+        let (tokens, value) = expression(tokens.take_from(1))?;
+        tokens.finish_node(start, SyntaxKind::AssignmentExpression);
+
+        Ok((
+            tokens,
+            Expression::Assignment {
+                target: Box::new(target),
+                operator,
+                value: Box::new(value),
+            },
+        ))
+    })
+}
+
+fn is_assignable(expression: &Expression) -> bool {
+    match expression {
+        Expression::Name(_)
+        | Expression::FieldAccess { .. }
+        | Expression::SuperFieldAccess { .. }
+        | Expression::ArrayAccess { .. } => true,
+        Expression::Parenthesized(e) => is_assignable(e),
+        _ => false,
+    }
+}
+
+/// 解析Lambda表达式，例如`x -> x + 1`、`(int a, int b) -> { return a + b; }`或`(var s) -> s`。
+pub fn lambda_expression<'a>(tokens: TokenStream) -> IResult<TokenStream, Expression<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, parameters) = lambda_parameters(tokens)?;
+    let (tokens, _) = tag(ts![Arrow]).parse(tokens)?;
+    let (tokens, body) = match tokens.first() {
+        Some(Token::LeftBrace) => {
+            let (tokens, b) = block(tokens)?;
+            (tokens, LambdaBody::Block(b))
+        }
+        _ => {
+            let (tokens, e) = expression(tokens)?;
+            (tokens, LambdaBody::Expression(Box::new(e)))
+        }
+    };
+    tokens.finish_node(start, SyntaxKind::LambdaExpression);
+
+    Ok((tokens, Expression::Lambda { parameters, body }))
+}
+
+fn lambda_parameters<'a>(tokens: TokenStream) -> IResult<TokenStream, LambdaParameters<'a>> {
+    if let Some(Token::LeftParen) = tokens.first() {
+        let inferred = delimited(
+            tag(ts![LeftParen]),
+            separated_list0(tag(ts![Comma]), identifier),
+            tag(ts![RightParen]),
+        )
+        .parse(tokens.clone());
+        if let Ok((tokens, names)) = inferred {
+            let names = names
+                .into_iter()
+                .map(|i| Cow::Owned(i.to_string()))
+                .collect();
+            return Ok((tokens, LambdaParameters::Inferred(names)));
+        }
+        let (tokens, parameters) = formal_parameters(tokens)?;
+        return Ok((tokens, LambdaParameters::Formal(parameters)));
+    }
+    let (tokens, name) = identifier(tokens)?;
+    Ok((
+        tokens,
+        LambdaParameters::Inferred(vec![Cow::Owned(name.to_string())]),
+    ))
+}
+
+/// 解析条件表达式，不包括赋值表达式和Lambda表达式。
+pub(crate) fn ternary_expression<'a>(tokens: TokenStream) -> IResult<TokenStream, Expression<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, condition) = binary_expression(tokens, 0)?;
+    let Some(Token::Question) = tokens.first() else {
+        return Ok((tokens, condition));
+    };
+    let (tokens, (if_true, if_false)) = nested(tokens.take_from(1), |tokens| {
+        let (tokens, if_true) = expression(tokens)?;
+        let (tokens, _) = tag(ts![Colon]).parse(tokens)?;
+        // 条件运算是右结合的，最后一个操作数也可以是Lambda表达式
+        let (tokens, if_false) = match lambda_expression(tokens.clone()) {
+            Ok(out) => out,
+            Err(_) => ternary_expression(tokens)?,
+        };
+        Ok((tokens, (if_true, if_false)))
+    })?;
+    tokens.finish_node(start, SyntaxKind::TernaryExpression);
+
+    Ok((
+        tokens,
+        Expression::Ternary {
+            condition: Box::new(condition),
+            if_true: Box::new(if_true),
+            if_false: Box::new(if_false),
+        },
+    ))
+}
+
+/// 使用优先级爬升法解析二元运算，只接受优先级不低于`min_precedence`的运算符。
+fn binary_expression<'a>(
+    tokens: TokenStream,
+    min_precedence: u8,
+) -> IResult<TokenStream, Expression<'a>> {
+    let start = tokens.source_offset();
+    let (mut tokens, mut left) = unary_expression(tokens)?;
+    loop {
+        match tokens.first() {
+            Some(Token::Instanceof) if INSTANCEOF_PRECEDENCE >= min_precedence => {
+                let (remaining, target) = instanceof_target(tokens.take_from(1))?;
+                left = Expression::InstanceOf {
+                    expression: Box::new(left),
+                    target,
+                };
+                tokens = remaining;
+                tokens.finish_node(start, SyntaxKind::InstanceOfExpression);
+            }
+            Some(token) => {
+                let Ok(operator) = BinaryOperator::try_from(token) else {
+                    break;
+                };
+                if operator.precedence() < min_precedence {
+                    break;
+                }
+                // 左结合：右操作数只接受优先级更高的运算符
+                let (remaining, right) =
+                    binary_expression(tokens.take_from(1), operator.precedence() + 1)?;
+                left = Expression::Binary {
+                    operator,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                };
+                tokens = remaining;
+                tokens.finish_node(start, SyntaxKind::BinaryExpression);
+            }
+            None => break,
+        }
+    }
+
+    Ok((tokens, left))
+}
+
+fn unary_expression<'a>(tokens: TokenStream) -> IResult<TokenStream, Expression<'a>> {
+    let start = tokens.source_offset();
+    if let Some(Token::Switch) = tokens.first() {
+        let (tokens, s) = switch(tokens)?;
+        tokens.finish_node(start, SyntaxKind::SwitchExpression);
+        return Ok((tokens, Expression::Switch(s)));
+    }
+    if let Some(operator) = tokens.first().and_then(|t| UnaryOperator::try_from(t).ok()) {
+        let operand = tokens.take_from(1);
+        let (tokens, operand) = match operand.first() {
+            // `2147483648`和`9223372036854775808L`只能作为一元`-`的操作数
+            Some(Token::IntegerLiteral(INT_LITERAL_MAX) | Token::LongLiteral(LONG_LITERAL_MAX))
+                if operator == UnaryOperator::Minus =>
+            {
+                let literal = syntax_node(
+                    SyntaxKind::Literal,
+                    next_token(|t| Literal::try_from(t.clone()).ok()),
+                );
+                map(literal, Expression::Literal).parse(operand)?
+            }
+            _ => nested(operand, unary_expression)?,
+        };
+        tokens.finish_node(start, SyntaxKind::UnaryExpression);
+        return Ok((
+            tokens,
+            Expression::Unary {
+                operator,
+                operand: Box::new(operand),
+            },
+        ));
+    }
+    if let Ok(out) = cast_expression(tokens.clone()) {
+        return Ok(out);
+    }
+    postfix_expression(tokens)
+}
+
+/// 解析类型转换。基本类型的转换后面可以是任意一元表达式；
+/// 引用类型的转换后面不能是`+`、`-`、`++`、`--`开头的表达式，否则按括号表达式处理。
+fn cast_expression<'a>(tokens: TokenStream) -> IResult<TokenStream, Expression<'a>> {
+    nested(tokens, |tokens| {
+        let start = tokens.source_offset();
+        let (tokens, _) = tag(ts![LeftParen]).parse(tokens)?;
+        let (tokens, t) = r#type(tokens)?;
+        let (tokens, bounds) = if let Type::Basic(_) = t {
+            (tokens, Vec::new())
+        } else {
+            many0(preceded(tag(ts![And]), reference_type)).parse(tokens)?
+        };
+        let (tokens, _) = tag(ts![RightParen]).parse(tokens)?;
+        if !matches!(t, Type::Basic(_)) && !tokens.first().is_some_and(starts_unary_not_plus_minus)
+        {
+            return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+        }
+        // 引用类型的转换后面可以是Lambda表达式，例如`(Runnable) () -> {}`
+        let (tokens, expression) = match lambda_expression(tokens.clone()) {
+            Ok(out) => out,
+            Err(_) => unary_expression(tokens)?,
+        };
+        tokens.finish_node(start, SyntaxKind::CastExpression);
+
+        Ok((
+            tokens,
+            Expression::Cast {
+                r#type: t,
+                bounds,
+                expression: Box::new(expression),
+            },
+        ))
+    })
+}
+
+/// 判断标记是否可以作为不以`+`、`-`开头的一元表达式的开始。
+fn starts_unary_not_plus_minus(token: &Token) -> bool {
+    token.is_identifier()
+        || token.is_literal()
+        || token.is_basic_type()
+        || matches!(
+            token,
+            Token::LeftParen
+                | Token::LogicalNot
+                | Token::Not
+                | Token::This
+                | Token::Super
+                | Token::New
+                | Token::Void
+        )
+}
+
+fn postfix_expression<'a>(tokens: TokenStream) -> IResult<TokenStream, Expression<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, operand) = syntax_node_for(primary).parse(tokens)?;
+    let (mut tokens, mut operand) = selectors(tokens, start, operand)?;
+    loop {
+        let operator = match tokens.first() {
+            Some(Token::DoublePlus) => PostfixOperator::Increment,
+            Some(Token::DoubleMinus) => PostfixOperator::Decrement,
+            _ => break,
+        };
+        operand = Expression::Postfix {
+            operator,
+            operand: Box::new(operand),
+        };
+        tokens = tokens.take_from(1);
+        tokens.finish_node(start, SyntaxKind::PostfixExpression);
+    }
+
+    Ok((tokens, operand))
+}
+
+fn primary<'a>(tokens: TokenStream) -> IResult<TokenStream, Expression<'a>> {
+    match tokens.first() {
+        Some(Token::This) => Ok((tokens.take_from(1), Expression::This)),
+        Some(Token::Super) => super_suffix(tokens.take_from(1), None),
+        Some(Token::New) => creator(tokens.take_from(1), None),
+        Some(Token::LeftParen) => {
+            let (tokens, e) = expression(tokens.take_from(1))?;
+            let (tokens, _) = tag(ts![RightParen]).parse(tokens)?;
+            Ok((tokens, Expression::Parenthesized(Box::new(e))))
+        }
+        Some(Token::Void) => {
+            let (tokens, _) = tag(ts![Void, Dot, Class]).parse(tokens)?;
+            Ok((tokens, Expression::VoidClassLiteral))
+        }
+        Some(t) if t.is_basic_type() => {
+            let (tokens, t) = basic_type(tokens)?;
+            let (tokens, dimensions) = dimensions(tokens)?;
+            type_suffix(tokens, array_of(Type::Basic(t), dimensions))
+        }
+        Some(t) if t.is_identifier() => {
+            if let Ok(out) = generic_method_reference(tokens.clone()) {
+                return Ok(out);
+            }
+            let (tokens, name) = identifier(tokens)?;
+            let name = Cow::Owned(name.to_string());
+            if let Some(Token::LeftParen) = tokens.first() {
+                let (tokens, arguments) = arguments(tokens)?;
+                return Ok((
+                    tokens,
+                    Expression::MethodInvocation {
+                        target: None,
+                        type_arguments: Vec::new(),
+                        name,
+                        arguments,
+                    },
+                ));
+            }
+            Ok((tokens, Expression::Name(name)))
+        }
+        t => {
+            if !t.is_some_and(Token::is_literal) {
+                tokens.expect(0, "表达式");
+            }
+            let (tokens, l) = literal(tokens)?;
+            Ok((tokens, Expression::Literal(l)))
+        }
+    }
+}
+
+/// 解析主表达式`target`后面的成员访问、方法调用、数组访问和方法引用等后缀。
+///
+/// 名称后面连续的`.identifier`会合并为限定名称`Name`，直到遇到其他形式的后缀。
+/// `start`是主表达式的字节偏移，每解析一个后缀就记录一个从这里开始的语法节点。
+fn selectors<'a>(
+    mut tokens: TokenStream,
+    start: usize,
+    mut target: Expression<'a>,
+) -> IResult<TokenStream, Expression<'a>> {
+    loop {
+        match tokens.first() {
+            Some(Token::Dot) => {
+                let next = tokens.take_from(1);
+                (tokens, target) = match next.first() {
+                    Some(Token::LessThan) => {
+                        let (next, type_arguments) = type_arguments(next)?;
+                        invocation(next, Some(target), type_arguments)?
+                    }
+                    Some(Token::This) => {
+                        let qualifier = qualifier(&next, &target)?;
+                        (next.take_from(1), Expression::QualifiedThis(qualifier))
+                    }
+                    Some(Token::Class) => {
+                        let qualifier = qualifier(&next, &target)?;
+                        (
+                            next.take_from(1),
+                            Expression::ClassLiteral(reference(qualifier)),
+                        )
+                    }
+                    Some(Token::Super) => {
+                        let qualifier = qualifier(&next, &target)?;
+                        super_suffix(next.take_from(1), Some(qualifier))?
+                    }
+                    Some(Token::New) => creator(next.take_from(1), Some(target))?,
+                    _ => {
+                        let (next, name) = identifier(next)?;
+                        let name = Cow::Owned(name.to_string());
+                        match (next.first(), target) {
+                            (Some(Token::LeftParen), target) => {
+                                let (next, arguments) = arguments(next)?;
+                                (
+                                    next,
+                                    Expression::MethodInvocation {
+                                        target: Some(Box::new(target)),
+                                        type_arguments: Vec::new(),
+                                        name,
+                                        arguments,
+                                    },
+                                )
+                            }
+                            (_, Expression::Name(n)) => (
+                                next,
+                                Expression::Name(Cow::Owned(format!("{}.{}", n, name))),
+                            ),
+                            (_, target) => (
+                                next,
+                                Expression::FieldAccess {
+                                    target: Box::new(target),
+                                    name,
+                                },
+                            ),
+                        }
+                    }
+                };
+            }
+            Some(Token::DoubleColon) => {
+                let t = MethodReferenceTarget::Expression(Box::new(target));
+                (tokens, target) = method_reference(tokens.take_from(1), t)?;
+            }
+            Some(Token::LeftBracket) => {
+                if let (Expression::Name(n), Some(Token::RightBracket)) =
+                    (&target, tokens.take_from(1).first())
+                {
+                    // `Name[]`只能出现在`Name[].class`和`Name[]::new`中
+                    let (next, dimensions) = dimensions(tokens)?;
+                    let t = array_of(reference(n.clone()), dimensions);
+                    (tokens, target) = type_suffix(next, t)?;
+                    tokens.finish_node_for(start, &target);
+                    continue;
+                }
+                let (next, index) =
+                    delimited(tag(ts![LeftBracket]), expression, tag(ts![RightBracket]))
+                        .parse(tokens)?;
+                target = Expression::ArrayAccess {
+                    array: Box::new(target),
+                    index: Box::new(index),
+                };
+                tokens = next;
+            }
+            _ => break,
+        }
+        tokens.finish_node_for(start, &target);
+    }
+
+    Ok((tokens, target))
+}
+
+/// `Name.this`、`Name.class`和`Name.super`要求前面是一个限定名称。
+fn qualifier<'a>(
+    tokens: &TokenStream,
+    target: &Expression<'a>,
+) -> Result<Cow<'a, str>, nom::Err<Error<TokenStream>>> {
+    match target {
+        Expression::Name(n) => Ok(n.clone()),
+        _ => Err(nom::Err::Error(Error::new(
+            tokens.clone(),
+            ErrorKind::Verify,
+        ))),
+    }
+}
+
+fn reference(name: Cow<str>) -> Type {
+    Type::Reference(ReferenceType {
+        name,
+        arguments: None,
+        sub_type: None,
+    })
+}
+
+/// 解析只能跟在类型后面的`.class`或`::`。
+fn type_suffix<'a>(tokens: TokenStream, t: Type<'a>) -> IResult<TokenStream, Expression<'a>> {
+    match tokens.first() {
+        Some(Token::DoubleColon) => {
+            method_reference(tokens.take_from(1), MethodReferenceTarget::Type(t))
+        }
+        _ => {
+            let (tokens, _) = tag(ts![Dot, Class]).parse(tokens)?;
+            Ok((tokens, Expression::ClassLiteral(t)))
+        }
+    }
+}
+
+/// 解析带有类型参数的类型开头的方法引用，例如`List<String>::size`或`ArrayList<T>[]::new`。
+/// 这类类型无法作为表达式解析，因为`<`会被当作小于运算符。
+fn generic_method_reference<'a>(tokens: TokenStream) -> IResult<TokenStream, Expression<'a>> {
+    let (tokens, t) = reference_type(tokens)?;
+    let mut generic = t.arguments.is_some();
+    let mut sub_type = &t.sub_type;
+    while let Some(s) = sub_type {
+        generic |= s.arguments.is_some();
+        sub_type = &s.sub_type;
+    }
+    let (tokens, dimensions) = dimensions(tokens)?;
+    if !generic || !matches!(tokens.first(), Some(Token::DoubleColon)) {
+        return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+    }
+    let t = array_of(Type::Reference(t), dimensions);
+    method_reference(tokens.take_from(1), MethodReferenceTarget::Type(t))
+}
+
+/// 解析`::`后面的类型参数和方法名称（或`new`）。
+fn method_reference<'a>(
+    tokens: TokenStream,
+    target: MethodReferenceTarget<'a>,
+) -> IResult<TokenStream, Expression<'a>> {
+    let (tokens, type_arguments) = opt(type_arguments).parse(tokens)?;
+    let (tokens, name) = match tokens.first() {
+        Some(Token::New) => (tokens.take_from(1), Cow::Borrowed(Token::NEW)),
+        _ => {
+            let (tokens, name) = identifier(tokens)?;
+            (tokens, Cow::Owned(name.to_string()))
+        }
+    };
+
+    Ok((
+        tokens,
+        Expression::MethodReference {
+            target,
+            type_arguments: type_arguments.unwrap_or_default(),
+            name,
+        },
+    ))
+}
+
+/// 解析方法名和实际参数，构造方法调用。
+fn invocation<'a>(
+    tokens: TokenStream,
+    target: Option<Expression<'a>>,
+    type_arguments: Vec<TypeArgument<'a>>,
+) -> IResult<TokenStream, Expression<'a>> {
+    let (tokens, name) = identifier(tokens)?;
+    let (tokens, arguments) = arguments(tokens)?;
+
+    Ok((
+        tokens,
+        Expression::MethodInvocation {
+            target: target.map(Box::new),
+            type_arguments,
+            name: Cow::Owned(name.to_string()),
+            arguments,
+        },
+    ))
+}
+
+/// 解析`super`后面的字段访问、方法调用或方法引用。
+fn super_suffix<'a>(
+    tokens: TokenStream,
+    qualifier: Option<Cow<'a, str>>,
+) -> IResult<TokenStream, Expression<'a>> {
+    if let Some(Token::DoubleColon) = tokens.first() {
+        return method_reference(tokens.take_from(1), MethodReferenceTarget::Super(qualifier));
+    }
+    let (tokens, _) = tag(ts![Dot]).parse(tokens)?;
+    let (tokens, type_arguments) = opt(type_arguments).parse(tokens)?;
+    let (tokens, name) = identifier(tokens)?;
+    let name = Cow::Owned(name.to_string());
+    if type_arguments.is_none() && !matches!(tokens.first(), Some(Token::LeftParen)) {
+        return Ok((tokens, Expression::SuperFieldAccess { qualifier, name }));
+    }
+    let (tokens, arguments) = arguments(tokens)?;
+
+    Ok((
+        tokens,
+        Expression::SuperMethodInvocation {
+            qualifier,
+            type_arguments: type_arguments.unwrap_or_default(),
+            name,
+            arguments,
+        },
+    ))
+}
+
+/// 解析`new`后面的类实例创建或数组创建，`outer`是内部类创建`outer.new Inner()`中的外部对象。
+fn creator<'a>(
+    tokens: TokenStream,
+    outer: Option<Expression<'a>>,
+) -> IResult<TokenStream, Expression<'a>> {
+    let (tokens, type_arguments) = opt(type_arguments).parse(tokens)?;
+    let (tokens, t) = match tokens.first() {
+        Some(t) if t.is_basic_type() && outer.is_none() => {
+            let (tokens, t) = basic_type(tokens)?;
+            (tokens, Type::Basic(t))
+        }
+        _ => {
+            let (tokens, t) = reference_type(tokens)?;
+            let (tokens, t) = diamond(tokens, t)?;
+            (tokens, Type::Reference(t))
+        }
+    };
+
+    if let (Some(Token::LeftBracket), None, None) = (tokens.first(), &outer, &type_arguments) {
+        return array_creation(tokens, t);
+    }
+    let Type::Reference(t) = t else {
+        return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+    };
+    let (tokens, arguments) = arguments(tokens)?;
+    let (tokens, body) = opt(class_body).parse(tokens)?;
+
+    Ok((
+        tokens,
+        Expression::ClassCreation {
+            outer: outer.map(Box::new),
+            type_arguments: type_arguments.unwrap_or_default(),
+            r#type: t,
+            arguments,
+            body,
+        },
+    ))
+}
+
+/// 类型参数`<>`表示由编译器推断，用空的类型参数列表表示。
+fn diamond<'a>(
+    tokens: TokenStream,
+    mut t: ReferenceType<'a>,
+) -> IResult<TokenStream, ReferenceType<'a>> {
+    let (tokens, d) = opt(tag(ts![LessThan, GreaterThan])).parse(tokens)?;
+    if d.is_some() {
+        let mut last = &mut t;
+        while let Some(ref mut sub_type) = last.sub_type {
+            last = sub_type;
+        }
+        if last.arguments.is_some() {
+            return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+        }
+        last.arguments = Some(Vec::new());
+    }
+
+    Ok((tokens, t))
+}
+
+fn array_creation<'a>(
+    mut tokens: TokenStream,
+    element: Type<'a>,
+) -> IResult<TokenStream, Expression<'a>> {
+    let mut lengths = Vec::new();
+    while let Some(Token::LeftBracket) = tokens.first() {
+        if let Some(Token::RightBracket) = tokens.take_from(1).first() {
+            break;
+        }
+        let (next, length) =
+            delimited(tag(ts![LeftBracket]), expression, tag(ts![RightBracket])).parse(tokens)?;
+        lengths.push(length);
+        tokens = next;
+    }
+    let (tokens, dimensions) = dimensions(tokens)?;
+    let (tokens, initializer) = if lengths.is_empty() {
+        let (tokens, initializer) = array_initializer(tokens)?;
+        (tokens, Some(initializer))
+    } else {
+        (tokens, None)
+    };
+    if lengths.len() + dimensions == 0 {
+        return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+    }
+
+    Ok((
+        tokens,
+        Expression::ArrayCreation {
+            r#type: array_of(element, lengths.len() + dimensions),
+            dimensions: lengths,
+            initializer,
+        },
+    ))
+}
+
+/// 解析方法调用或类实例创建的实际参数列表`(a, b)`。
+pub fn arguments<'a>(tokens: TokenStream) -> IResult<TokenStream, Vec<Expression<'a>>> {
+    let arguments = delimited(
+        tag(ts![LeftParen]),
+        separated_list0(tag(ts![Comma]), expression),
+        tag(ts![RightParen]),
+    );
+    syntax_node(SyntaxKind::ArgumentList, arguments).parse(tokens)
+}
+
+/// 解析数组初始化器`{a, {b, c}, }`，允许末尾多余的逗号。
+pub fn array_initializer<'a>(tokens: TokenStream) -> IResult<TokenStream, ArrayInitializer<'a>> {
+    nested(tokens, |tokens| {
+        let start = tokens.source_offset();
+        let (tokens, _) = tag(ts![LeftBrace]).parse(tokens)?;
+        let (tokens, initializers) =
+            separated_list0(tag(ts![Comma]), variable_initializer).parse(tokens)?;
+        let (tokens, _) = opt(tag(ts![Comma])).parse(tokens)?;
+        let (tokens, _) = tag(ts![RightBrace]).parse(tokens)?;
+        tokens.finish_node(start, SyntaxKind::ArrayInitializer);
+        Ok((tokens, ArrayInitializer { initializers }))
+    })
+}
+
+/// 解析变量的初始值：表达式或数组初始化器。
+pub fn variable_initializer<'a>(
+    tokens: TokenStream,
+) -> IResult<TokenStream, VariableInitializer<'a>> {
+    match tokens.first() {
+        Some(Token::LeftBrace) => {
+            let (tokens, a) = array_initializer(tokens)?;
+            Ok((tokens, VariableInitializer::Array(a)))
+        }
+        _ => {
+            let (tokens, e) = expression(tokens)?;
+            Ok((tokens, VariableInitializer::Expression(e)))
+        }
+    }
+}
+
+/// 解析字面量，例如`1`、`1.5f`、`'a'`、`"abc"`、`true`、`null`。
+///
+/// 超出`int`或`long`范围的十进制字面量（不是一元`-`的操作数的`2147483648`）会终止解析。
+pub fn literal<'a>(tokens: TokenStream) -> IResult<TokenStream, Literal<'a>> {
+    match tokens.first() {
+        Some(Token::IntegerLiteral(n)) if *n > i32::MAX as u32 => Err(out_of_range(&tokens)),
+        Some(Token::LongLiteral(n)) if *n > i64::MAX as u64 => Err(out_of_range(&tokens)),
+        _ => next_token(|token| Literal::try_from(token.clone()).ok())(tokens),
+    }
+}
+
+/// 第一个标记是超出范围的字面量，终止解析。
+fn out_of_range(tokens: &TokenStream) -> nom::Err<Error<TokenStream>> {
+    tokens.fail(ParseErrorKind::Literal(LiteralError::OutOfRange), 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BasicType, InstanceOfTarget, Pattern, ReferenceType};
+
+    fn parse(input: &'static str) -> anyhow::Result<Expression<'static>> {
+        let (_, tokens) = TokenStream::from_str(input)?;
+        let (tokens, e) = expression(tokens)?;
+        assert!(tokens.is_empty(), "剩余标记：{:?}", tokens);
+        Ok(e)
+    }
+
+    #[test]
+    fn test_precedence() -> anyhow::Result<()> {
+        let Expression::Binary {
+            operator: BinaryOperator::LogicalOr,
+            left,
+            right,
+        } = parse("a || b && c | d ^ e & f == g < h << i + j * k")?
+        else {
+            panic!("`||`的优先级最低");
+        };
+        assert_eq!(*left, Expression::Name("a".into()));
+        let Expression::Binary {
+            operator: BinaryOperator::LogicalAnd,
+            right,
+            ..
+        } = *right
+        else {
+            panic!("`&&`的优先级次低");
+        };
+        assert!(matches!(
+            *right,
+            Expression::Binary {
+                operator: BinaryOperator::Or,
+                ..
+            }
+        ));
+
+        // 同级运算符左结合
+        let Expression::Binary {
+            operator: BinaryOperator::Minus,
+            left,
+            ..
+        } = parse("a - b - c")?
+        else {
+            panic!();
+        };
+        assert!(matches!(
+            *left,
+            Expression::Binary {
+                operator: BinaryOperator::Minus,
+                ..
+            }
+        ));
+
+        let Expression::Binary {
+            operator: BinaryOperator::LogicalAnd,
+            left,
+            ..
+        } = parse("x instanceof String && y >>> 2 >= 0")?
+        else {
+            panic!();
+        };
+        assert!(matches!(*left, Expression::InstanceOf { .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_assignment_and_ternary() -> anyhow::Result<()> {
+        let Expression::Assignment {
+            operator: AssignmentOperator::Assign,
+            value,
+            ..
+        } = parse("a = b += c ? d : e ? f : g")?
+        else {
+            panic!();
+        };
+        let Expression::Assignment {
+            operator: AssignmentOperator::Plus,
+            value,
+            ..
+        } = *value
+        else {
+            panic!("赋值运算是右结合的");
+        };
+        let Expression::Ternary { if_false, .. } = *value else {
+            panic!();
+        };
+        assert!(matches!(*if_false, Expression::Ternary { .. }));
+
+        assert!(matches!(
+            parse("x >>>= 1")?,
+            Expression::Assignment {
+                operator: AssignmentOperator::UnsignedShiftRight,
+                ..
+            }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unary_and_postfix() -> anyhow::Result<()> {
+        assert_eq!(parse("-~!a")?.to_string(), "-~!a");
+        let Expression::Binary { left, right, .. } = parse("i++ + --j")? else {
+            panic!();
+        };
+        assert!(matches!(
+            *left,
+            Expression::Postfix {
+                operator: PostfixOperator::Increment,
+                ..
+            }
+        ));
+        assert!(matches!(
+            *right,
+            Expression::Unary {
+                operator: UnaryOperator::Decrement,
+                ..
+            }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast() -> anyhow::Result<()> {
+        assert_eq!(
+            parse("(float) -1.0")?,
+            Expression::Cast {
+                r#type: Type::Basic(BasicType::Float),
+                bounds: vec![],
+                expression: Box::new(Expression::Unary {
+                    operator: UnaryOperator::Minus,
+                    operand: Box::new(Expression::Literal(Literal::Double(1.0))),
+                }),
+            }
+        );
+
+        let Expression::Cast { r#type, bounds, .. } = parse("(Runnable & java.io.Serializable) r")?
+        else {
+            panic!();
+        };
+        assert_eq!(r#type.to_string(), "Runnable");
+        assert_eq!(
+            bounds,
+            vec![ReferenceType {
+                name: "java.io.Serializable".into(),
+                arguments: None,
+                sub_type: None,
+            }]
+        );
+
+        assert!(matches!(
+            parse("(List<String>) (Object) list")?,
+            Expression::Cast { .. }
+        ));
+        // 引用类型后面是`-`时是括号表达式而不是类型转换
+        assert!(matches!(
+            parse("(a) - 1")?,
+            Expression::Binary {
+                operator: BinaryOperator::Minus,
+                ..
+            }
+        ));
+        assert!(matches!(parse("(a < b)")?, Expression::Parenthesized(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_literal() -> anyhow::Result<()> {
+        assert_eq!(parse("'a'")?, Expression::Literal(Literal::Char('a')));
+        assert_eq!(
+            parse("\"abc\"")?,
+            Expression::Literal(Literal::String("abc".into()))
+        );
+        assert_eq!(parse("0x1f")?, Expression::Literal(Literal::Hex(31)));
+        // 十六进制、八进制和二进制的长整数后缀
+        for (source, literal) in [
+            ("0xFFFFFFFFFFL", Literal::HexLong(0xFF_FFFF_FFFF)),
+            ("0xffff_ffff_ffff_ffffl", Literal::HexLong(u64::MAX)),
+            ("0b1L", Literal::BinaryLong(1)),
+            ("017L", Literal::OctalLong(0o17)),
+            ("0L", Literal::Long(0)),
+        ] {
+            let e = parse(source)?;
+            assert_eq!(e, Expression::Literal(literal), "{}", source);
+            // 输出时保留后缀
+            let display = e.to_string();
+            let token = TokenStream::from_str(&display)
+                .ok()
+                .and_then(|(_, t)| t.first().cloned());
+            assert_eq!(token.map(|t| t.to_string()), Some(display));
+        }
+        assert!(parse("0x1_0000_0000_0000_0000L").is_err());
+        assert_eq!(parse("null")?, Expression::Literal(Literal::Null));
+        // 以关键字或字面量开头的标识符
+        assert_eq!(parse("nullable")?, Expression::Name("nullable".into()));
+        assert_eq!(
+            parse("integer.doubleValue")?,
+            Expression::Name("integer.doubleValue".into())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_literal() -> anyhow::Result<()> {
+        for (source, c) in [
+            (r"'\n'", '\n'),
+            (r"'\''", '\''),
+            (r"'\\'", '\\'),
+            (r"'\s'", ' '),
+            (r"'\u0041'", 'A'),
+            (r"'\101'", 'A'),
+            (r"'\0'", '\0'),
+            ("'\"'", '"'),
+            ("'中'", '中'),
+        ] {
+            let literal = parse(source)?;
+            assert_eq!(literal, Expression::Literal(Literal::Char(c)), "{}", source);
+            // 输出时重新转义，得到的字面量表示同一个字符
+            let display = literal.to_string();
+            let token = TokenStream::from_str(&display)
+                .ok()
+                .and_then(|(_, t)| t.first().cloned());
+            assert_eq!(token, Some(Token::CharLiteral(c)), "{}", display);
+        }
+        assert_eq!(parse(r"'\t'")?.to_string(), r"'\t'");
+
+        // 必须恰好有一个字符或转义序列
+        for source in ["'ab'", "''", r"'\q'", r"'\400'"] {
+            assert!(TokenStream::from_str(source).is_err(), "{}", source);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_primary() -> anyhow::Result<()> {
+        let Expression::MethodInvocation {
+            target: Some(target),
+            name,
+            arguments,
+            ..
+        } = parse("System.out.println(a[i][j], this.x)")?
+        else {
+            panic!();
+        };
+        assert_eq!(*target, Expression::Name("System.out".into()));
+        assert_eq!(name, "println");
+        assert!(matches!(
+            &arguments[..],
+            [
+                Expression::ArrayAccess { .. },
+                Expression::FieldAccess { .. }
+            ]
+        ));
+
+        let e = parse("Collections.<String>emptyList().get(0).length")?;
+        assert!(matches!(e, Expression::FieldAccess { .. }));
+        assert_eq!(
+            e.to_string(),
+            "Collections.<String>emptyList().get(0).length"
+        );
+
+        assert_eq!(parse("Outer.super.foo()")?.to_string(), "Outer.super.foo()");
+        assert!(matches!(
+            parse("super.x = Outer.this.y")?,
+            Expression::Assignment { .. }
+        ));
+
+        assert_eq!(parse("String.class")?.to_string(), "String.class");
+        assert!(matches!(
+            parse("int[][].class")?,
+            Expression::ClassLiteral(t) if t.dimensions() == 2
+        ));
+        assert_eq!(
+            parse("java.lang.String[].class")?.to_string(),
+            "java.lang.String[].class"
+        );
+        assert_eq!(parse("void.class")?, Expression::VoidClassLiteral);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_creation() -> anyhow::Result<()> {
+        let Expression::ClassCreation {
+            r#type, arguments, ..
+        } = parse("new java.util.HashMap<>(16)")?
+        else {
+            panic!();
+        };
+        assert_eq!(r#type.arguments, Some(vec![]));
+        assert_eq!(arguments.len(), 1);
+
+        let Expression::ClassCreation { body, .. } =
+            parse("new Runnable() { public void run() {} }")?
+        else {
+            panic!();
+        };
+        assert_eq!(body.map(|b| b.declarations.len()), Some(1));
+
+        assert!(matches!(
+            parse("outer.new Inner<String>()")?,
+            Expression::ClassCreation { outer: Some(_), .. }
+        ));
+
+        let Expression::ArrayCreation {
+            r#type,
+            dimensions,
+            initializer,
+        } = parse("new int[n][]")?
+        else {
+            panic!();
+        };
+        assert_eq!(r#type.dimensions(), 2);
+        assert_eq!(dimensions.len(), 1);
+        assert!(initializer.is_none());
+
+        let e = parse("new String[][] {{\"a\"}, {}, }")?;
+        assert_eq!(e.to_string(), "new String[][] {{\"a\"}, {}}");
+        assert!(parse("new int[]").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lambda() -> anyhow::Result<()> {
+        let Expression::Lambda { parameters, body } = parse("x -> x + 1")? else {
+            panic!();
+        };
+        assert_eq!(parameters, LambdaParameters::Inferred(vec!["x".into()]));
+        assert!(matches!(body, LambdaBody::Expression(_)));
+
+        let Expression::Lambda { parameters, body } =
+            parse("(int a, final String... b) -> { return a; }")?
+        else {
+            panic!();
+        };
+        assert!(
+            matches!(parameters, LambdaParameters::Formal(ref p) if p.len() == 2 && p[1].varargs)
+        );
+        assert!(matches!(body, LambdaBody::Block(_)));
+
+        assert_eq!(
+            parse("(var a, var b) -> a")?.to_string(),
+            "(var a, var b) -> a"
+        );
+        assert_eq!(parse("() -> a -> b")?.to_string(), "() -> (a) -> b");
+        assert!(matches!(
+            parse("(Runnable) () -> {}")?,
+            Expression::Cast { expression, .. } if matches!(*expression, Expression::Lambda { .. })
+        ));
+        assert!(matches!(
+            parse("c ? x -> 1 : y -> 2")?,
+            Expression::Ternary { if_false, .. } if matches!(*if_false, Expression::Lambda { .. })
+        ));
+        // 括号表达式不是Lambda表达式
+        assert!(matches!(parse("(a) + (b)")?, Expression::Binary { .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_method_reference() -> anyhow::Result<()> {
+        let Expression::MethodReference { target, name, .. } = parse("System.out::println")? else {
+            panic!();
+        };
+        assert_eq!(
+            target,
+            MethodReferenceTarget::Expression(Box::new(Expression::Name("System.out".into())))
+        );
+        assert_eq!(name, "println");
+
+        assert!(matches!(
+            parse("int[]::new")?,
+            Expression::MethodReference { target: MethodReferenceTarget::Type(t), name, .. }
+                if t.dimensions() == 1 && name == "new"
+        ));
+        assert!(matches!(
+            parse("String[]::new")?,
+            Expression::MethodReference {
+                target: MethodReferenceTarget::Type(_),
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse("java.util.ArrayList<String>::new")?,
+            Expression::MethodReference {
+                target: MethodReferenceTarget::Type(_),
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse("super::toString")?,
+            Expression::MethodReference {
+                target: MethodReferenceTarget::Super(None),
+                ..
+            }
+        ));
+        assert_eq!(parse("Outer.super::<T>m")?.to_string(), "Outer.super::<T>m");
+        assert_eq!(parse("this.list()::add")?.to_string(), "this.list()::add");
+        // `<`是小于运算符时不是方法引用
+        assert!(matches!(
+            parse("a < b")?,
+            Expression::Binary {
+                operator: BinaryOperator::LessThan,
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse("f(a < b, c > d)")?,
+            Expression::MethodInvocation { .. }
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_instanceof_pattern() -> anyhow::Result<()> {
+        let Expression::Binary { left, .. } = parse("o instanceof final String s && s.isEmpty()")?
+        else {
+            panic!();
+        };
+        let Expression::InstanceOf {
+            target: InstanceOfTarget::Pattern(Pattern::Type { name, .. }),
+            ..
+        } = *left
+        else {
+            panic!();
+        };
+        assert_eq!(name, "s");
+
+        assert!(matches!(
+            parse("o instanceof Point(var x, var y)")?,
+            Expression::InstanceOf {
+                target: InstanceOfTarget::Pattern(Pattern::Record { .. }),
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse("o instanceof List<?>[]")?,
+            Expression::InstanceOf {
+                target: InstanceOfTarget::Type(_),
+                ..
+            }
+        ));
+
+        // 基本类型模式是预览特性
+        let (_, tokens) = TokenStream::from_str("x instanceof int i")?;
+        assert!(expression(tokens.clone()).is_err());
+        let (remaining, e) = expression(tokens.with_options(crate::ParseOptions::preview()))?;
+        assert!(remaining.is_empty());
+        assert!(matches!(
+            e,
+            Expression::InstanceOf {
+                target: InstanceOfTarget::Pattern(Pattern::Type {
+                    r#type: Type::Basic(BasicType::Int),
+                    ..
+                }),
+                ..
+            }
+        ));
+
+        Ok(())
+    }
+}
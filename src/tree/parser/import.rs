@@ -1,14 +1,14 @@
-use super::{super::ImportDeclaration, identifier};
-use crate::{ts, Token, TokenStream};
+use super::{super::ImportDeclaration, identifier, qualified_name, syntax_node, tag};
+use crate::{ts, SyntaxKind, Token, TokenStream};
 use nom::{
-    bytes::tag,
     combinator::{complete, opt},
-    error::Error,
     multi::{many0, separated_list1},
-    IResult, Parser,
+    IResult, Input, Parser,
 };
 use std::borrow::Cow;
 
+const MODULE: &str = "module";
+
 /// 解析导入声明
 ///
 /// 该函数解析一个导入声明，并返回解析后的导入声明和剩余的标记流。
@@ -37,6 +37,11 @@ use std::borrow::Cow;
 /// 如果解析过程中出现错误，将返回一个解析错误。
 pub fn import_declaration<'a>(tokens: TokenStream) -> IResult<TokenStream, ImportDeclaration<'a>> {
     let (tokens, _) = tag(ts![Import]).parse(tokens)?;
+    if tokens.options().preview && is_module_import(&tokens) {
+        let (tokens, name) = qualified_name(tokens.take_from(1))?;
+        let (tokens, _) = tag(ts![SemiColon]).parse(tokens)?;
+        return Ok((tokens, ImportDeclaration::Module(name)));
+    }
     let (tokens, r#static) = opt(tag(ts![Static])).parse(tokens)?;
     let (tokens, idents) = separated_list1(complete(tag(ts![Dot])), identifier).parse(tokens)?;
     let name = idents
@@ -45,8 +50,7 @@ pub fn import_declaration<'a>(tokens: TokenStream) -> IResult<TokenStream, Impor
         .collect::<Vec<_>>()
         .join(Token::DOT);
 
-    let Ok((tokens, _)) = tag::<_, _, Error<TokenStream>>(ts![Dot, Star]).parse(tokens.clone())
-    else {
+    let Ok((tokens, _)) = tag(ts![Dot, Star]).parse(tokens.clone()) else {
         let (tokens, _) = tag(ts![SemiColon]).parse(tokens)?;
         let import_declaration = if r#static.is_none() {
             ImportDeclaration::SimpleType(Cow::Owned(name))
@@ -65,6 +69,14 @@ pub fn import_declaration<'a>(tokens: TokenStream) -> IResult<TokenStream, Impor
     Ok((tokens, import_declaration))
 }
 
+/// `import module M;`以上下文关键字`module`开头，而`import module.Foo;`仍然是普通的单类型导入。
+fn is_module_import(tokens: &TokenStream) -> bool {
+    matches!(
+        tokens.as_slice(),
+        [Token::Identifier(i), Token::Identifier(_), ..] if i == MODULE
+    )
+}
+
 /// 解析导入声明列表
 ///
 /// 该函数解析一个导入声明列表，并返回解析后的导入声明列表和剩余的标记流。
@@ -94,12 +106,17 @@ pub fn import_declaration<'a>(tokens: TokenStream) -> IResult<TokenStream, Impor
 pub fn import_declarations<'a>(
     tokens: TokenStream,
 ) -> IResult<TokenStream, Vec<ImportDeclaration<'a>>> {
-    many0(complete(import_declaration)).parse(tokens)
+    many0(complete(syntax_node(
+        SyntaxKind::ImportDeclaration,
+        import_declaration,
+    )))
+    .parse(tokens)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ParseOptions;
 
     const IMPORTS: &str = "
     import java.util.List;
@@ -130,6 +147,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_module_import() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str("import module java.base; import module.Foo;")?;
+        // 未启用预览特性时不识别模块导入声明
+        assert!(import_declarations(tokens.clone())?.1.is_empty());
+
+        let (tokens, imports) = import_declarations(tokens.with_options(ParseOptions::preview()))?;
+        assert!(tokens.is_empty());
+        assert_eq!(imports[0], ImportDeclaration::Module("java.base".into()));
+        assert_eq!(imports[0].to_string(), "import module java.base;");
+        assert_eq!(
+            imports[1],
+            ImportDeclaration::SimpleType("module.Foo".into())
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_import_declarations() -> anyhow::Result<()> {
         let (_, tokens) = TokenStream::from_str(IMPORTS)?;
@@ -0,0 +1,423 @@
+use super::super::{
+    BlockTag, BlockTagKind, DocFragment, DocumentationComment, DocumentationFlavor, InlineTag,
+    InlineTagKind, Javadoc, Span,
+};
+use super::snippet;
+use std::{borrow::Cow, ops::Range};
+
+/// 解析文档注释的内容
+///
+/// 该函数把文档注释拆分为主描述、第一句话和块标签，并识别其中的行内标签。
+/// 传统文档注释行首的空白和`*`会被去掉，所有范围都指向[`DocumentationComment::text`]中的原文。
+///
+/// # 参数
+///
+/// * `comment` - 待解析的文档注释。
+///
+/// # 返回值
+///
+/// 返回解析后的 `Javadoc`，任何文本都是合法的文档注释，所以该函数不会失败。
+///
+/// # 示例
+///
+/// ```rust
+/// use java_lang::{javadoc, BlockTagKind, DocumentationComment};
+/// let comment = DocumentationComment::from("
+///  * Returns the {@code size} of the list. Never negative.
+///  *
+///  * @param <E> the element type
+///  * @return the size
+///  ");
+/// let doc = javadoc(&comment);
+/// assert_eq!(doc.first_sentence.len(), 3);
+/// assert_eq!(doc.inline_tags().count(), 1);
+/// assert!(matches!(doc.block_tags[0].kind, BlockTagKind::Param { type_parameter: true, .. }));
+/// assert_eq!(&comment.text()[doc.block_tags[1].span.clone()], "@return the size");
+/// ```
+pub fn javadoc<'a>(comment: &DocumentationComment) -> Javadoc<'a> {
+    let source = Source::new(comment);
+    let sections = source.sections();
+    let description_end = sections.first().map_or(source.text.len(), |s| s.start);
+    let description = 0..description_end;
+
+    Javadoc {
+        description: source.fragments(description.clone()),
+        first_sentence: source.fragments(0..source.first_sentence_end(description)),
+        block_tags: sections.into_iter().map(|s| source.block_tag(s)).collect(),
+    }
+}
+
+/// 去掉行首`*`后的注释内容，以及其中每个字节在原文中的位置。
+struct Source {
+    text: String,
+    /// `offsets[i]`是`text`第`i`个字节在原文中的位置，最后一项是原文的长度。
+    offsets: Vec<usize>,
+}
+
+impl Source {
+    fn new(comment: &DocumentationComment) -> Self {
+        let raw = comment.text();
+        let mut text = String::with_capacity(raw.len());
+        let mut offsets = Vec::with_capacity(raw.len() + 1);
+        let mut start = 0;
+        for line in raw.split_inclusive('\n') {
+            let mut skip = 0;
+            if comment.flavor() == DocumentationFlavor::JavaDoc {
+                let trimmed = line.trim_start_matches([' ', '\t']);
+                if trimmed.starts_with('*') {
+                    skip = line.len() - trimmed.trim_start_matches('*').len();
+                }
+            }
+            for (i, c) in line[skip..].char_indices() {
+                if c == '\r' {
+                    continue;
+                }
+                text.push(c);
+                offsets.extend((0..c.len_utf8()).map(|j| start + skip + i + j));
+            }
+            start += line.len();
+        }
+        offsets.push(raw.len());
+
+        Self { text, offsets }
+    }
+
+    fn span(&self, range: &Range<usize>) -> Span {
+        if range.is_empty() {
+            let start = self.offsets[range.start];
+            return start..start;
+        }
+        self.offsets[range.start]..self.offsets[range.end - 1] + 1
+    }
+
+    /// 去掉范围两端的空白。
+    fn trim(&self, range: Range<usize>) -> Range<usize> {
+        let s = &self.text[range.clone()];
+        let start = range.start + (s.len() - s.trim_start().len());
+        let end = range.end - (s.len() - s.trim_end().len());
+        start..end.max(start)
+    }
+
+    /// 找到所有块标签的范围：行首（忽略空白）以`@`和字母开头，并且不在行内标签中。
+    fn sections(&self) -> Vec<Range<usize>> {
+        let bytes = self.text.as_bytes();
+        let mut starts = Vec::new();
+        let mut depth = 0usize;
+        let mut line_start = true;
+        for (i, &b) in bytes.iter().enumerate() {
+            match b {
+                b'\n' => line_start = true,
+                b' ' | b'\t' => {}
+                b'@' if line_start
+                    && depth == 0
+                    && bytes.get(i + 1).is_some_and(u8::is_ascii_alphabetic) =>
+                {
+                    starts.push(i);
+                    line_start = false;
+                }
+                _ => {
+                    if b == b'{' && (depth > 0 || bytes.get(i + 1) == Some(&b'@')) {
+                        depth += 1;
+                    } else if b == b'}' && depth > 0 {
+                        depth -= 1;
+                    }
+                    line_start = false;
+                }
+            }
+        }
+
+        let mut sections = Vec::with_capacity(starts.len());
+        for (i, &start) in starts.iter().enumerate() {
+            let end = starts.get(i + 1).copied().unwrap_or(self.text.len());
+            sections.push(self.trim(start..end));
+        }
+        sections
+    }
+
+    /// 第一句话在第一个后面跟着空白的`.`处结束，或者在段落标签`<p>`处结束。
+    fn first_sentence_end(&self, range: Range<usize>) -> usize {
+        let bytes = self.text.as_bytes();
+        let mut i = range.start;
+        while i < range.end {
+            match bytes[i] {
+                b'{' if bytes.get(i + 1) == Some(&b'@') => {
+                    i = self.inline_tag_end(i).unwrap_or(range.end);
+                    continue;
+                }
+                b'.' if i + 1 == range.end || bytes[i + 1].is_ascii_whitespace() => {
+                    return i + 1;
+                }
+                b'<' if self.text[i..range.end]
+                    .get(..3)
+                    .is_some_and(|p| p.eq_ignore_ascii_case("<p>"))
+                    && i > range.start =>
+                {
+                    return i;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        range.end
+    }
+
+    /// 找到从`start`处的`{@`开始的行内标签的结束位置（`}`之后），内容中的大括号需要成对出现。
+    fn inline_tag_end(&self, start: usize) -> Option<usize> {
+        let mut depth = 0usize;
+        for (i, b) in self.text.as_bytes()[start..].iter().enumerate() {
+            match b {
+                b'{' => depth += 1,
+                b'}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Some(start + i + 1);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn fragments<'a>(&self, range: Range<usize>) -> Vec<DocFragment<'a>> {
+        let range = self.trim(range);
+        let mut fragments = Vec::new();
+        let mut text_start = range.start;
+        let mut i = range.start;
+        while let Some(pos) = self.text[i..range.end].find("{@") {
+            let start = i + pos;
+            let Some(end) = self.inline_tag_end(start).filter(|&e| e <= range.end) else {
+                break;
+            };
+            self.push_text(&mut fragments, text_start..start);
+            fragments.push(DocFragment::InlineTag(self.inline_tag(start..end)));
+            text_start = end;
+            i = end;
+        }
+        self.push_text(&mut fragments, text_start..range.end);
+
+        fragments
+    }
+
+    fn push_text<'a>(&self, fragments: &mut Vec<DocFragment<'a>>, range: Range<usize>) {
+        if !range.is_empty() {
+            fragments.push(DocFragment::Text {
+                text: Cow::Owned(self.text[range.clone()].to_string()),
+                span: self.span(&range),
+            });
+        }
+    }
+
+    /// 解析`{@name content}`，`range`包括两边的大括号。
+    fn inline_tag<'a>(&self, range: Range<usize>) -> InlineTag<'a> {
+        let inner = &self.text[range.start + 2..range.end - 1];
+        let (name, content) = split_word(inner);
+        let content = content.trim_start();
+        let owned = |s: &str| Cow::Owned(s.to_string());
+        let kind = match name {
+            "link" | "linkplain" => {
+                let (reference, label) = split_reference(content);
+                let label = label.trim();
+                InlineTagKind::Link {
+                    reference: owned(reference),
+                    label: (!label.is_empty()).then(|| owned(label)),
+                    plain: name == "linkplain",
+                }
+            }
+            "code" => InlineTagKind::Code(owned(content)),
+            "literal" => InlineTagKind::Literal(owned(content)),
+            "inheritDoc" => InlineTagKind::InheritDoc,
+            "snippet" => InlineTagKind::Snippet(snippet(content)),
+            "value" => {
+                let content = content.trim();
+                InlineTagKind::Value((!content.is_empty()).then(|| owned(content)))
+            }
+            _ => InlineTagKind::Unknown {
+                name: owned(name),
+                content: owned(content.trim_end()),
+            },
+        };
+
+        InlineTag {
+            kind,
+            span: self.span(&range),
+        }
+    }
+
+    /// 解析块标签，`range`从`@`开始。
+    fn block_tag<'a>(&self, range: Range<usize>) -> BlockTag<'a> {
+        let (name, rest) = self.word(range.start + 1..range.end);
+        let kind = match &self.text[name] {
+            "param" => {
+                let (word, rest) = self.word(rest);
+                let word = &self.text[word];
+                let parameter = word.strip_prefix('<').and_then(|w| w.strip_suffix('>'));
+                BlockTagKind::Param {
+                    name: Cow::Owned(parameter.unwrap_or(word).to_string()),
+                    type_parameter: parameter.is_some(),
+                    description: self.fragments(rest),
+                }
+            }
+            "return" => BlockTagKind::Return(self.fragments(rest)),
+            "throws" | "exception" => {
+                let (exception, rest) = self.word(rest);
+                BlockTagKind::Throws {
+                    exception: Cow::Owned(self.text[exception].to_string()),
+                    description: self.fragments(rest),
+                }
+            }
+            "see" => BlockTagKind::See(self.fragments(rest)),
+            "since" => BlockTagKind::Since(self.fragments(rest)),
+            "deprecated" => BlockTagKind::Deprecated(self.fragments(rest)),
+            name => BlockTagKind::Unknown {
+                name: Cow::Owned(name.to_string()),
+                content: self.fragments(rest),
+            },
+        };
+
+        BlockTag {
+            kind,
+            span: self.span(&range),
+        }
+    }
+
+    /// 跳过空白后取出一个单词，返回单词和剩余部分的范围。
+    fn word(&self, range: Range<usize>) -> (Range<usize>, Range<usize>) {
+        let s = &self.text[range.clone()];
+        let start = range.start + (s.len() - s.trim_start().len());
+        let (word, _) = split_word(&self.text[start..range.end]);
+        let end = start + word.len();
+        (start..end, end..range.end)
+    }
+}
+
+/// 在第一个空白或`}`处分开。
+fn split_word(s: &str) -> (&str, &str) {
+    let end = s
+        .find(|c: char| c.is_whitespace() || c == '}')
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+/// 分开`{@link}`中的引用和标签，引用中的参数列表可以包含空白，例如`List#add(int, Object)`。
+fn split_reference(s: &str) -> (&str, &str) {
+    let mut depth = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => return s.split_at(i),
+            _ => {}
+        }
+    }
+    (s, "")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMENT: &str = "
+     * Appends the specified element to the end of this list (optional
+     * operation). See {@link java.util.List#add(int, Object) add at index}
+     * and {@code Map<K, V> {}}.
+     * <p>
+     * Lists that support this operation may place limitations.
+     *
+     * @param e element to be appended
+     * @param <T> the element type
+     * @return {@code true} (as specified by {@link Collection#add})
+     * @throws UnsupportedOperationException if the {@code add} operation
+     *         is not supported
+     * @exception ClassCastException if the class is wrong
+     * @see #remove(Object)
+     * @since 1.2
+     * @deprecated use {@linkplain #push push} instead
+     * @author someone
+     ";
+
+    #[test]
+    fn test_javadoc() {
+        let comment = DocumentationComment::from(COMMENT);
+        let doc = javadoc(&comment);
+
+        let description = doc
+            .description
+            .iter()
+            .map(|f| f.to_string())
+            .collect::<String>();
+        assert!(description.starts_with("Appends the specified element"));
+        assert!(!description.contains('*'));
+        assert!(description.ends_with("may place limitations."));
+        assert_eq!(
+            doc.first_sentence
+                .iter()
+                .map(|f| f.to_string())
+                .collect::<String>(),
+            "Appends the specified element to the end of this list (optional\n operation)."
+        );
+
+        let tags = doc.inline_tags().map(|t| &t.kind).collect::<Vec<_>>();
+        assert_eq!(
+            tags[0],
+            &InlineTagKind::Link {
+                reference: "java.util.List#add(int, Object)".into(),
+                label: Some("add at index".into()),
+                plain: false,
+            }
+        );
+        assert_eq!(tags[1], &InlineTagKind::Code("Map<K, V> {}".into()));
+        assert_eq!(tags.len(), 6);
+
+        let kinds = doc.block_tags.iter().map(|t| &t.kind).collect::<Vec<_>>();
+        assert_eq!(kinds.len(), 9);
+        assert!(matches!(
+            kinds[0],
+            BlockTagKind::Param { name, type_parameter: false, .. } if name == "e"
+        ));
+        assert!(matches!(
+            kinds[1],
+            BlockTagKind::Param { name, type_parameter: true, .. } if name == "T"
+        ));
+        assert!(matches!(kinds[2], BlockTagKind::Return(d) if d.len() == 4));
+        assert_eq!(
+            kinds[3].to_string(),
+            "@throws UnsupportedOperationException if the {@code add} operation\n         is not supported"
+        );
+        assert!(matches!(
+            kinds[4],
+            BlockTagKind::Throws { exception, .. } if exception == "ClassCastException"
+        ));
+        assert_eq!(kinds[6].to_string(), "@since 1.2");
+        assert!(matches!(kinds[8], BlockTagKind::Unknown { name, .. } if name == "author"));
+
+        // 范围指向原文
+        let span = doc.inline_tags().next().unwrap().span.clone();
+        assert_eq!(
+            &COMMENT[span],
+            "{@link java.util.List#add(int, Object) add at index}"
+        );
+        let DocFragment::Text { span, .. } = &doc.first_sentence[0] else {
+            panic!();
+        };
+        assert!(COMMENT[span.clone()].contains("* operation)."));
+        assert_eq!(&COMMENT[doc.block_tags[6].span.clone()], "@since 1.2");
+    }
+
+    #[test]
+    fn test_markdown_javadoc() {
+        let comment = DocumentationComment::markdown(
+            " Returns the size.\n\n * not a tag {@value}\n @return the size",
+        );
+        let doc = javadoc(&comment);
+        // Markdown注释的行首`*`是列表标记，不会被去掉
+        assert!(doc.description[0].to_string().contains("* not a tag"));
+        assert_eq!(doc.first_sentence[0].to_string(), "Returns the size.");
+        assert_eq!(
+            doc.inline_tags().next().map(|t| &t.kind),
+            Some(&InlineTagKind::Value(None))
+        );
+        assert_eq!(doc.block_tags.len(), 1);
+        assert_eq!(doc.to_string().lines().last(), Some("@return the size"));
+    }
+}
@@ -0,0 +1,215 @@
+use super::{
+    super::{ModuleDeclaration, ModuleDirective, RequiresModifier},
+    annotation, documentation_comment, qualified_name, tag,
+};
+use crate::{ts, SyntaxKind, Token, TokenStream};
+use nom::{
+    combinator::opt,
+    error::{Error, ErrorKind},
+    multi::{many0, separated_list1},
+    sequence::preceded,
+    IResult, Input, Parser,
+};
+
+const OPEN: &str = "open";
+const MODULE: &str = "module";
+const REQUIRES: &str = "requires";
+const EXPORTS: &str = "exports";
+const OPENS: &str = "opens";
+const USES: &str = "uses";
+const PROVIDES: &str = "provides";
+const TO: &str = "to";
+const WITH: &str = "with";
+
+/// 解析模块声明
+///
+/// 该函数解析`module-info.java`中的模块声明（包括前面的文档注释和注解），并返回解析后的模块声明和剩余的标记流。
+///
+/// # 参数
+///
+/// * `tokens` - 标记流，包含待解析的标记。
+///
+/// # 返回值
+///
+/// 返回一个 `IResult`，其中包含解析后剩余的标记流和模块声明。
+///
+/// # 示例
+///
+/// ```rust
+/// fn main() -> anyhow::Result<()> {
+/// use java_lang::{TokenStream, module_declaration};
+/// let (_, tokens) = TokenStream::from_str("
+/// open module com.example.app {
+///     requires transitive java.sql;
+///     exports com.example.api to com.example.client;
+///     provides java.sql.Driver with com.example.MyDriver;
+/// }
+/// ")?;
+/// let (tokens, m) = module_declaration(tokens)?;
+/// assert!(m.open);
+/// assert_eq!(m.name, "com.example.app");
+/// assert_eq!(m.directives.len(), 3);
+/// assert!(tokens.is_empty());
+/// Ok(())
+/// }
+/// ```
+///
+/// # 错误处理
+///
+/// 如果解析过程中出现错误，将返回一个解析错误。
+pub fn module_declaration<'a>(tokens: TokenStream) -> IResult<TokenStream, ModuleDeclaration<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, documentation) = opt(documentation_comment).parse(tokens)?;
+    let (tokens, annotations) = many0(annotation).parse(tokens)?;
+    let (tokens, open) = opt(keyword(OPEN)).parse(tokens)?;
+    let (tokens, _) = keyword(MODULE)(tokens)?;
+    let (tokens, name) = qualified_name(tokens)?;
+    let (tokens, _) = tag(ts![LeftBrace]).parse(tokens)?;
+    let (tokens, directives) = many0(module_directive).parse(tokens)?;
+    let (tokens, _) = tag(ts![RightBrace]).parse(tokens)?;
+    tokens.finish_node(start, SyntaxKind::ModuleDeclaration);
+
+    Ok((
+        tokens,
+        ModuleDeclaration {
+            name,
+            annotations,
+            directives,
+            open: open.is_some(),
+            documentation,
+            comments: Default::default(),
+        },
+    ))
+}
+
+/// 解析模块声明中的一条指令，例如`requires static lombok;`。
+pub fn module_directive<'a>(tokens: TokenStream) -> IResult<TokenStream, ModuleDirective<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, directive) = match tokens.first() {
+        Some(Token::Identifier(i)) if i == REQUIRES => requires(tokens.take_from(1))?,
+        Some(Token::Identifier(i)) if i == EXPORTS || i == OPENS => {
+            let exports = i == EXPORTS;
+            let (tokens, package) = qualified_name(tokens.take_from(1))?;
+            let (tokens, to) = opt(preceded(
+                keyword(TO),
+                separated_list1(tag(ts![Comma]), qualified_name),
+            ))
+            .parse(tokens)?;
+            let to = to.unwrap_or_default();
+            let directive = if exports {
+                ModuleDirective::Exports { package, to }
+            } else {
+                ModuleDirective::Opens { package, to }
+            };
+            (tokens, directive)
+        }
+        Some(Token::Identifier(i)) if i == USES => {
+            let (tokens, name) = qualified_name(tokens.take_from(1))?;
+            (tokens, ModuleDirective::Uses(name))
+        }
+        Some(Token::Identifier(i)) if i == PROVIDES => {
+            let (tokens, service) = qualified_name(tokens.take_from(1))?;
+            let (tokens, with) = preceded(
+                keyword(WITH),
+                separated_list1(tag(ts![Comma]), qualified_name),
+            )
+            .parse(tokens)?;
+            (tokens, ModuleDirective::Provides { service, with })
+        }
+        _ => return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Alt))),
+    };
+    let (tokens, _) = tag(ts![SemiColon]).parse(tokens)?;
+    tokens.finish_node(start, SyntaxKind::ModuleDirective);
+
+    Ok((tokens, directive))
+}
+
+fn requires<'a>(mut tokens: TokenStream) -> IResult<TokenStream, ModuleDirective<'a>> {
+    let mut modifiers = Vec::new();
+    loop {
+        // `requires transitive;`中的`transitive`是模块名称
+        let modifier = match tokens.as_slice() {
+            [Token::Static, ..] => RequiresModifier::Static,
+            [Token::Identifier(i), Token::Identifier(_) | Token::Static, ..]
+                if i == RequiresModifier::TRANSITIVE =>
+            {
+                RequiresModifier::Transitive
+            }
+            _ => break,
+        };
+        modifiers.push(modifier);
+        tokens = tokens.take_from(1);
+    }
+    let (tokens, name) = qualified_name(tokens)?;
+
+    Ok((tokens, ModuleDirective::Requires { modifiers, name }))
+}
+
+/// 匹配一个上下文关键字，例如`module`、`to`、`with`，它们在词法上是标识符。
+fn keyword(word: &'static str) -> impl Fn(TokenStream) -> IResult<TokenStream, ()> {
+    move |tokens: TokenStream| match tokens.first() {
+        Some(Token::Identifier(i)) if i == word => Ok((tokens.take_from(1), ())),
+        _ => Err(nom::Err::Error(Error::new(tokens, ErrorKind::Tag))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_declaration() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str(
+            "
+            /** 应用模块 */
+            @Deprecated(since = \"9\")
+            module com.example {
+                requires java.base;
+                requires static transitive lombok;
+                requires transitive;
+                requires transitive static b;
+                exports com.example.api;
+                opens com.example.model to jackson.databind, gson;
+                uses com.example.spi.Plugin;
+                provides com.example.spi.Plugin with com.example.impl.A, com.example.impl.B;
+            }
+            ",
+        )?;
+        let (tokens, m) = module_declaration(tokens.without_comments())?;
+        assert!(tokens.is_empty());
+        assert!(!m.open);
+        assert!(m.documentation.is_some());
+        assert_eq!(m.annotations.len(), 1);
+        assert_eq!(
+            m.directives[1],
+            ModuleDirective::Requires {
+                modifiers: vec![RequiresModifier::Static, RequiresModifier::Transitive],
+                name: "lombok".into(),
+            }
+        );
+        assert_eq!(
+            m.directives[2],
+            ModuleDirective::Requires {
+                modifiers: vec![],
+                name: "transitive".into(),
+            }
+        );
+        assert_eq!(
+            m.directives[3],
+            ModuleDirective::Requires {
+                modifiers: vec![RequiresModifier::Transitive, RequiresModifier::Static],
+                name: "b".into(),
+            }
+        );
+        assert_eq!(
+            m.directives[5].to_string(),
+            "opens com.example.model to jackson.databind, gson;"
+        );
+        assert!(matches!(
+            &m.directives[7],
+            ModuleDirective::Provides { with, .. } if with.len() == 2
+        ));
+
+        Ok(())
+    }
+}
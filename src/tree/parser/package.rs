@@ -1,6 +1,6 @@
-use super::{super::PackageDeclaration, identifier, documentation_comment};
-use crate::{ts, Token, TokenStream};
-use nom::{bytes::tag, combinator::opt, multi::separated_list1, IResult, Parser};
+use super::{super::PackageDeclaration, identifier, documentation_comment, tag};
+use crate::{ts, SyntaxKind, Token, TokenStream};
+use nom::{combinator::opt, multi::separated_list1, IResult, Parser};
 use std::borrow::Cow;
 
 /// 解析包声明从给定的标记流中。
@@ -43,10 +43,12 @@ use std::borrow::Cow;
 pub fn package_declaration<'a>(
     tokens: TokenStream,
 ) -> IResult<TokenStream, PackageDeclaration<'a>> {
+    let start = tokens.source_offset();
     let (tokens, documentation) = opt(documentation_comment).parse(tokens)?;
     let (tokens, _) = tag(ts![Package]).parse(tokens)?;
     let (tokens, idents) = separated_list1(tag(ts![Dot]), identifier).parse(tokens)?;
     let (tokens, _) = tag(ts![SemiColon]).parse(tokens)?;
+    tokens.finish_node(start, SyntaxKind::PackageDeclaration);
     let name = idents
         .into_iter()
         .map(|i| i.to_string())
@@ -58,6 +60,7 @@ pub fn package_declaration<'a>(
             name: Cow::Owned(name),
             modifiers: Default::default(),
             documentation,
+            comments: Default::default(),
         },
     ))
 }
@@ -76,13 +79,14 @@ mod tests {
         )?;
         assert!(!tokens.is_empty());
 
-        let (tokens, package) = package_declaration(tokens)?;
+        let (tokens, package) = package_declaration(tokens.without_comments())?;
         assert_eq!(
             package,
             PackageDeclaration {
                 name: "com.test".into(),
                 modifiers: Default::default(),
-                documentation: Some(" 定义包 ".into())
+                documentation: Some(" 定义包 ".into()),
+                comments: Default::default(),
             }
         );
 
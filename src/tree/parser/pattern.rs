@@ -0,0 +1,118 @@
+use super::{
+    super::{Annotation, InstanceOfTarget, Modifier, Pattern, Type},
+    identifier, modifiers, nested, r#type, tag,
+};
+use crate::{ts, SyntaxKind, Token, TokenStream};
+use nom::{
+    error::{Error, ErrorKind},
+    multi::separated_list0,
+    IResult, Input, Parser,
+};
+use std::borrow::Cow;
+
+const UNNAMED: &str = "_";
+
+/// 解析模式
+///
+/// 该函数解析一个类型模式（例如`String s`）、记录模式（例如`Point(var x, _)`）或未命名模式`_`，
+/// 并返回解析后的模式和剩余的标记流。
+///
+/// # 参数
+///
+/// * `tokens` - 标记流，包含待解析的标记。
+///
+/// # 返回值
+///
+/// 返回一个 `IResult`，其中包含解析后剩余的标记流和模式。
+///
+/// # 示例
+///
+/// ```rust
+/// fn main() -> anyhow::Result<()> {
+/// use java_lang::{TokenStream, pattern, Pattern};
+/// let (_, tokens) = TokenStream::from_str("Line(Point(var x, _), Point p)")?;
+/// let (tokens, p) = pattern(tokens)?;
+/// let Pattern::Record { patterns, .. } = p else { panic!() };
+/// assert!(matches!(&patterns[0], Pattern::Record { patterns, .. } if patterns[1] == Pattern::Unnamed));
+/// assert!(tokens.is_empty());
+/// Ok(())
+/// }
+/// ```
+///
+/// # 错误处理
+///
+/// 如果解析过程中出现错误，将返回一个解析错误。
+pub fn pattern<'a>(tokens: TokenStream) -> IResult<TokenStream, Pattern<'a>> {
+    nested(tokens, |tokens| {
+        let start = tokens.source_offset();
+        if let [Token::Identifier(i), Token::Comma | Token::RightParen, ..] = tokens.as_slice() {
+            if i == UNNAMED {
+                let tokens = tokens.take_from(1);
+                tokens.finish_node(start, SyntaxKind::UnnamedPattern);
+                return Ok((tokens, Pattern::Unnamed));
+            }
+        }
+        let (tokens, (annotations, modifiers)) = modifiers(tokens)?;
+        let (tokens, t) = r#type(tokens)?;
+        let (tokens, p) = pattern_rest(tokens, annotations, modifiers, t)?;
+        tokens.finish_node_for(start, &p);
+        Ok((tokens, p))
+    })
+}
+
+/// 解析`instanceof`右边的类型或模式。
+pub(crate) fn instanceof_target<'a>(
+    tokens: TokenStream,
+) -> IResult<TokenStream, InstanceOfTarget<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, (annotations, modifiers)) = modifiers(tokens)?;
+    let (tokens, t) = r#type(tokens)?;
+    if !is_primitive_allowed(&tokens, &t) {
+        return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+    }
+    match tokens.first() {
+        Some(Token::LeftParen | Token::Identifier(_)) => {
+            let (tokens, p) = pattern_rest(tokens, annotations, modifiers, t)?;
+            tokens.finish_node_for(start, &p);
+            Ok((tokens, InstanceOfTarget::Pattern(p)))
+        }
+        _ if annotations.is_empty() && modifiers.is_empty() => {
+            Ok((tokens, InstanceOfTarget::Type(t)))
+        }
+        _ => Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify))),
+    }
+}
+
+/// `instanceof`和`switch`中的顶层模式使用基本类型是预览特性（记录模式的组件除外）。
+pub(crate) fn is_primitive_allowed(tokens: &TokenStream, t: &Type) -> bool {
+    !matches!(t, Type::Basic(_)) || tokens.options().preview
+}
+
+/// 解析类型后面的部分：记录模式的组件或类型模式的变量名。
+fn pattern_rest<'a>(
+    tokens: TokenStream,
+    annotations: Vec<Annotation<'a>>,
+    modifiers: Vec<Modifier>,
+    t: Type<'a>,
+) -> IResult<TokenStream, Pattern<'a>> {
+    match (tokens.first(), t) {
+        (Some(Token::LeftParen), Type::Reference(r#type)) => {
+            let (tokens, _) = tag(ts![LeftParen]).parse(tokens)?;
+            let (tokens, patterns) = separated_list0(tag(ts![Comma]), pattern).parse(tokens)?;
+            let (tokens, _) = tag(ts![RightParen]).parse(tokens)?;
+            Ok((tokens, Pattern::Record { r#type, patterns }))
+        }
+        (_, r#type) => {
+            let (tokens, name) = identifier(tokens)?;
+            Ok((
+                tokens,
+                Pattern::Type {
+                    modifiers,
+                    annotations,
+                    r#type,
+                    name: Cow::Owned(name.to_string()),
+                },
+            ))
+        }
+    }
+}
@@ -0,0 +1,404 @@
+use super::super::ErrorNode;
+use crate::{Expectation, MissingToken, SyntaxKind, Token, TokenStream};
+use nom::{
+    error::{Error, ErrorKind},
+    IResult, Input,
+};
+
+/// 恢复解析时可以补上的缺失标记，它们通常是一个节点的结尾。
+const MISSING: [Token; 4] = [
+    Token::SemiColon,
+    Token::RightParen,
+    Token::RightBracket,
+    Token::RightBrace,
+];
+
+/// 在恢复模式下解析一个节点（语句、成员声明或顶层声明），没有启用恢复模式时等同于直接调用`parser`。
+///
+/// 解析失败时，先尝试在最靠后的失败位置补上缺失的结尾标记（例如`;`）后重新解析（见[`insert_missing`]）；
+/// 仍然失败则跳过标记直到同步点，把跳过的标记作为[`ErrorNode`]交给`error`转换成节点。
+/// 同步点是最外层的`;`之后、最外层的`{ ... }`之后、不匹配的`}`之前，以及满足`sync`的标记（例如声明的开头）之前。
+/// 两种情况都会记录一个语法错误。
+pub(crate) fn recover<'a, T>(
+    tokens: TokenStream,
+    parser: impl Fn(TokenStream) -> IResult<TokenStream, T>,
+    sync: impl Fn(&Token) -> bool,
+    error: impl Fn(ErrorNode<'a>) -> T,
+) -> IResult<TokenStream, T> {
+    if !tokens.is_recovering() {
+        return parser(tokens);
+    }
+    let failure = match parse_or_insert(&tokens, &parser)? {
+        Ok(out) => return Ok(out),
+        Err(failure) => failure,
+    };
+
+    tokens.report(failure.unwrap_or_else(|| Expectation {
+        remaining: tokens.input_len(),
+        expected: Vec::new(),
+        found: tokens.first().map(|t| (t.clone(), tokens.info().cloned())),
+        missing: Vec::new(),
+    }));
+    let (rest, skipped) = tokens.take_split(skip_count(&tokens, sync));
+    let last = skipped.input_len().checked_sub(1);
+    let span = match (skipped.info(), last.and_then(|i| skipped.info_at(i))) {
+        (Some(first), Some(last)) => Some(first.span.start..last.span.end),
+        _ => None,
+    };
+    let node = ErrorNode {
+        tokens: skipped.iter().cloned().collect(),
+        span,
+        comments: Default::default(),
+    };
+    rest.finish_node(tokens.source_offset(), SyntaxKind::Error);
+
+    Ok((rest, error(node)))
+}
+
+/// 在恢复模式下解析一个节点，失败时只尝试补上缺失的结尾标记，不跳过标记；
+/// 没有启用恢复模式时等同于直接调用`parser`。
+///
+/// 用于无法用错误节点代替的节点，例如导入声明，无法补全时返回错误。
+pub(crate) fn insert_missing<T>(
+    tokens: TokenStream,
+    parser: impl Fn(TokenStream) -> IResult<TokenStream, T>,
+) -> IResult<TokenStream, T> {
+    if !tokens.is_recovering() {
+        return parser(tokens);
+    }
+    match parse_or_insert(&tokens, &parser)? {
+        Ok(out) => Ok(out),
+        Err(_) => Err(nom::Err::Error(Error::new(tokens, ErrorKind::Fail))),
+    }
+}
+
+/// 补全解析的结果：成功时是剩余的标记流和节点，无法补全时是最初的失败位置上期望的内容。
+type Attempt<T> = Result<(TokenStream, T), Option<Expectation>>;
+
+/// 调用`parser`解析，失败时在最靠后的失败位置补上缺失的结尾标记后重新解析，成功时记录一个语法错误和补上的标记。
+///
+/// 输入的末尾可能同时缺少多个结尾标记（例如`class A { void f() {`缺少两个`}`），
+/// 所以补上标记后仍然在末尾失败时继续补，补上的标记数量不超过未闭合的括号数量加一。
+fn parse_or_insert<T>(
+    tokens: &TokenStream,
+    parser: &impl Fn(TokenStream) -> IResult<TokenStream, T>,
+) -> Result<Attempt<T>, nom::Err<Error<TokenStream>>> {
+    let checkpoint = tokens.diagnostics_len();
+    tokens.reset_expectation();
+    let failure = match parser(tokens.clone()) {
+        Ok(out) => {
+            tokens.reset_expectation();
+            return Ok(Ok(out));
+        }
+        // 超过限制或遇到字面量错误时不再恢复，直接终止解析
+        Err(e) if matches!(e, nom::Err::Incomplete(_)) || tokens.fatal().is_some() => {
+            return Err(e)
+        }
+        Err(_) => tokens.expectation(),
+    };
+    tokens.truncate_diagnostics(checkpoint);
+    tokens.reset_expectation();
+
+    let Some(first) = failure
+        .clone()
+        .filter(|e| e.remaining <= tokens.input_len())
+    else {
+        return Ok(Err(failure));
+    };
+    let mut current = tokens.clone();
+    let mut expectation = first.clone();
+    let mut missing = Vec::new();
+    loop {
+        let index = current.input_len() - expectation.remaining;
+        let mut next = None;
+        for token in MISSING {
+            if !expectation.expected.contains(&format!("`{}`", token)) {
+                continue;
+            }
+            let inserted = current.insert(index, token.clone());
+            let token = MissingToken {
+                token,
+                offset: inserted.info_at(index).map(|i| i.span.start),
+            };
+            match parser(inserted.clone()) {
+                Err(e) if tokens.fatal().is_some() => return Err(e),
+                // 补上的标记必须被消费，否则它对解析没有帮助
+                Ok((rest, out)) if rest.input_len() <= expectation.remaining => {
+                    tokens.reset_expectation();
+                    missing.push(token);
+                    tokens.report(Expectation { missing, ..first });
+                    return Ok(Ok((rest, out)));
+                }
+                Err(nom::Err::Error(_)) if expectation.remaining == 0 && next.is_none() => {
+                    // 在末尾补上标记后仍然在末尾失败，可能还缺少外层的结尾标记
+                    if let Some(e) = tokens.expectation().filter(|e| e.remaining == 0) {
+                        next = Some((inserted, e, token));
+                    }
+                }
+                _ => {}
+            }
+            tokens.truncate_diagnostics(checkpoint);
+            tokens.reset_expectation();
+        }
+        match next {
+            Some((inserted, e, token)) if missing.len() < unclosed(tokens) => {
+                current = inserted;
+                expectation = e;
+                missing.push(token);
+            }
+            _ => return Ok(Err(failure)),
+        }
+    }
+}
+
+/// 计算未闭合的括号数量。
+fn unclosed(tokens: &TokenStream) -> usize {
+    tokens.iter().fold(0, |depth, token| match token {
+        Token::LeftParen | Token::LeftBracket | Token::LeftBrace => depth + 1,
+        Token::RightParen | Token::RightBracket | Token::RightBrace => depth.saturating_sub(1),
+        _ => depth,
+    })
+}
+
+/// 计算需要跳过的标记数量，至少跳过一个标记。
+fn skip_count(tokens: &TokenStream, sync: impl Fn(&Token) -> bool) -> usize {
+    let mut depth = 0usize;
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::LeftBrace => depth += 1,
+            Token::RightBrace if depth == 0 => return i.max(1),
+            Token::RightBrace => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            Token::SemiColon if depth == 0 => return i + 1,
+            t if depth == 0 && i > 0 && sync(t) => return i,
+            _ => {}
+        }
+    }
+
+    tokens.input_len()
+}
+
+/// 是否为成员声明的开头，用作类体中的同步点。
+pub(crate) fn is_member_start(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Public
+            | Token::Protected
+            | Token::Private
+            | Token::Static
+            | Token::Abstract
+            | Token::Final
+            | Token::Native
+            | Token::Synchronized
+            | Token::Transient
+            | Token::Volatile
+            | Token::Strictfp
+            | Token::Class
+            | Token::Interface
+            | Token::Enum
+            | Token::Void
+            | Token::At
+    )
+}
+
+/// 是否为顶层声明的开头，用作编译单元中的同步点。
+pub(crate) fn is_top_level_start(token: &Token) -> bool {
+    matches!(token, Token::Import | Token::Package) || is_member_start(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        parse_str_recovering, ClassBodyDeclaration, CompilationUnitDeclaration, ParseErrorKind,
+        Statement, Token, TokenStream, TopLevelClassOrInterfaceDeclaration,
+    };
+    use nom::Input;
+
+    #[test]
+    fn test_recover() {
+        // 没有错误时和普通解析的结果相同
+        let source = "package a;\nclass A { void f() { int a = 1; } }";
+        let result = parse_str_recovering(source, Default::default());
+        assert!(result.is_ok());
+        assert_eq!(result.unit, crate::parse_str(source).unwrap());
+
+        let result = parse_str_recovering(
+            "class A {\n  void f() {\n    foo(;\n    int a = # 1;\n    bar()\n  }\n  int x\n",
+            Default::default(),
+        );
+        let errors: Vec<_> = result
+            .diagnostics
+            .iter()
+            .map(|e| (e.kind(), e.line(), e.column()))
+            .collect();
+        assert_eq!(
+            errors,
+            [
+                (ParseErrorKind::Syntax, 3, 9),
+                (ParseErrorKind::Lexical, 4, 13),
+                (ParseErrorKind::Syntax, 6, 3),
+                (ParseErrorKind::Syntax, 7, 8),
+                (ParseErrorKind::Syntax, 7, 8),
+            ]
+        );
+        assert_eq!(
+            result.diagnostics[3].to_string(),
+            "第7行第8列：期望`[`、`=`、`,`或`;`，但遇到了输入结束"
+        );
+        let CompilationUnitDeclaration::Ordinary {
+            top_level_class_or_interfaces,
+            ..
+        } = result.unit
+        else {
+            panic!();
+        };
+        let TopLevelClassOrInterfaceDeclaration::Class(ref class) =
+            top_level_class_or_interfaces[0]
+        else {
+            panic!();
+        };
+        let [ClassBodyDeclaration::Method(method), ClassBodyDeclaration::Field(field)] =
+            &class.body.declarations[..]
+        else {
+            panic!("{:?}", class.body.declarations);
+        };
+        // 补上的标记和它们在源代码中的位置
+        let missing: Vec<_> = result
+            .missing
+            .iter()
+            .map(|m| (m.token.clone(), m.offset))
+            .collect();
+        assert_eq!(
+            missing,
+            [
+                (Token::RightParen, Some(31)),
+                (Token::SemiColon, Some(62)),
+                (Token::SemiColon, Some(71)),
+                (Token::RightBrace, Some(71)),
+            ]
+        );
+        let statements = &method.body.as_ref().unwrap().statements;
+        // 补上缺少的`)`
+        assert_eq!(statements[0].to_string(), "foo();");
+        assert!(matches!(statements[1], Statement::LocalVariable(_)));
+        assert!(matches!(statements[2], Statement::Expression(_)));
+        assert_eq!(field.to_string(), "int x;");
+
+        // 无法补全的语句跳过到`;`之后
+        let result =
+            parse_str_recovering("class A { void f() { int = 2; g(); } }", Default::default());
+        assert_eq!(result.diagnostics.len(), 1);
+        let text = result.unit.to_string();
+        assert!(text.contains("int = 2 ;\ng();"), "{}", text);
+
+        // 顶层无法解析的标记跳过到下一个声明的开头
+        let result = parse_str_recovering("} int; class B {}", Default::default());
+        assert_eq!(result.diagnostics.len(), 2);
+        let CompilationUnitDeclaration::Ordinary {
+            top_level_class_or_interfaces,
+            ..
+        } = result.unit
+        else {
+            panic!();
+        };
+        let [TopLevelClassOrInterfaceDeclaration::Error(a), TopLevelClassOrInterfaceDeclaration::Error(b), TopLevelClassOrInterfaceDeclaration::Class(_)] =
+            &top_level_class_or_interfaces[..]
+        else {
+            panic!("{:?}", top_level_class_or_interfaces);
+        };
+        assert_eq!((a.to_string(), a.span.clone()), ("}".into(), Some(0..1)));
+        assert_eq!(b.to_string(), "int ;");
+    }
+
+    #[test]
+    fn test_insert_at_end() {
+        // 末尾缺少多个`}`时全部补上，只记录一个语法错误
+        let source = "class A { void f() {";
+        let result = parse_str_recovering(source, Default::default());
+        let errors: Vec<_> = result.diagnostics.iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors, ["第1行第21列：期望`}`，但遇到了输入结束"]);
+        let missing: Vec<_> = result
+            .missing
+            .iter()
+            .map(|m| (m.token.clone(), m.offset))
+            .collect();
+        assert_eq!(
+            missing,
+            [(Token::RightBrace, Some(20)), (Token::RightBrace, Some(20))]
+        );
+        assert_eq!(result.unit.to_string(), "class A {\nvoid f() {\n}\n}\n");
+
+        // 导入声明缺少`;`时补上，错误报告在期望`;`的位置
+        let result = parse_str_recovering("import a.b\nclass A {}", Default::default());
+        let errors: Vec<_> = result.diagnostics.iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors, ["第2行第1列：期望`.`或`;`，但遇到了`class`"]);
+        assert_eq!(result.missing[0].token, Token::SemiColon);
+        let CompilationUnitDeclaration::Ordinary {
+            imports,
+            top_level_class_or_interfaces,
+            ..
+        } = &result.unit
+        else {
+            panic!();
+        };
+        assert_eq!(imports.len(), 1);
+        assert!(matches!(
+            top_level_class_or_interfaces[..],
+            [TopLevelClassOrInterfaceDeclaration::Class(_)]
+        ));
+    }
+
+    #[test]
+    fn test_insert() {
+        let source = "a /* x */ b c d e f g h i j k l m n o p q r s t u v w x y z";
+        let (_, tokens) = TokenStream::from_str(source).unwrap();
+        let tokens = tokens.without_comments();
+        let text = |tokens: &TokenStream| {
+            tokens
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        // 插入的标记之后的标记仍然共享，切分后和一次性构造的结果相同
+        let inserted = tokens.take_from(1).insert(2, Token::SemiColon);
+        assert_eq!(inserted.input_len(), 26);
+        assert_eq!(text(&inserted.take(4)), "b c ; d");
+        assert_eq!(
+            &inserted.as_slice()[..4],
+            inserted.take(4).iter().cloned().collect::<Vec<_>>()
+        );
+        // 开头连续存储的部分包括插入的标记之后的几个标记，用于向后查看
+        assert!(inserted.as_slice().len() > 4);
+        assert_eq!(inserted.info_at(2).map(|i| i.span.clone()), Some(14..14));
+        assert_eq!(inserted.comments_at(0).len(), 1);
+        assert!(inserted.comments_at(2).is_empty());
+        for i in 0..=inserted.input_len() {
+            let rest = inserted.take_from(i);
+            assert_eq!(rest.input_len(), 26 - i);
+            assert_eq!(rest.iter().count(), 26 - i);
+            assert_eq!(rest.first(), inserted.get(i));
+            assert_eq!(
+                rest.iter_elements().next_back(),
+                (i < 26).then_some(Token::Identifier("z".into()))
+            );
+        }
+
+        // 在插入过标记的标记流中再次插入和替换
+        let twice = inserted
+            .insert(5, Token::RightParen)
+            .replace_first(Token::Comma);
+        assert_eq!(text(&twice.take(8)), ", c ; d e ) f g");
+        assert_eq!(text(&twice.take_from(20)), "t u v w x y z");
+        assert_eq!(twice.info().map(|i| i.span.clone()), Some(10..11));
+        assert_eq!(
+            twice.take_from(6).info().map(|i| i.span.clone()),
+            Some(18..19)
+        );
+    }
+}
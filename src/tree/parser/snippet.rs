@@ -0,0 +1,385 @@
+use super::super::{Snippet, SnippetAttribute, SnippetLine, SnippetMarkup, SnippetMarkupKind};
+use std::{
+    borrow::Cow,
+    fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+/// 解析`{@snippet ...}`标签中`@snippet`后面的内容：属性和可选的`:`之后的内联代码片段。
+pub(crate) fn snippet<'a>(content: &str) -> Snippet<'a> {
+    let (attributes, rest) = attributes(content);
+    let body = rest.strip_prefix(':').map(snippet_body);
+
+    Snippet { attributes, body }
+}
+
+/// 解析代码片段
+///
+/// 该函数解析内联代码片段或外部代码片段文件的内容，去掉公共缩进和首尾的空行，
+/// 并把`// @start`、`// @end`、`// @highlight`、`// @replace`和`// @link`等标记注释从代码中分离出来。
+/// 以`:`结尾的标记注释作用于下一行；单独占一行的标记注释所在的行会被去掉，
+/// 其中的`@end`作用于上一行，其他标记作用于下一行。
+///
+/// # 参数
+///
+/// * `source` - 代码片段的源代码。
+///
+/// # 返回值
+///
+/// 返回代码片段中的各行。
+///
+/// # 示例
+///
+/// ```rust
+/// use java_lang::{snippet_body, SnippetMarkupKind};
+/// let lines = snippet_body("
+///     // @start region=main
+///     System.out.println(\"Hello\"); // @highlight substring=\"println\"
+///     // @end
+/// ");
+/// assert_eq!(lines.len(), 1);
+/// assert_eq!(lines[0].text, "System.out.println(\"Hello\");");
+/// assert_eq!(lines[0].markup[0].kind, SnippetMarkupKind::Start);
+/// assert_eq!(lines[0].markup[1].attribute("substring"), Some("println"));
+/// assert_eq!(lines[0].markup[2].kind, SnippetMarkupKind::End);
+/// ```
+pub fn snippet_body<'a>(source: &str) -> Vec<SnippetLine<'a>> {
+    let raw = source.lines().collect::<Vec<_>>();
+    let first = raw.iter().position(|l| !l.trim().is_empty());
+    let last = raw.iter().rposition(|l| !l.trim().is_empty());
+    let (Some(first), Some(last)) = (first, last) else {
+        return Vec::new();
+    };
+    let raw = &raw[first..=last];
+    let indent = raw
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    let mut lines: Vec<SnippetLine<'a>> = Vec::new();
+    let mut pending = Vec::new();
+    for line in raw {
+        let line = line.get(indent..).unwrap_or(line.trim_start());
+        let Some((text, markup, next_line)) = markup_comment(line) else {
+            lines.push(SnippetLine {
+                text: Cow::Owned(line.trim_end().to_string()),
+                markup: std::mem::take(&mut pending),
+            });
+            continue;
+        };
+        if next_line {
+            pending.extend(markup);
+        } else if text.is_empty() {
+            // 单独占一行的标记注释不出现在输出中
+            for m in markup {
+                match (m.kind, lines.last_mut()) {
+                    (SnippetMarkupKind::End, Some(previous)) => previous.markup.push(m),
+                    _ => pending.push(m),
+                }
+            }
+        } else {
+            pending.extend(markup);
+            lines.push(SnippetLine {
+                text: Cow::Owned(text.to_string()),
+                markup: std::mem::take(&mut pending),
+            });
+        }
+    }
+
+    lines
+}
+
+/// 从一行代码中分离标记注释，返回代码、标记以及标记是否作用于下一行（以`:`结尾）。
+fn markup_comment<'a>(line: &str) -> Option<(&str, Vec<SnippetMarkup<'a>>, bool)> {
+    let mut from = 0;
+    while let Some(pos) = line[from..].find("//") {
+        let start = from + pos;
+        if let Some((markup, next_line)) = markup_tags(&line[start + 2..]) {
+            return Some((line[..start].trim_end(), markup, next_line));
+        }
+        from = start + 2;
+    }
+    None
+}
+
+/// 解析`//`后面的一个或多个标记标签，注释中包含其他内容时不是标记注释。
+fn markup_tags<'a>(mut s: &str) -> Option<(Vec<SnippetMarkup<'a>>, bool)> {
+    let mut markup = Vec::new();
+    loop {
+        s = s.trim_start();
+        if s.is_empty() {
+            return (!markup.is_empty()).then_some((markup, false));
+        }
+        if s == ":" {
+            return (!markup.is_empty()).then_some((markup, true));
+        }
+        let rest = s.strip_prefix('@')?;
+        let end = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let kind = SnippetMarkupKind::from_name(&rest[..end])?;
+        let (attributes, rest) = attributes(&rest[end..]);
+        markup.push(SnippetMarkup { kind, attributes });
+        s = rest;
+    }
+}
+
+/// 解析`name=value`形式的属性，在`:`、`@`或无法识别的内容处停止。
+fn attributes<'a>(mut s: &str) -> (Vec<SnippetAttribute<'a>>, &str) {
+    let mut attributes = Vec::new();
+    loop {
+        s = s.trim_start();
+        let end = s
+            .find(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_'))
+            .unwrap_or(s.len());
+        if end == 0 {
+            return (attributes, s);
+        }
+        let name = Cow::Owned(s[..end].to_string());
+        s = &s[end..];
+        let Some(rest) = s.trim_start().strip_prefix('=') else {
+            attributes.push(SnippetAttribute { name, value: None });
+            continue;
+        };
+        let rest = rest.trim_start();
+        let (value, rest) = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => match rest[1..].find(quote) {
+                Some(close) => (&rest[1..close + 1], &rest[close + 2..]),
+                None => (&rest[1..], ""),
+            },
+            _ => {
+                let end = rest
+                    .find(|c: char| c.is_whitespace() || c == ':')
+                    .unwrap_or(rest.len());
+                rest.split_at(end)
+            }
+        };
+        attributes.push(SnippetAttribute {
+            name,
+            value: Some(Cow::Owned(value.to_string())),
+        });
+        s = rest;
+    }
+}
+
+/// 提取代码片段中的区域
+///
+/// 区域从带有`region=name`属性的标记开始，到对应的`@end`（或`@end region=name`）结束，包括首尾两行。
+///
+/// # 参数
+///
+/// * `lines` - 代码片段中的各行。
+/// * `region` - 区域名称。
+///
+/// # 返回值
+///
+/// 返回区域中的各行，找不到区域时返回`None`。
+pub fn snippet_region<'a>(lines: &[SnippetLine<'a>], region: &str) -> Option<Vec<SnippetLine<'a>>> {
+    let start = lines.iter().position(|l| {
+        l.markup
+            .iter()
+            .any(|m| m.kind != SnippetMarkupKind::End && m.attribute("region") == Some(region))
+    })?;
+    let mut open = Vec::new();
+    for (i, line) in lines.iter().enumerate().skip(start) {
+        for m in &line.markup {
+            match (m.kind, m.attribute("region")) {
+                (SnippetMarkupKind::End, Some(name)) => open.retain(|o| *o != name),
+                (SnippetMarkupKind::End, None) => {
+                    open.pop();
+                }
+                // 开始行上只有目标区域，其他区域可能在它之前就已经开始
+                (_, Some(name)) if i > start || name == region => open.push(name),
+                _ => {}
+            }
+        }
+        if open.is_empty() {
+            return Some(lines[start..=i].to_vec());
+        }
+    }
+
+    Some(lines[start..].to_vec())
+}
+
+/// 获取代码片段的内容
+///
+/// 带有`file`或`class`属性的代码片段从外部文件读取：`file`是相对于`snippet_files`目录的路径，
+/// `class`是该目录中的类的全限定名，例如`pkg.Example`对应`pkg/Example.java`。
+/// 否则使用内联的代码片段。如果有`region`属性，则只返回该区域。
+///
+/// # 参数
+///
+/// * `snippet` - `{@snippet}`标签。
+/// * `snippet_files` - 存放外部代码片段文件的目录，通常是源文件所在包目录中的`snippet-files`目录。
+///
+/// # 返回值
+///
+/// 返回代码片段中的各行。
+///
+/// # 错误处理
+///
+/// 如果外部文件无法读取或找不到指定的区域，将返回一个IO错误。
+/// `file`或`class`属性是绝对路径或包含`..`，即可能指向`snippet_files`之外的文件时，
+/// 返回[`io::ErrorKind::InvalidInput`]错误。
+pub fn resolve_snippet<'a>(
+    snippet: &Snippet<'a>,
+    snippet_files: &Path,
+) -> io::Result<Vec<SnippetLine<'a>>> {
+    let path = match (snippet.attribute("file"), snippet.attribute("class")) {
+        (Some(file), _) => Some(snippet_path(snippet_files, file)?),
+        (None, Some(class)) => Some(snippet_path(
+            snippet_files,
+            &(class.replace('.', "/") + ".java"),
+        )?),
+        (None, None) => None,
+    };
+    let lines = match path {
+        Some(path) => snippet_body(&fs::read_to_string(path)?),
+        None => snippet.body.clone().unwrap_or_default(),
+    };
+    match snippet.attribute("region") {
+        Some(region) => snippet_region(&lines, region).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("找不到代码片段区域`{}`", region),
+            )
+        }),
+        None => Ok(lines),
+    }
+}
+
+/// 获取`snippet_files`目录中相对路径为`relative`的文件的路径，`relative`只能由普通的路径部分组成。
+fn snippet_path(snippet_files: &Path, relative: &str) -> io::Result<PathBuf> {
+    let path = Path::new(relative);
+    if !path
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("代码片段文件`{}`不在代码片段目录中", relative),
+        ));
+    }
+
+    Ok(snippet_files.join(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{javadoc, DocumentationComment, InlineTagKind};
+
+    #[test]
+    fn test_inline_snippet() {
+        let comment = DocumentationComment::from(
+            "
+     * Example:
+     * {@snippet lang=java id='greeting' :
+     *   class Main {
+     *       void main() {
+     *           // @highlight region substring=\"out\" type=italic :
+     *           System.out.println(\"hi\"); // @replace regex='\"hi\"' replacement=\"...\"
+     *           System.out.println(map.get(1)); // @link substring=\"get\" target=\"Map#get\"
+     *           // @end
+     *       }
+     *   }
+     * }
+     ",
+        );
+        let doc = javadoc(&comment);
+        let Some(InlineTagKind::Snippet(s)) = doc.inline_tags().next().map(|t| &t.kind) else {
+            panic!("{:?}", doc);
+        };
+        assert_eq!(s.attribute("lang"), Some("java"));
+        assert_eq!(s.attribute("id"), Some("greeting"));
+        let body = s.body.as_ref().unwrap();
+        assert_eq!(body.len(), 6);
+        assert_eq!(body[0].text, "class Main {");
+        assert_eq!(body[2].text, "        System.out.println(\"hi\");");
+        let kinds = body[2].markup.iter().map(|m| m.kind).collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![SnippetMarkupKind::Highlight, SnippetMarkupKind::Replace]
+        );
+        assert_eq!(body[2].markup[0].attribute("region"), Some(""));
+        assert_eq!(body[2].markup[1].attribute("regex"), Some("\"hi\""));
+        assert_eq!(body[3].markup[0].kind, SnippetMarkupKind::Link);
+        assert_eq!(body[3].markup[1].kind, SnippetMarkupKind::End);
+
+        // 普通注释不是标记注释
+        let lines = snippet_body("a(); // see @link\nb(); // TODO");
+        assert!(lines.iter().all(|l| l.markup.is_empty()));
+        assert_eq!(lines[1].text, "b(); // TODO");
+    }
+
+    #[test]
+    fn test_external_snippet() -> anyhow::Result<()> {
+        let dir = std::env::temp_dir().join(format!("snippet-files-{}", std::process::id()));
+        fs::create_dir_all(dir.join("pkg"))?;
+        fs::write(
+            dir.join("pkg/Example.java"),
+            "package pkg;
+            class Example {
+                void run() {
+                    // @start region=setup
+                    var list = new ArrayList<String>();
+                    // @start region=inner
+                    list.add(\"a\");
+                    // @end region=inner
+                    list.add(\"b\");
+                    // @end region=setup
+                    list.clear();
+                }
+            }
+            ",
+        )?;
+
+        let comment = DocumentationComment::from(
+            " {@snippet class=pkg.Example region=setup} {@snippet file=\"pkg/Example.java\" region=inner}",
+        );
+        let doc = javadoc(&comment);
+        let snippets = doc
+            .inline_tags()
+            .filter_map(|t| match &t.kind {
+                InlineTagKind::Snippet(s) => Some(s),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert!(snippets[0].body.is_none());
+
+        let setup = resolve_snippet(snippets[0], &dir)?;
+        let texts = setup.iter().map(|l| l.text.trim()).collect::<Vec<_>>();
+        assert_eq!(
+            texts,
+            vec![
+                "var list = new ArrayList<String>();",
+                "list.add(\"a\");",
+                "list.add(\"b\");"
+            ]
+        );
+        let inner = resolve_snippet(snippets[1], &dir)?;
+        assert_eq!(inner.len(), 1);
+        assert!(resolve_snippet(&snippet("region=missing :\n a();"), &dir).is_err());
+        assert!(resolve_snippet(&snippet("file=Missing.java"), &dir).is_err());
+
+        // 不能读取代码片段目录之外的文件
+        fs::write(dir.with_extension("java"), "secret();")?;
+        let name = dir.file_name().unwrap().to_string_lossy().into_owned();
+        for attributes in [
+            format!("file=\"../{}.java\"", name),
+            format!("file=\"pkg/../../{}.java\"", name),
+            format!("file=\"{}\"", dir.with_extension("java").display()),
+            "class=..pkg.Example".to_string(),
+            "class=/pkg.Example".to_string(),
+        ] {
+            let error = resolve_snippet(&snippet(&attributes), &dir).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidInput, "{}", attributes);
+        }
+        fs::remove_file(dir.with_extension("java"))?;
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,746 @@
+use super::{
+    super::{
+        AssignmentOperator, CatchClause, CatchClauseParameter, Comments, ConstructorInvocationKind,
+        ExplicitConstructorInvocation, Expression, ForControl, ForInit, LocalVariableDeclaration,
+        Statement, TryResource, TryStatement, UnaryOperator, VariableDeclarator,
+    },
+    arguments, block, expression, identifier, local_class_declaration, modifiers, nested, r#type,
+    reference_type, switch, syntax_node, tag, type_arguments, variable_declarator,
+    CommentCollector,
+};
+use crate::{ts, SyntaxKind, Token, TokenStream};
+use nom::{
+    combinator::opt,
+    error::{Error, ErrorKind},
+    multi::{separated_list0, separated_list1},
+    sequence::{delimited, preceded, terminated},
+    IResult, Input, Parser,
+};
+use std::borrow::Cow;
+
+const YIELD: &str = "yield";
+
+/// 解析代码块中的语句
+///
+/// 该函数解析代码块中的一条语句，除了普通语句外还可以是局部变量声明或局部类声明，
+/// 并返回解析后的语句和剩余的标记流。
+///
+/// # 参数
+///
+/// * `tokens` - 标记流，包含待解析的标记。
+///
+/// # 返回值
+///
+/// 返回一个 `IResult`，其中包含解析后剩余的标记流和语句。
+///
+/// # 示例
+///
+/// ```rust
+/// fn main() -> anyhow::Result<()> {
+/// use java_lang::{TokenStream, block_statement, Statement};
+/// let (_, tokens) = TokenStream::from_str("final var list = new ArrayList<String>();")?;
+/// let (tokens, s) = block_statement(tokens)?;
+/// let Statement::LocalVariable(d) = s else { panic!() };
+/// assert_eq!(d.r#type.to_string(), "var");
+/// assert!(tokens.is_empty());
+/// Ok(())
+/// }
+/// ```
+///
+/// # 错误处理
+///
+/// 如果解析过程中出现错误，将返回一个解析错误。
+pub fn block_statement<'a>(tokens: TokenStream) -> IResult<TokenStream, Statement<'a>> {
+    let start = tokens.source_offset();
+    if let Ok((tokens, d)) = local_class_declaration(tokens.clone()) {
+        tokens.finish_node_for(start, &d);
+        return Ok((tokens, Statement::LocalClass(Box::new(d))));
+    }
+    if is_yield(&tokens) {
+        return statement(tokens);
+    }
+    if let Ok((tokens, d)) =
+        terminated(local_variable_declaration, tag(ts![SemiColon])).parse(tokens.clone())
+    {
+        tokens.finish_node(start, SyntaxKind::LocalVariableStatement);
+        return Ok((tokens, Statement::LocalVariable(d)));
+    }
+    statement(tokens)
+}
+
+/// 解析一条语句，不包括局部变量声明和局部类声明（例如`if`语句的分支）。
+pub fn statement<'a>(tokens: TokenStream) -> IResult<TokenStream, Statement<'a>> {
+    nested(tokens, |tokens| {
+        let start = tokens.source_offset();
+        let (tokens, node) = match tokens.first() {
+            Some(Token::LeftBrace) => {
+                let (tokens, b) = block(tokens)?;
+                Ok((tokens, Statement::Block(b)))
+            }
+            Some(Token::SemiColon) => Ok((tokens.take_from(1), Statement::Empty)),
+            Some(Token::If) => if_statement(tokens),
+            Some(Token::While) => {
+                let (tokens, condition) = parenthesized(tokens.take_from(1))?;
+                let (tokens, (body, body_comments)) = body(tokens)?;
+                Ok((
+                    tokens,
+                    Statement::While {
+                        condition,
+                        body,
+                        body_comments,
+                    },
+                ))
+            }
+            Some(Token::Do) => {
+                let mut comments = CommentCollector::default();
+                let tokens = tokens.take_from(1);
+                comments.collect(&tokens, None);
+                let mut body_comments = comments.leading();
+                let (tokens, body) = statement(tokens)?;
+                // `while`前面的注释都作为循环体的尾随注释
+                comments.collect(&tokens, Some(&mut body_comments));
+                body_comments.trailing.extend(comments.dangling());
+                let (tokens, _) = tag(ts![While]).parse(tokens)?;
+                let (tokens, condition) = parenthesized(tokens)?;
+                let (tokens, _) = tag(ts![SemiColon]).parse(tokens)?;
+                Ok((
+                    tokens,
+                    Statement::Do {
+                        body: Box::new(body),
+                        condition,
+                        body_comments,
+                    },
+                ))
+            }
+            Some(Token::For) => {
+                let (tokens, control) =
+                    delimited(tag(ts![LeftParen]), for_control, tag(ts![RightParen]))
+                        .parse(tokens.take_from(1))?;
+                let (tokens, (body, body_comments)) = body(tokens)?;
+                Ok((
+                    tokens,
+                    Statement::For {
+                        control,
+                        body,
+                        body_comments,
+                    },
+                ))
+            }
+            Some(Token::Break) => {
+                let (tokens, label) = jump_label(tokens.take_from(1))?;
+                Ok((tokens, Statement::Break(label)))
+            }
+            Some(Token::Continue) => {
+                let (tokens, label) = jump_label(tokens.take_from(1))?;
+                Ok((tokens, Statement::Continue(label)))
+            }
+            Some(Token::Return) => {
+                let (tokens, e) =
+                    terminated(opt(expression), tag(ts![SemiColon])).parse(tokens.take_from(1))?;
+                Ok((tokens, Statement::Return(e)))
+            }
+            Some(Token::Throw) => {
+                let (tokens, e) =
+                    terminated(expression, tag(ts![SemiColon])).parse(tokens.take_from(1))?;
+                Ok((tokens, Statement::Throw(e)))
+            }
+            Some(Token::Assert) => {
+                let (tokens, condition) = expression(tokens.take_from(1))?;
+                let (tokens, message) = opt(preceded(tag(ts![Colon]), expression)).parse(tokens)?;
+                let (tokens, _) = tag(ts![SemiColon]).parse(tokens)?;
+                Ok((tokens, Statement::Assert { condition, message }))
+            }
+            Some(Token::Synchronized) => {
+                let (tokens, lock) = parenthesized(tokens.take_from(1))?;
+                let (tokens, block) = block(tokens)?;
+                Ok((tokens, Statement::Synchronized { lock, block }))
+            }
+            Some(Token::Switch) => {
+                let (tokens, s) = switch(tokens)?;
+                Ok((tokens, Statement::Switch(s)))
+            }
+            _ if is_yield(&tokens) => {
+                let (tokens, e) =
+                    terminated(expression, tag(ts![SemiColon])).parse(tokens.take_from(1))?;
+                Ok((tokens, Statement::Yield(e)))
+            }
+            Some(Token::Try) => {
+                let (tokens, t) = try_statement(tokens.take_from(1))?;
+                Ok((tokens, Statement::Try(t)))
+            }
+            Some(Token::Identifier(label))
+                if matches!(tokens.take_from(1).first(), Some(Token::Colon)) =>
+            {
+                let label = Cow::Owned(label.clone());
+                let (tokens, (statement, statement_comments)) = body(tokens.take_from(2))?;
+                Ok((
+                    tokens,
+                    Statement::Labeled {
+                        label,
+                        statement,
+                        statement_comments,
+                    },
+                ))
+            }
+            _ => {
+                let (remaining, e) = expression(tokens.clone())?;
+                if !is_statement_expression(&e) {
+                    return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+                }
+                let (remaining, _) = tag(ts![SemiColon]).parse(remaining)?;
+                Ok((remaining, Statement::Expression(e)))
+            }
+        }?;
+        tokens.finish_node_for(start, &node);
+        Ok((tokens, node))
+    })
+}
+
+/// 解析`while`、`for`或带标签的语句中的子语句，子语句前面的普通注释作为它的前导注释。
+fn body<'a>(tokens: TokenStream) -> IResult<TokenStream, (Box<Statement<'a>>, Comments<'a>)> {
+    let mut comments = CommentCollector::default();
+    comments.collect(&tokens, None);
+    let (tokens, statement) = statement(tokens)?;
+    Ok((tokens, (Box::new(statement), comments.leading())))
+}
+
+/// `yield`是上下文关键字，只有后面紧跟表达式时才是`yield`语句，否则是普通的标识符，例如`yield = 1;`。
+fn is_yield(tokens: &TokenStream) -> bool {
+    let [Token::Identifier(i), next, ..] = tokens.as_slice() else {
+        return false;
+    };
+    i == YIELD
+        && !matches!(
+            next,
+            Token::Dot
+                | Token::LeftBracket
+                | Token::SemiColon
+                | Token::Colon
+                | Token::DoubleColon
+                | Token::Arrow
+        )
+        && AssignmentOperator::try_from(next).is_err()
+}
+
+/// 只有赋值、自增自减、方法调用和类实例创建可以作为表达式语句。
+fn is_statement_expression(expression: &Expression) -> bool {
+    matches!(
+        expression,
+        Expression::Assignment { .. }
+            | Expression::Postfix { .. }
+            | Expression::Unary {
+                operator: UnaryOperator::Increment | UnaryOperator::Decrement,
+                ..
+            }
+            | Expression::MethodInvocation { .. }
+            | Expression::SuperMethodInvocation { .. }
+            | Expression::ClassCreation { .. }
+    )
+}
+
+/// 解析`if`语句。`else if`链在循环中逐个解析，分支再多也不会增加嵌套层数。
+///
+/// 分支前面的普通注释是分支的前导注释，`else`前面和`then_statement`同一行的注释是它的尾随注释。
+fn if_statement<'a>(mut tokens: TokenStream) -> IResult<TokenStream, Statement<'a>> {
+    let mut branches = Vec::new();
+    let mut else_statement = loop {
+        let start = tokens.source_offset();
+        let mut comments = CommentCollector::default();
+        let (rest, condition) = parenthesized(tokens.take_from(1))?;
+        comments.collect(&rest, None);
+        let mut then_comments = comments.leading();
+        let (rest, then_statement) = statement(rest)?;
+        if !matches!(rest.first(), Some(Token::Else)) {
+            branches.push((
+                start,
+                condition,
+                then_statement,
+                then_comments,
+                Comments::default(),
+            ));
+            tokens = rest;
+            break None;
+        }
+        comments.collect(&rest, Some(&mut then_comments));
+        let rest = rest.take_from(1);
+        comments.collect(&rest, None);
+        let else_comments = comments.leading();
+        branches.push((
+            start,
+            condition,
+            then_statement,
+            then_comments,
+            else_comments,
+        ));
+        if let Some(Token::If) = rest.first() {
+            tokens = rest;
+            continue;
+        }
+        let (rest, else_statement) = statement(rest)?;
+        tokens = rest;
+        break Some(else_statement);
+    };
+    // 从最后一个分支开始向前组装，最外层的`if`语句由调用方记录为语法节点
+    let (_, condition, then_statement, then_comments, else_comments) = branches.remove(0);
+    for (start, condition, then_statement, then_comments, else_comments) in
+        branches.into_iter().rev()
+    {
+        tokens.finish_node(start, SyntaxKind::IfStatement);
+        else_statement = Some(Statement::If {
+            condition,
+            then_statement: Box::new(then_statement),
+            else_statement: else_statement.map(Box::new),
+            then_comments,
+            else_comments,
+        });
+    }
+
+    Ok((
+        tokens,
+        Statement::If {
+            condition,
+            then_statement: Box::new(then_statement),
+            else_statement: else_statement.map(Box::new),
+            then_comments,
+            else_comments,
+        },
+    ))
+}
+
+fn parenthesized<'a>(tokens: TokenStream) -> IResult<TokenStream, Expression<'a>> {
+    delimited(tag(ts![LeftParen]), expression, tag(ts![RightParen])).parse(tokens)
+}
+
+fn jump_label<'a>(tokens: TokenStream) -> IResult<TokenStream, Option<Cow<'a, str>>> {
+    let (tokens, label) = terminated(opt(identifier), tag(ts![SemiColon])).parse(tokens)?;
+    Ok((tokens, label.map(|i| Cow::Owned(i.to_string()))))
+}
+
+/// 解析局部变量声明（不包括末尾的分号），例如`final int a = 1, b[]`。
+/// 类型后面必须紧跟变量名，否则不是局部变量声明。
+pub fn local_variable_declaration<'a>(
+    tokens: TokenStream,
+) -> IResult<TokenStream, LocalVariableDeclaration<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, (annotations, modifiers)) = modifiers(tokens)?;
+    let (tokens, r#type) = r#type(tokens)?;
+    let (tokens, declarators) =
+        separated_list1(tag(ts![Comma]), variable_declarator).parse(tokens)?;
+    tokens.finish_node(start, SyntaxKind::LocalVariableDeclaration);
+
+    Ok((
+        tokens,
+        LocalVariableDeclaration {
+            modifiers,
+            annotations,
+            r#type,
+            declarators,
+        },
+    ))
+}
+
+fn for_control<'a>(tokens: TokenStream) -> IResult<TokenStream, ForControl<'a>> {
+    if let Ok(out) = enhanced_for_control(tokens.clone()) {
+        return Ok(out);
+    }
+    let (tokens, init) = match tokens.first() {
+        Some(Token::SemiColon) => (tokens, ForInit::Expressions(Vec::new())),
+        _ => match local_variable_declaration(tokens.clone()) {
+            Ok((tokens, d)) => (tokens, ForInit::Declaration(d)),
+            Err(_) => {
+                let (tokens, e) = separated_list1(tag(ts![Comma]), expression).parse(tokens)?;
+                (tokens, ForInit::Expressions(e))
+            }
+        },
+    };
+    let (tokens, _) = tag(ts![SemiColon]).parse(tokens)?;
+    let (tokens, condition) = opt(expression).parse(tokens)?;
+    let (tokens, _) = tag(ts![SemiColon]).parse(tokens)?;
+    let (tokens, update) = separated_list0(tag(ts![Comma]), expression).parse(tokens)?;
+
+    Ok((
+        tokens,
+        ForControl::Basic {
+            init,
+            condition,
+            update,
+        },
+    ))
+}
+
+fn enhanced_for_control<'a>(tokens: TokenStream) -> IResult<TokenStream, ForControl<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, (annotations, modifiers)) = modifiers(tokens)?;
+    let (tokens, r#type) = r#type(tokens)?;
+    let (tokens, name) = identifier(tokens)?;
+    tokens.finish_node(start, SyntaxKind::LocalVariableDeclaration);
+    let (tokens, iterable) = preceded(tag(ts![Colon]), expression).parse(tokens)?;
+
+    Ok((
+        tokens,
+        ForControl::Enhanced {
+            variable: LocalVariableDeclaration {
+                modifiers,
+                annotations,
+                r#type,
+                declarators: vec![VariableDeclarator {
+                    name: Cow::Owned(name.to_string()),
+                    dimensions: 0,
+                    initializer: None,
+                }],
+            },
+            iterable,
+        },
+    ))
+}
+
+/// 解析`try`后面的资源、代码块、`catch`子句和`finally`块。
+fn try_statement<'a>(tokens: TokenStream) -> IResult<TokenStream, TryStatement<'a>> {
+    let (tokens, resources) = match tokens.first() {
+        Some(Token::LeftParen) => {
+            let (tokens, _) = tag(ts![LeftParen]).parse(tokens)?;
+            let (tokens, resources) = separated_list1(
+                tag(ts![SemiColon]),
+                syntax_node(SyntaxKind::TryResource, try_resource),
+            )
+            .parse(tokens)?;
+            // 最后一个资源后面可以有多余的分号
+            let (tokens, _) = opt(tag(ts![SemiColon])).parse(tokens)?;
+            let (tokens, _) = tag(ts![RightParen]).parse(tokens)?;
+            (tokens, resources)
+        }
+        _ => (tokens, Vec::new()),
+    };
+    let (tokens, try_block) = block(tokens)?;
+    let (mut tokens, mut catches) = (tokens, Vec::new());
+    while let Some(Token::Catch) = tokens.first() {
+        let start = tokens.source_offset();
+        let (remaining, c) = catch_clause(tokens.take_from(1))?;
+        remaining.finish_node(start, SyntaxKind::CatchClause);
+        catches.push(c);
+        tokens = remaining;
+    }
+    let (tokens, finally) = opt(preceded(tag(ts![Finally]), block)).parse(tokens)?;
+    if resources.is_empty() && catches.is_empty() && finally.is_none() {
+        return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+    }
+
+    Ok((
+        tokens,
+        TryStatement {
+            resources,
+            block: try_block,
+            catches,
+            finally,
+        },
+    ))
+}
+
+fn try_resource<'a>(tokens: TokenStream) -> IResult<TokenStream, TryResource<'a>> {
+    if let Ok(out) = resource_declaration(tokens.clone()) {
+        return Ok(out);
+    }
+    let (remaining, e) = expression(tokens.clone())?;
+    if !matches!(
+        e,
+        Expression::Name(_) | Expression::FieldAccess { .. } | Expression::This
+    ) {
+        return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+    }
+    Ok((remaining, TryResource::Variable(e)))
+}
+
+fn resource_declaration<'a>(tokens: TokenStream) -> IResult<TokenStream, TryResource<'a>> {
+    let (tokens, (annotations, modifiers)) = modifiers(tokens)?;
+    let (tokens, r#type) = r#type(tokens)?;
+    let (tokens, name) = identifier(tokens)?;
+    let (tokens, value) = preceded(tag(ts![Assign]), expression).parse(tokens)?;
+
+    Ok((
+        tokens,
+        TryResource::Declaration {
+            modifiers,
+            annotations,
+            r#type,
+            name: Cow::Owned(name.to_string()),
+            value,
+        },
+    ))
+}
+
+fn catch_clause<'a>(tokens: TokenStream) -> IResult<TokenStream, CatchClause<'a>> {
+    let (tokens, _) = tag(ts![LeftParen]).parse(tokens)?;
+    let (tokens, (annotations, modifiers)) = modifiers(tokens)?;
+    let (tokens, types) = separated_list1(tag(ts![Or]), reference_type).parse(tokens)?;
+    let (tokens, name) = identifier(tokens)?;
+    let (tokens, _) = tag(ts![RightParen]).parse(tokens)?;
+    let (tokens, block) = block(tokens)?;
+
+    Ok((
+        tokens,
+        CatchClause {
+            parameter: CatchClauseParameter {
+                modifiers,
+                annotations,
+                types,
+                name: Cow::Owned(name.to_string()),
+            },
+            block,
+        },
+    ))
+}
+
+/// 解析`this(...);`、`super(...);`、`<T>this(...);`或`outer.super(...);`。
+pub(crate) fn explicit_constructor_invocation<'a>(
+    tokens: TokenStream,
+) -> IResult<TokenStream, ExplicitConstructorInvocation<'a>> {
+    let (tokens, qualifier) = match tokens.first() {
+        Some(Token::Identifier(_)) => {
+            let (tokens, idents) =
+                terminated(separated_list1(tag(ts![Dot]), identifier), tag(ts![Dot]))
+                    .parse(tokens)?;
+            let name = idents
+                .into_iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join(Token::DOT);
+            (tokens, Some(Expression::Name(Cow::Owned(name))))
+        }
+        _ => (tokens, None),
+    };
+    let (tokens, type_arguments) = opt(type_arguments).parse(tokens)?;
+    let (tokens, kind) = match (tokens.first(), &qualifier) {
+        (Some(Token::Super), _) => (tokens.take_from(1), ConstructorInvocationKind::Super),
+        (Some(Token::This), None) => (tokens.take_from(1), ConstructorInvocationKind::This),
+        _ => return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Tag))),
+    };
+    let (tokens, arguments) = terminated(arguments, tag(ts![SemiColon])).parse(tokens)?;
+
+    Ok((
+        tokens,
+        ExplicitConstructorInvocation {
+            qualifier,
+            type_arguments: type_arguments.unwrap_or_default(),
+            kind,
+            arguments,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &'static str) -> anyhow::Result<Statement<'static>> {
+        let (_, tokens) = TokenStream::from_str(input)?;
+        let (tokens, s) = block_statement(tokens)?;
+        assert!(tokens.is_empty(), "剩余标记：{:?}", tokens);
+        Ok(s)
+    }
+
+    #[test]
+    fn test_control_flow() -> anyhow::Result<()> {
+        let Statement::If {
+            else_statement: Some(else_statement),
+            ..
+        } = parse("if (a) b(); else if (c) { d = 1; } else ;")?
+        else {
+            panic!();
+        };
+        assert!(matches!(*else_statement, Statement::If { .. }));
+
+        let Statement::Labeled {
+            label, statement, ..
+        } = parse("outer: for (int i = 0, j = n; i < j; i++, j--) { continue outer; }")?
+        else {
+            panic!();
+        };
+        assert_eq!(label, "outer");
+        let Statement::For {
+            control:
+                ForControl::Basic {
+                    init: ForInit::Declaration(d),
+                    condition: Some(_),
+                    update,
+                },
+            ..
+        } = *statement
+        else {
+            panic!();
+        };
+        assert_eq!(d.declarators.len(), 2);
+        assert_eq!(update.len(), 2);
+
+        assert!(matches!(
+            parse("for (;;) break;")?,
+            Statement::For {
+                control: ForControl::Basic {
+                    condition: None,
+                    ..
+                },
+                ..
+            }
+        ));
+        let Statement::For {
+            control: ForControl::Enhanced { variable, .. },
+            ..
+        } = parse("for (final Map.Entry<K, V> e : map.entrySet()) sum += e.getValue();")?
+        else {
+            panic!();
+        };
+        assert_eq!(variable.to_string(), "final Map.Entry<K, V> e");
+
+        assert!(matches!(
+            parse("do i++; while (i < 10);")?,
+            Statement::Do { .. }
+        ));
+        assert!(matches!(parse("while (true) {}")?, Statement::While { .. }));
+        assert_eq!(parse("return;")?, Statement::Return(None));
+        assert_eq!(
+            parse("assert x : \"msg\";")?.to_string(),
+            "assert x : \"msg\";"
+        );
+        assert!(matches!(
+            parse("synchronized (this) { notify(); }")?,
+            Statement::Synchronized { .. }
+        ));
+        assert!(matches!(
+            parse("throw new IllegalStateException();")?,
+            Statement::Throw(_)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_body_comments() -> anyhow::Result<()> {
+        let texts = |comments: &[crate::Comment]| -> Vec<String> {
+            comments.iter().map(|c| c.text.trim().to_string()).collect()
+        };
+        let parse = |source: &'static str| -> anyhow::Result<Statement<'static>> {
+            let (_, tokens) = TokenStream::from_str(source)?;
+            let (tokens, s) = block_statement(tokens.without_comments())?;
+            assert!(tokens.is_empty(), "剩余标记：{:?}", tokens);
+            Ok(s)
+        };
+
+        let statement = parse("if (x > 0) // positive\n    return 1;")?;
+        let Statement::If { then_comments, .. } = &statement else {
+            panic!();
+        };
+        assert_eq!(texts(&then_comments.leading), ["positive"]);
+        assert_eq!(statement.to_string(), "if (x > 0) // positive\nreturn 1;");
+
+        let statement = parse("if (x) a(); // a\nelse /* b */ if (y) b(); else // c\n c();")?;
+        let Statement::If {
+            then_comments,
+            else_comments,
+            else_statement: Some(else_statement),
+            ..
+        } = &statement
+        else {
+            panic!();
+        };
+        assert_eq!(texts(&then_comments.trailing), ["a"]);
+        assert_eq!(texts(&else_comments.leading), ["b"]);
+        let Statement::If { else_comments, .. } = else_statement.as_ref() else {
+            panic!();
+        };
+        assert_eq!(texts(&else_comments.leading), ["c"]);
+        assert_eq!(
+            statement.to_string(),
+            "if (x) a(); // a\nelse /* b */\nif (y) b(); else // c\nc();"
+        );
+
+        let statement = parse("while (true) // 循环\n    i++;")?;
+        let Statement::While { body_comments, .. } = &statement else {
+            panic!();
+        };
+        assert_eq!(texts(&body_comments.leading), ["循环"]);
+        let statement = parse("do i++; // 自增\nwhile (i < 10);")?;
+        let Statement::Do { body_comments, .. } = &statement else {
+            panic!();
+        };
+        assert_eq!(texts(&body_comments.trailing), ["自增"]);
+        assert_eq!(statement.to_string(), "do i++; // 自增\nwhile (i < 10);");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_declarations_and_expressions() -> anyhow::Result<()> {
+        let Statement::LocalVariable(d) = parse("int[] a = {1, 2}, b;")? else {
+            panic!();
+        };
+        assert_eq!(d.declarators.len(), 2);
+        assert!(matches!(
+            parse("List<String> list;")?,
+            Statement::LocalVariable(_)
+        ));
+        assert!(matches!(parse("a.b.c = 1;")?, Statement::Expression(_)));
+        assert!(matches!(parse("a[i] = 1;")?, Statement::Expression(_)));
+        assert!(matches!(
+            parse("System.out.println(x);")?,
+            Statement::Expression(_)
+        ));
+        assert!(matches!(
+            parse("record Point(int x, int y) {}")?,
+            Statement::LocalClass(_)
+        ));
+        // 显式构造函数调用只能出现在构造函数体中
+        assert!(parse("super(a, b);").is_err());
+        assert!(matches!(
+            parse("this.x = x;")?,
+            Statement::Expression(Expression::Assignment { .. })
+        ));
+        // 不是语句表达式
+        assert!(parse("a + b;").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try() -> anyhow::Result<()> {
+        let Statement::Try(t) = parse(
+            "try (var in = open(f); final @Cleanup Writer w = writer(); out) {
+                read(in);
+            } catch (final IOException | java.sql.SQLException e) {
+                log(e);
+            } catch (@SuppressWarnings(\"x\") RuntimeException e) {
+            } finally {
+                close();
+            }",
+        )?
+        else {
+            panic!();
+        };
+        assert_eq!(t.resources.len(), 3);
+        assert!(matches!(
+            &t.resources[1],
+            TryResource::Declaration { modifiers, annotations, .. }
+                if modifiers.len() == 1 && annotations.len() == 1
+        ));
+        assert_eq!(
+            t.resources[2],
+            TryResource::Variable(Expression::Name("out".into()))
+        );
+        assert_eq!(t.catches.len(), 2);
+        assert_eq!(t.catches[0].parameter.types.len(), 2);
+        assert_eq!(
+            t.catches[0].parameter.to_string(),
+            "final IOException | java.sql.SQLException e"
+        );
+        assert_eq!(t.catches[1].parameter.annotations.len(), 1);
+        assert!(t.finally.is_some());
+
+        assert!(matches!(
+            parse("try (this.in;) {}")?,
+            Statement::Try(TryStatement { resources, .. }) if resources.len() == 1
+        ));
+        assert!(parse("try {} finally {}").is_ok());
+        // 没有资源的`try`语句必须有`catch`或`finally`
+        assert!(parse("try {}").is_err());
+
+        Ok(())
+    }
+}
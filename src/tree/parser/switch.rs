@@ -0,0 +1,436 @@
+use super::{
+    super::{Pattern, Switch, SwitchBody, SwitchGroup, SwitchLabel, SwitchRule, SwitchRuleBody},
+    block, block_statement, expression, is_primitive_allowed, pattern, syntax_node, tag,
+    ternary_expression, CommentCollector,
+};
+use crate::{ts, SyntaxKind, Token, TokenStream};
+use nom::{
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::{delimited, terminated},
+    IResult, Input, Parser,
+};
+
+const WHEN: &str = "when";
+
+/// 解析switch
+///
+/// 该函数解析从`switch`关键字开始的`switch`语句或`switch`表达式（两者的语法相同），
+/// 并返回解析后的结果和剩余的标记流。
+///
+/// # 参数
+///
+/// * `tokens` - 标记流，包含待解析的标记。
+///
+/// # 返回值
+///
+/// 返回一个 `IResult`，其中包含解析后剩余的标记流和`switch`。
+///
+/// # 示例
+///
+/// ```rust
+/// fn main() -> anyhow::Result<()> {
+/// use java_lang::{TokenStream, switch, SwitchBody};
+/// let (_, tokens) = TokenStream::from_str("
+/// switch (day) {
+///     case SATURDAY, SUNDAY -> \"weekend\";
+///     default -> { yield \"weekday\"; }
+/// }
+/// ")?;
+/// let (tokens, s) = switch(tokens)?;
+/// assert!(matches!(s.body, SwitchBody::Rules(ref r) if r.len() == 2));
+/// assert!(tokens.is_empty());
+/// Ok(())
+/// }
+/// ```
+///
+/// # 错误处理
+///
+/// 如果解析过程中出现错误，将返回一个解析错误。
+pub fn switch<'a>(tokens: TokenStream) -> IResult<TokenStream, Switch<'a>> {
+    let (tokens, _) = tag(ts![Switch]).parse(tokens)?;
+    let (tokens, selector) =
+        delimited(tag(ts![LeftParen]), expression, tag(ts![RightParen])).parse(tokens)?;
+    let (tokens, _) = tag(ts![LeftBrace]).parse(tokens)?;
+    let mut comments = CommentCollector::default();
+    comments.collect(&tokens, None);
+    let (tokens, body) = match tokens.first() {
+        Some(Token::RightBrace) => (tokens, SwitchBody::Groups(Vec::new())),
+        _ => {
+            // 根据第一个标签后面是`->`还是`:`决定整个块的形式
+            let (remaining, _) = switch_label(tokens.clone())?;
+            match remaining.first() {
+                Some(Token::Arrow) => switch_rules(tokens, &mut comments)?,
+                _ => switch_groups(tokens, &mut comments)?,
+            }
+        }
+    };
+    let (tokens, _) = tag(ts![RightBrace]).parse(tokens)?;
+
+    Ok((
+        tokens,
+        Switch {
+            selector: Box::new(selector),
+            body,
+            dangling: comments.dangling(),
+        },
+    ))
+}
+
+/// 解析箭头形式的规则直到`}`，规则前面的普通注释是它的前导注释。
+fn switch_rules<'a>(
+    mut tokens: TokenStream,
+    comments: &mut CommentCollector<'a>,
+) -> IResult<TokenStream, SwitchBody<'a>> {
+    let mut rules: Vec<SwitchRule> = Vec::new();
+    loop {
+        comments.collect(&tokens, rules.last_mut().map(|r| &mut r.comments));
+        if matches!(tokens.first(), Some(Token::RightBrace) | None) {
+            break;
+        }
+        let start = tokens.source_offset();
+        let (remaining, label) = terminated(
+            syntax_node(SyntaxKind::SwitchLabel, switch_label),
+            tag(ts![Arrow]),
+        )
+        .parse(tokens)?;
+        let (remaining, body) = match remaining.first() {
+            Some(Token::LeftBrace) => {
+                let (remaining, b) = block(remaining)?;
+                (remaining, SwitchRuleBody::Block(b))
+            }
+            Some(Token::Throw) => {
+                let (remaining, e) =
+                    delimited(tag(ts![Throw]), expression, tag(ts![SemiColon])).parse(remaining)?;
+                (remaining, SwitchRuleBody::Throw(e))
+            }
+            _ => {
+                let (remaining, e) =
+                    terminated(expression, tag(ts![SemiColon])).parse(remaining)?;
+                (remaining, SwitchRuleBody::Expression(e))
+            }
+        };
+        remaining.finish_node(start, SyntaxKind::SwitchRule);
+        rules.push(SwitchRule {
+            label,
+            body,
+            comments: comments.leading(),
+        });
+        tokens = remaining;
+    }
+
+    Ok((tokens, SwitchBody::Rules(rules)))
+}
+
+/// 解析语句组直到`}`，语句组中的语句和代码块中的语句一样关联普通注释。
+fn switch_groups<'a>(
+    mut tokens: TokenStream,
+    comments: &mut CommentCollector<'a>,
+) -> IResult<TokenStream, SwitchBody<'a>> {
+    let mut groups = Vec::new();
+    while !matches!(tokens.first(), Some(Token::RightBrace) | None) {
+        let start = tokens.source_offset();
+        let mut group = SwitchGroup {
+            labels: Vec::new(),
+            statements: Vec::new(),
+            statement_comments: Vec::new(),
+            comments: comments.leading(),
+        };
+        while let Some(Token::Case | Token::Default) = tokens.first() {
+            comments.collect(&tokens, Some(&mut group.comments));
+            let (remaining, label) = terminated(
+                syntax_node(SyntaxKind::SwitchLabel, switch_label),
+                tag(ts![Colon]),
+            )
+            .parse(tokens)?;
+            group.labels.push(label);
+            tokens = remaining;
+        }
+        if group.labels.is_empty() {
+            return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Tag)));
+        }
+        loop {
+            // 紧跟在标签后面、同一行的注释是语句组的尾随注释
+            let previous = match group.statement_comments.last_mut() {
+                Some(c) => c,
+                None => &mut group.comments,
+            };
+            comments.collect(&tokens, Some(previous));
+            if matches!(
+                tokens.first(),
+                Some(Token::RightBrace | Token::Case | Token::Default) | None
+            ) {
+                break;
+            }
+            let (remaining, statement) = block_statement(tokens)?;
+            group.statements.push(statement);
+            group.statement_comments.push(comments.leading());
+            tokens = remaining;
+        }
+        tokens.finish_node(start, SyntaxKind::SwitchGroup);
+        groups.push(group);
+    }
+
+    Ok((tokens, SwitchBody::Groups(groups)))
+}
+
+/// 解析`case ...`或`default`，不包括后面的`:`或`->`。
+/// `case`后面的常量不能是Lambda表达式或赋值表达式，否则会和`->`产生歧义。
+fn switch_label<'a>(tokens: TokenStream) -> IResult<TokenStream, SwitchLabel<'a>> {
+    if let Some(Token::Default) = tokens.first() {
+        return Ok((tokens.take_from(1), SwitchLabel::Default));
+    }
+    let (tokens, _) = tag(ts![Case]).parse(tokens)?;
+    if let Ok((tokens, _)) = tag(ts![NullLiteral, Comma, Default]).parse(tokens.clone()) {
+        return Ok((tokens, SwitchLabel::NullDefault));
+    }
+    if let Ok(out) = pattern_label(tokens.clone()) {
+        return Ok(out);
+    }
+    let (tokens, constants) = separated_list1(tag(ts![Comma]), ternary_expression).parse(tokens)?;
+
+    Ok((tokens, SwitchLabel::Case(constants)))
+}
+
+fn pattern_label<'a>(tokens: TokenStream) -> IResult<TokenStream, SwitchLabel<'a>> {
+    let (tokens, patterns) = separated_list1(tag(ts![Comma]), pattern).parse(tokens)?;
+    if patterns.iter().any(|p| match p {
+        Pattern::Type { r#type, .. } => !is_primitive_allowed(&tokens, r#type),
+        _ => false,
+    }) {
+        return Err(nom::Err::Error(Error::new(tokens, ErrorKind::Verify)));
+    }
+    let (tokens, guard) = match tokens.first() {
+        Some(Token::Identifier(i)) if i == WHEN => {
+            let (tokens, guard) = ternary_expression(tokens.take_from(1))?;
+            (tokens, Some(guard))
+        }
+        _ => (tokens, None),
+    };
+
+    Ok((tokens, SwitchLabel::Pattern { patterns, guard }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Expression, Statement};
+
+    fn parse(input: &'static str) -> anyhow::Result<Statement<'static>> {
+        let (_, tokens) = TokenStream::from_str(input)?;
+        let (tokens, s) = block_statement(tokens)?;
+        assert!(tokens.is_empty(), "剩余标记：{:?}", tokens);
+        Ok(s)
+    }
+
+    #[test]
+    fn test_switch_statement() -> anyhow::Result<()> {
+        let Statement::Switch(s) = parse(
+            "switch (x) {
+                case 1:
+                case 2, 3:
+                    a();
+                    break;
+                case null, default:
+                    b();
+            }",
+        )?
+        else {
+            panic!();
+        };
+        let SwitchBody::Groups(groups) = s.body else {
+            panic!();
+        };
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].labels.len(), 2);
+        assert_eq!(groups[0].statements.len(), 2);
+        assert_eq!(groups[1].labels, vec![SwitchLabel::NullDefault]);
+
+        let Statement::Switch(s) = parse(
+            "switch (kind) {
+                case A -> run();
+                case B, C -> { log(); }
+                default -> throw new IllegalStateException();
+            }",
+        )?
+        else {
+            panic!();
+        };
+        let SwitchBody::Rules(rules) = s.body else {
+            panic!();
+        };
+        assert!(matches!(rules[0].body, SwitchRuleBody::Expression(_)));
+        assert!(matches!(rules[1].body, SwitchRuleBody::Block(_)));
+        assert!(matches!(rules[2].body, SwitchRuleBody::Throw(_)));
+
+        assert!(matches!(parse("switch (x) {}")?, Statement::Switch(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_switch_comments() -> anyhow::Result<()> {
+        let texts = |comments: &[crate::Comment]| -> Vec<String> {
+            comments.iter().map(|c| c.text.trim().to_string()).collect()
+        };
+        let (_, tokens) = TokenStream::from_str(
+            "switch (x) {
+                // 一
+                case 1: // one
+                    f(); // call f
+                    // 贯穿
+                case 2:
+                    g();
+                // 末尾
+            }",
+        )?;
+        let (tokens, statement) = block_statement(tokens.without_comments())?;
+        assert!(tokens.is_empty());
+        let Statement::Switch(s) = &statement else {
+            panic!();
+        };
+        let SwitchBody::Groups(groups) = &s.body else {
+            panic!();
+        };
+        assert_eq!(texts(&groups[0].comments.leading), ["一"]);
+        assert_eq!(texts(&groups[0].comments.trailing), ["one"]);
+        assert_eq!(texts(&groups[0].statement_comments[0].trailing), ["call f"]);
+        assert_eq!(texts(&groups[1].comments.leading), ["贯穿"]);
+        assert!(groups[1].statement_comments[0].is_empty());
+        assert_eq!(texts(&s.dangling), ["末尾"]);
+        let text = statement.to_string();
+        assert!(
+            text.contains("case 1: // one\nf(); // call f\n"),
+            "{}",
+            text
+        );
+        assert!(text.contains("// 末尾\n}"), "{}", text);
+
+        let (_, tokens) = TokenStream::from_str(
+            "switch (x) {
+                case 1 -> f(); // call f
+                // 默认
+                default -> { g(); }
+            }",
+        )?;
+        let (_, statement) = block_statement(tokens.without_comments())?;
+        let Statement::Switch(s) = &statement else {
+            panic!();
+        };
+        let SwitchBody::Rules(rules) = &s.body else {
+            panic!();
+        };
+        assert_eq!(texts(&rules[0].comments.trailing), ["call f"]);
+        assert_eq!(texts(&rules[1].comments.leading), ["默认"]);
+        assert!(statement
+            .to_string()
+            .contains("case 1 -> f(); // call f\n// 默认\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_switch_expression() -> anyhow::Result<()> {
+        let Statement::LocalVariable(d) = parse(
+            "int n = switch (s) {
+                case \"a\":
+                    yield 1;
+                default: {
+                    int t = s.length();
+                    yield t * 2;
+                }
+            };",
+        )?
+        else {
+            panic!();
+        };
+        let Some(crate::VariableInitializer::Expression(Expression::Switch(s))) =
+            &d.declarators[0].initializer
+        else {
+            panic!();
+        };
+        let SwitchBody::Groups(groups) = &s.body else {
+            panic!();
+        };
+        assert!(matches!(groups[0].statements[0], Statement::Yield(_)));
+
+        let Statement::Return(Some(Expression::Binary { left, .. })) =
+            parse("return switch (c) { case 'a' -> 1; default -> 0; } + 1;")?
+        else {
+            panic!();
+        };
+        assert!(matches!(*left, Expression::Switch(_)));
+
+        // `yield`仍然可以作为普通标识符使用
+        assert!(matches!(parse("yield = 1;")?, Statement::Expression(_)));
+        assert!(matches!(parse("yield.run();")?, Statement::Expression(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pattern_labels() -> anyhow::Result<()> {
+        let Statement::Switch(s) = parse(
+            "switch (shape) {
+                case Circle c when c.r() > 0 -> 1;
+                case Rect(Point(var x, _), Point p) -> 2;
+                case Square _, Triangle _ -> 3;
+                case RED -> 4;
+                default -> 0;
+            }",
+        )?
+        else {
+            panic!();
+        };
+        let SwitchBody::Rules(rules) = s.body else {
+            panic!();
+        };
+        assert!(matches!(
+            &rules[0].label,
+            SwitchLabel::Pattern { patterns, guard: Some(_) } if patterns.len() == 1
+        ));
+        assert_eq!(
+            rules[1].label.to_string(),
+            "case Rect(Point(var x, _), Point p)"
+        );
+        assert!(matches!(
+            &rules[2].label,
+            SwitchLabel::Pattern { patterns, guard: None } if patterns.len() == 2
+        ));
+        assert!(matches!(rules[3].label, SwitchLabel::Case(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_primitive_patterns() -> anyhow::Result<()> {
+        const SOURCE: &str = "switch (value) {
+                case 1L -> 1;
+                case long l when l > 10L -> 2;
+                default -> 0;
+            }";
+        // 未启用预览特性时不能在顶层模式中使用基本类型
+        assert!(parse(SOURCE).is_err());
+
+        let (_, tokens) = TokenStream::from_str(SOURCE)?;
+        let (tokens, s) = block_statement(tokens.with_options(crate::ParseOptions::preview()))?;
+        assert!(tokens.is_empty());
+        let Statement::Switch(s) = s else {
+            panic!();
+        };
+        let SwitchBody::Rules(rules) = s.body else {
+            panic!();
+        };
+        assert_eq!(rules[0].label.to_string(), "case 1L");
+        assert!(matches!(
+            &rules[1].label,
+            SwitchLabel::Pattern { patterns, guard: Some(_) }
+                if matches!(patterns[0], Pattern::Type { r#type: crate::Type::Basic(_), .. })
+        ));
+
+        // 记录模式中的基本类型组件不需要预览特性
+        assert!(parse("switch (p) { case Point(int x, int y) -> {} }").is_ok());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use super::{
+    super::{BasicType, ReferenceType, Type, TypeArgument},
+    greater_than, identifier, nested, next_token, syntax_node, syntax_node_for, tag,
+};
+use crate::{ts, SyntaxKind, Token, TokenStream};
+use nom::{
+    branch::alt,
+    combinator::{map, opt},
+    multi::{many0_count, separated_list1},
+    sequence::preceded,
+    IResult, Parser,
+};
+use std::borrow::Cow;
+
+/// 解析类型
+///
+/// 该函数解析一个基本类型、引用类型或数组类型，并返回解析后的类型和剩余的标记流。
+///
+/// # 参数
+///
+/// * `tokens` - 标记流，包含待解析的标记。
+///
+/// # 返回值
+///
+/// 返回一个 `IResult`，其中包含解析后剩余的标记流和类型。
+///
+/// # 示例
+///
+/// ```rust
+/// fn main() -> anyhow::Result<()> {
+/// use java_lang::{TokenStream, r#type};
+/// let (_, tokens) = TokenStream::from_str("java.util.Map<String, List<? extends Number>>[]")?;
+/// let (tokens, t) = r#type(tokens)?;
+/// assert_eq!(t.dimensions(), 1);
+/// assert_eq!(t.to_string(), "java.util.Map<String, List<? extends Number>>[]");
+/// assert!(tokens.is_empty());
+/// Ok(())
+/// }
+/// ```
+///
+/// # 错误处理
+///
+/// 如果解析过程中出现错误，将返回一个解析错误。
+pub fn r#type<'a>(tokens: TokenStream) -> IResult<TokenStream, Type<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, t) = alt((
+        map(basic_type, Type::Basic),
+        map(reference_type, Type::Reference),
+    ))
+    .parse(tokens)?;
+    let (tokens, dimensions) = dimensions(tokens)?;
+    let t = array_of(t, dimensions);
+    tokens.finish_node_for(start, &t);
+    Ok((tokens, t))
+}
+
+/// 解析基本类型，例如`int`、`boolean`。
+pub fn basic_type(tokens: TokenStream) -> IResult<TokenStream, BasicType> {
+    let basic_type = next_token(|token| {
+        Some(match token {
+            Token::Boolean => BasicType::Boolean,
+            Token::Byte => BasicType::Byte,
+            Token::Char => BasicType::Char,
+            Token::Short => BasicType::Short,
+            Token::Int => BasicType::Int,
+            Token::Long => BasicType::Long,
+            Token::Float => BasicType::Float,
+            Token::Double => BasicType::Double,
+            _ => return None,
+        })
+    });
+    syntax_node(SyntaxKind::PrimitiveType, basic_type).parse(tokens)
+}
+
+/// 解析引用类型，例如`String`、`java.util.List<String>`或`Outer<T>.Inner`。
+pub fn reference_type<'a>(tokens: TokenStream) -> IResult<TokenStream, ReferenceType<'a>> {
+    let start = tokens.source_offset();
+    let (tokens, idents) = separated_list1(tag(ts![Dot]), identifier).parse(tokens)?;
+    let name = idents
+        .into_iter()
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(Token::DOT);
+    let (tokens, arguments) = opt(type_arguments).parse(tokens)?;
+    let (tokens, sub_type) = if arguments.is_some() {
+        opt(preceded(tag(ts![Dot]), reference_type)).parse(tokens)?
+    } else {
+        (tokens, None)
+    };
+    tokens.finish_node(start, SyntaxKind::ReferenceType);
+
+    Ok((
+        tokens,
+        ReferenceType {
+            name: Cow::Owned(name),
+            arguments,
+            sub_type: sub_type.map(Box::new),
+        },
+    ))
+}
+
+/// 解析类型参数列表`<A, B>`。
+pub fn type_arguments<'a>(tokens: TokenStream) -> IResult<TokenStream, Vec<TypeArgument<'a>>> {
+    let start = tokens.source_offset();
+    let (tokens, _) = tag(ts![LessThan]).parse(tokens)?;
+    let (tokens, arguments) =
+        separated_list1(tag(ts![Comma]), syntax_node_for(type_argument)).parse(tokens)?;
+    let (tokens, _) = greater_than(tokens)?;
+    tokens.finish_node(start, SyntaxKind::TypeArguments);
+    Ok((tokens, arguments))
+}
+
+fn type_argument<'a>(tokens: TokenStream) -> IResult<TokenStream, TypeArgument<'a>> {
+    nested(tokens, |tokens| {
+        let Ok((tokens, _)) = tag(ts![Question]).parse(tokens.clone()) else {
+            return map(r#type, TypeArgument::Type).parse(tokens);
+        };
+        alt((
+            map(preceded(tag(ts![Extends]), r#type), TypeArgument::Extends),
+            map(preceded(tag(ts![Super]), r#type), TypeArgument::Super),
+            |tokens| Ok((tokens, TypeArgument::Wildcard)),
+        ))
+        .parse(tokens)
+    })
+}
+
+/// 解析数组维度`[]`，返回维度的数量。
+pub(crate) fn dimensions(tokens: TokenStream) -> IResult<TokenStream, usize> {
+    many0_count(tag(ts![LeftBracket, RightBracket])).parse(tokens)
+}
+
+/// 把类型包装为指定维度的数组类型。
+pub(crate) fn array_of<'a>(t: Type<'a>, dimensions: usize) -> Type<'a> {
+    (0..dimensions).fold(t, |t, _| Type::Array(Box::new(t)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str("int[][]")?;
+        let (tokens, t) = r#type(tokens)?;
+        assert_eq!(
+            t,
+            Type::Array(Box::new(Type::Array(Box::new(Type::Basic(BasicType::Int)))))
+        );
+        assert!(tokens.is_empty());
+
+        let (_, tokens) = TokenStream::from_str("Map<K, List<V>>.Entry<? super T>")?;
+        let (tokens, t) = reference_type(tokens)?;
+        assert_eq!(t.name, "Map");
+        assert_eq!(t.arguments.as_ref().map(Vec::len), Some(2));
+        let sub_type = t.sub_type.as_ref().unwrap();
+        assert_eq!(sub_type.name, "Entry");
+        assert!(matches!(
+            sub_type.arguments.as_deref(),
+            Some([TypeArgument::Super(_)])
+        ));
+        assert!(tokens.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_type_arguments() -> anyhow::Result<()> {
+        let (_, tokens) = TokenStream::from_str("List<List<List<String>>> x")?;
+        let (tokens, t) = r#type(tokens)?;
+        assert_eq!(t.to_string(), "List<List<List<String>>>");
+        assert_eq!(tokens.first(), Some(&Token::Identifier("x".into())));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1504 @@
+use super::{
+    Annotation, AnnotationDeclaration, AnnotationElement, AnnotationMethod, ArrayInitializer,
+    Block, CatchClause, CatchClauseParameter, ClassBody, ClassBodyDeclaration, ClassDeclaration,
+    Comment, Comments, CompilationUnitDeclaration, ConstructorBody, ConstructorDeclaration,
+    DocumentationComment, ElementValue, ElementValuePair, EnumConstantDeclaration, EnumDeclaration,
+    ErrorNode, ExplicitConstructorInvocation, Expression, FieldDeclaration, ForControl, ForInit,
+    FormalParameter, ImportDeclaration, Initializer, InstanceOfTarget, InterfaceDeclaration,
+    LambdaBody, LambdaParameters, Literal, LocalVariableDeclaration, MethodDeclaration,
+    MethodReferenceTarget, ModuleDeclaration, ModuleDirective, PackageDeclaration, Pattern,
+    RecordComponent, RecordDeclaration, ReferenceType, Statement, Switch, SwitchBody, SwitchGroup,
+    SwitchLabel, SwitchRule, SwitchRuleBody, TopLevelClassOrInterfaceDeclaration, TryResource,
+    TryStatement, Type, TypeArgument, TypeParameter, VariableDeclarator, VariableInitializer,
+};
+
+/// Visitor以只读的方式遍历语法树，每种节点类型对应一个`visit_*`方法。
+///
+/// 每个方法的默认实现调用对应的`walk_*`函数，依次访问节点的子节点（包括关联的注释）。
+/// 实现者只需覆盖关心的方法；覆盖的方法中调用`walk_*`函数会继续访问子节点，不调用则跳过整个子树。
+///
+/// 生命周期`'ast`是语法树被借用的时间，访问者可以保存其中节点的引用。
+///
+/// # 示例
+/// ```
+/// use java_lang::{parse_str, walk_expression, Expression, Visitor};
+///
+/// /// 统计Lambda表达式之外的方法调用。
+/// #[derive(Default)]
+/// struct Calls<'ast>(Vec<&'ast str>);
+///
+/// impl<'ast> Visitor<'ast> for Calls<'ast> {
+///     fn visit_expression(&mut self, node: &'ast Expression<'ast>) {
+///         match node {
+///             // 不调用walk_expression，跳过Lambda表达式的子树
+///             Expression::Lambda { .. } => return,
+///             Expression::MethodInvocation { name, .. } => self.0.push(name),
+///             _ => {}
+///         }
+///         walk_expression(self, node)
+///     }
+/// }
+///
+/// let unit = parse_str("class A { void f() { a(b()); run(() -> c()); } }").unwrap();
+/// let mut calls = Calls::default();
+/// calls.visit_compilation_unit_declaration(&unit);
+/// assert_eq!(calls.0, ["a", "b", "run"]);
+/// ```
+pub trait Visitor<'ast> {
+    /// 访问编译单元。
+    fn visit_compilation_unit_declaration(&mut self, node: &'ast CompilationUnitDeclaration<'ast>) {
+        walk_compilation_unit_declaration(self, node)
+    }
+
+    /// 访问包声明。
+    fn visit_package_declaration(&mut self, node: &'ast PackageDeclaration<'ast>) {
+        walk_package_declaration(self, node)
+    }
+
+    /// 访问导入声明。
+    fn visit_import_declaration(&mut self, _node: &'ast ImportDeclaration<'ast>) {}
+
+    /// 访问模块声明。
+    fn visit_module_declaration(&mut self, node: &'ast ModuleDeclaration<'ast>) {
+        walk_module_declaration(self, node)
+    }
+
+    /// 访问模块指令。
+    fn visit_module_directive(&mut self, _node: &'ast ModuleDirective<'ast>) {}
+
+    /// 访问顶层类或接口声明。
+    fn visit_top_level_class_or_interface_declaration(
+        &mut self,
+        node: &'ast TopLevelClassOrInterfaceDeclaration<'ast>,
+    ) {
+        walk_top_level_class_or_interface_declaration(self, node)
+    }
+
+    /// 访问类声明。
+    fn visit_class_declaration(&mut self, node: &'ast ClassDeclaration<'ast>) {
+        walk_class_declaration(self, node)
+    }
+
+    /// 访问接口声明。
+    fn visit_interface_declaration(&mut self, node: &'ast InterfaceDeclaration<'ast>) {
+        walk_interface_declaration(self, node)
+    }
+
+    /// 访问枚举声明。
+    fn visit_enum_declaration(&mut self, node: &'ast EnumDeclaration<'ast>) {
+        walk_enum_declaration(self, node)
+    }
+
+    /// 访问枚举常量声明。
+    fn visit_enum_constant_declaration(&mut self, node: &'ast EnumConstantDeclaration<'ast>) {
+        walk_enum_constant_declaration(self, node)
+    }
+
+    /// 访问记录声明。
+    fn visit_record_declaration(&mut self, node: &'ast RecordDeclaration<'ast>) {
+        walk_record_declaration(self, node)
+    }
+
+    /// 访问记录组件。
+    fn visit_record_component(&mut self, node: &'ast RecordComponent<'ast>) {
+        walk_record_component(self, node)
+    }
+
+    /// 访问注解接口声明。
+    fn visit_annotation_declaration(&mut self, node: &'ast AnnotationDeclaration<'ast>) {
+        walk_annotation_declaration(self, node)
+    }
+
+    /// 访问类型参数。
+    fn visit_type_parameter(&mut self, node: &'ast TypeParameter<'ast>) {
+        walk_type_parameter(self, node)
+    }
+
+    /// 访问类体。
+    fn visit_class_body(&mut self, node: &'ast ClassBody<'ast>) {
+        walk_class_body(self, node)
+    }
+
+    /// 访问类体中的声明。
+    fn visit_class_body_declaration(&mut self, node: &'ast ClassBodyDeclaration<'ast>) {
+        walk_class_body_declaration(self, node)
+    }
+
+    /// 访问字段声明。
+    fn visit_field_declaration(&mut self, node: &'ast FieldDeclaration<'ast>) {
+        walk_field_declaration(self, node)
+    }
+
+    /// 访问方法声明。
+    fn visit_method_declaration(&mut self, node: &'ast MethodDeclaration<'ast>) {
+        walk_method_declaration(self, node)
+    }
+
+    /// 访问构造函数声明。
+    fn visit_constructor_declaration(&mut self, node: &'ast ConstructorDeclaration<'ast>) {
+        walk_constructor_declaration(self, node)
+    }
+
+    /// 访问初始化块。
+    fn visit_initializer(&mut self, node: &'ast Initializer<'ast>) {
+        walk_initializer(self, node)
+    }
+
+    /// 访问注解接口的元素声明。
+    fn visit_annotation_method(&mut self, node: &'ast AnnotationMethod<'ast>) {
+        walk_annotation_method(self, node)
+    }
+
+    /// 访问形式参数。
+    fn visit_formal_parameter(&mut self, node: &'ast FormalParameter<'ast>) {
+        walk_formal_parameter(self, node)
+    }
+
+    /// 访问变量声明符。
+    fn visit_variable_declarator(&mut self, node: &'ast VariableDeclarator<'ast>) {
+        walk_variable_declarator(self, node)
+    }
+
+    /// 访问变量初始化器。
+    fn visit_variable_initializer(&mut self, node: &'ast VariableInitializer<'ast>) {
+        walk_variable_initializer(self, node)
+    }
+
+    /// 访问数组初始化器。
+    fn visit_array_initializer(&mut self, node: &'ast ArrayInitializer<'ast>) {
+        walk_array_initializer(self, node)
+    }
+
+    /// 访问代码块。
+    fn visit_block(&mut self, node: &'ast Block<'ast>) {
+        walk_block(self, node)
+    }
+
+    /// 访问构造函数体。
+    fn visit_constructor_body(&mut self, node: &'ast ConstructorBody<'ast>) {
+        walk_constructor_body(self, node)
+    }
+
+    /// 访问语句。
+    fn visit_statement(&mut self, node: &'ast Statement<'ast>) {
+        walk_statement(self, node)
+    }
+
+    /// 访问局部变量声明。
+    fn visit_local_variable_declaration(&mut self, node: &'ast LocalVariableDeclaration<'ast>) {
+        walk_local_variable_declaration(self, node)
+    }
+
+    /// 访问`for`循环的控制部分。
+    fn visit_for_control(&mut self, node: &'ast ForControl<'ast>) {
+        walk_for_control(self, node)
+    }
+
+    /// 访问`for`循环的初始化部分。
+    fn visit_for_init(&mut self, node: &'ast ForInit<'ast>) {
+        walk_for_init(self, node)
+    }
+
+    /// 访问`try`语句。
+    fn visit_try_statement(&mut self, node: &'ast TryStatement<'ast>) {
+        walk_try_statement(self, node)
+    }
+
+    /// 访问`try`语句的资源。
+    fn visit_try_resource(&mut self, node: &'ast TryResource<'ast>) {
+        walk_try_resource(self, node)
+    }
+
+    /// 访问`catch`子句。
+    fn visit_catch_clause(&mut self, node: &'ast CatchClause<'ast>) {
+        walk_catch_clause(self, node)
+    }
+
+    /// 访问`catch`子句的参数。
+    fn visit_catch_clause_parameter(&mut self, node: &'ast CatchClauseParameter<'ast>) {
+        walk_catch_clause_parameter(self, node)
+    }
+
+    /// 访问显式构造函数调用。
+    fn visit_explicit_constructor_invocation(
+        &mut self,
+        node: &'ast ExplicitConstructorInvocation<'ast>,
+    ) {
+        walk_explicit_constructor_invocation(self, node)
+    }
+
+    /// 访问`switch`语句或表达式。
+    fn visit_switch(&mut self, node: &'ast Switch<'ast>) {
+        walk_switch(self, node)
+    }
+
+    /// 访问`switch`块。
+    fn visit_switch_body(&mut self, node: &'ast SwitchBody<'ast>) {
+        walk_switch_body(self, node)
+    }
+
+    /// 访问`switch`块中的语句组。
+    fn visit_switch_group(&mut self, node: &'ast SwitchGroup<'ast>) {
+        walk_switch_group(self, node)
+    }
+
+    /// 访问`switch`规则。
+    fn visit_switch_rule(&mut self, node: &'ast SwitchRule<'ast>) {
+        walk_switch_rule(self, node)
+    }
+
+    /// 访问`switch`规则的主体。
+    fn visit_switch_rule_body(&mut self, node: &'ast SwitchRuleBody<'ast>) {
+        walk_switch_rule_body(self, node)
+    }
+
+    /// 访问`switch`标签。
+    fn visit_switch_label(&mut self, node: &'ast SwitchLabel<'ast>) {
+        walk_switch_label(self, node)
+    }
+
+    /// 访问表达式。
+    fn visit_expression(&mut self, node: &'ast Expression<'ast>) {
+        walk_expression(self, node)
+    }
+
+    /// 访问字面量。
+    fn visit_literal(&mut self, _node: &'ast Literal<'ast>) {}
+
+    /// 访问Lambda表达式的参数列表。
+    fn visit_lambda_parameters(&mut self, node: &'ast LambdaParameters<'ast>) {
+        walk_lambda_parameters(self, node)
+    }
+
+    /// 访问Lambda体。
+    fn visit_lambda_body(&mut self, node: &'ast LambdaBody<'ast>) {
+        walk_lambda_body(self, node)
+    }
+
+    /// 访问方法引用中`::`前面的部分。
+    fn visit_method_reference_target(&mut self, node: &'ast MethodReferenceTarget<'ast>) {
+        walk_method_reference_target(self, node)
+    }
+
+    /// 访问模式。
+    fn visit_pattern(&mut self, node: &'ast Pattern<'ast>) {
+        walk_pattern(self, node)
+    }
+
+    /// 访问`instanceof`右边的类型或模式。
+    fn visit_instance_of_target(&mut self, node: &'ast InstanceOfTarget<'ast>) {
+        walk_instance_of_target(self, node)
+    }
+
+    /// 访问类型。
+    fn visit_type(&mut self, node: &'ast Type<'ast>) {
+        walk_type(self, node)
+    }
+
+    /// 访问引用类型。
+    fn visit_reference_type(&mut self, node: &'ast ReferenceType<'ast>) {
+        walk_reference_type(self, node)
+    }
+
+    /// 访问类型实参。
+    fn visit_type_argument(&mut self, node: &'ast TypeArgument<'ast>) {
+        walk_type_argument(self, node)
+    }
+
+    /// 访问注解。
+    fn visit_annotation(&mut self, node: &'ast Annotation<'ast>) {
+        walk_annotation(self, node)
+    }
+
+    /// 访问注解括号中的内容。
+    fn visit_annotation_element(&mut self, node: &'ast AnnotationElement<'ast>) {
+        walk_annotation_element(self, node)
+    }
+
+    /// 访问注解的元素值对。
+    fn visit_element_value_pair(&mut self, node: &'ast ElementValuePair<'ast>) {
+        walk_element_value_pair(self, node)
+    }
+
+    /// 访问注解的元素值。
+    fn visit_element_value(&mut self, node: &'ast ElementValue<'ast>) {
+        walk_element_value(self, node)
+    }
+
+    /// 访问文档注释。
+    fn visit_documentation_comment(&mut self, _node: &'ast DocumentationComment<'ast>) {}
+
+    /// 访问关联到节点的普通注释。
+    fn visit_comments(&mut self, node: &'ast Comments<'ast>) {
+        walk_comments(self, node)
+    }
+
+    /// 访问普通注释。
+    fn visit_comment(&mut self, _node: &'ast Comment<'ast>) {}
+
+    /// 访问恢复解析时跳过的错误节点。
+    fn visit_error_node(&mut self, node: &'ast ErrorNode<'ast>) {
+        walk_error_node(self, node)
+    }
+}
+
+/// 依次访问编译单元的子节点。
+pub fn walk_compilation_unit_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast CompilationUnitDeclaration<'ast>,
+) {
+    match node {
+        CompilationUnitDeclaration::Ordinary {
+            package,
+            imports,
+            import_comments,
+            top_level_class_or_interfaces,
+        } => {
+            if let Some(package) = package {
+                visitor.visit_package_declaration(package);
+            }
+            walk_imports(visitor, imports, import_comments);
+            for i in top_level_class_or_interfaces {
+                visitor.visit_top_level_class_or_interface_declaration(i);
+            }
+        }
+        CompilationUnitDeclaration::Modular {
+            imports,
+            import_comments,
+            module,
+        } => {
+            walk_imports(visitor, imports, import_comments);
+            visitor.visit_module_declaration(module);
+        }
+        CompilationUnitDeclaration::Implicit {
+            imports,
+            import_comments,
+            class,
+        } => {
+            walk_imports(visitor, imports, import_comments);
+            visitor.visit_class_declaration(class);
+        }
+    }
+}
+
+/// 依次访问导入声明和它们关联的普通注释。
+fn walk_imports<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    imports: &'ast [ImportDeclaration<'ast>],
+    comments: &'ast [Comments<'ast>],
+) {
+    for (i, import) in imports.iter().enumerate() {
+        if let Some(c) = comments.get(i) {
+            visitor.visit_comments(c);
+        }
+        visitor.visit_import_declaration(import);
+    }
+}
+
+/// 依次访问包声明的子节点。
+pub fn walk_package_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast PackageDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.modifiers {
+        visitor.visit_annotation(i);
+    }
+}
+
+/// 依次访问模块声明的子节点。
+pub fn walk_module_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ModuleDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    for i in &node.directives {
+        visitor.visit_module_directive(i);
+    }
+}
+
+/// 依次访问顶层类或接口声明的子节点。
+pub fn walk_top_level_class_or_interface_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TopLevelClassOrInterfaceDeclaration<'ast>,
+) {
+    match node {
+        TopLevelClassOrInterfaceDeclaration::Class(d) => visitor.visit_class_declaration(d),
+        TopLevelClassOrInterfaceDeclaration::Interface(d) => visitor.visit_interface_declaration(d),
+        TopLevelClassOrInterfaceDeclaration::Enum(d) => visitor.visit_enum_declaration(d),
+        TopLevelClassOrInterfaceDeclaration::Record(d) => visitor.visit_record_declaration(d),
+        TopLevelClassOrInterfaceDeclaration::Annotation(d) => {
+            visitor.visit_annotation_declaration(d)
+        }
+        TopLevelClassOrInterfaceDeclaration::Error(e) => visitor.visit_error_node(e),
+    }
+}
+
+/// 依次访问类声明的子节点。
+pub fn walk_class_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ClassDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    for i in &node.type_parameters {
+        visitor.visit_type_parameter(i);
+    }
+    if let Some(t) = &node.extends {
+        visitor.visit_reference_type(t);
+    }
+    for i in &node.implements {
+        visitor.visit_reference_type(i);
+    }
+    for i in &node.permits {
+        visitor.visit_reference_type(i);
+    }
+    visitor.visit_class_body(&node.body);
+}
+
+/// 依次访问接口声明的子节点。
+pub fn walk_interface_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast InterfaceDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    for i in &node.type_parameters {
+        visitor.visit_type_parameter(i);
+    }
+    for i in &node.extends {
+        visitor.visit_reference_type(i);
+    }
+    for i in &node.permits {
+        visitor.visit_reference_type(i);
+    }
+    visitor.visit_class_body(&node.body);
+}
+
+/// 依次访问枚举声明的子节点。
+pub fn walk_enum_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast EnumDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    for i in &node.implements {
+        visitor.visit_reference_type(i);
+    }
+    for i in &node.constants {
+        visitor.visit_enum_constant_declaration(i);
+    }
+    visitor.visit_class_body(&node.body);
+}
+
+/// 依次访问枚举常量声明的子节点。
+pub fn walk_enum_constant_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast EnumConstantDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    for i in node.arguments.iter().flatten() {
+        visitor.visit_expression(i);
+    }
+    if let Some(b) = &node.body {
+        visitor.visit_class_body(b);
+    }
+}
+
+/// 依次访问记录声明的子节点。
+pub fn walk_record_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast RecordDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    for i in &node.type_parameters {
+        visitor.visit_type_parameter(i);
+    }
+    for i in &node.components {
+        visitor.visit_record_component(i);
+    }
+    for i in &node.implements {
+        visitor.visit_reference_type(i);
+    }
+    visitor.visit_class_body(&node.body);
+}
+
+/// 依次访问记录组件的子节点。
+pub fn walk_record_component<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast RecordComponent<'ast>,
+) {
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    visitor.visit_type(&node.r#type);
+}
+
+/// 依次访问注解接口声明的子节点。
+pub fn walk_annotation_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast AnnotationDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    visitor.visit_class_body(&node.body);
+}
+
+/// 依次访问类型参数的子节点。
+pub fn walk_type_parameter<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TypeParameter<'ast>,
+) {
+    for i in &node.extends {
+        visitor.visit_reference_type(i);
+    }
+}
+
+/// 依次访问类体的子节点。
+pub fn walk_class_body<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ClassBody<'ast>,
+) {
+    for i in &node.declarations {
+        visitor.visit_class_body_declaration(i);
+    }
+    for i in &node.dangling {
+        visitor.visit_comment(i);
+    }
+}
+
+/// 依次访问类体中的声明的子节点。
+pub fn walk_class_body_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ClassBodyDeclaration<'ast>,
+) {
+    match node {
+        ClassBodyDeclaration::Field(d) => visitor.visit_field_declaration(d),
+        ClassBodyDeclaration::Method(d) => visitor.visit_method_declaration(d),
+        ClassBodyDeclaration::Constructor(d) => visitor.visit_constructor_declaration(d),
+        ClassBodyDeclaration::Initializer(d) => visitor.visit_initializer(d),
+        ClassBodyDeclaration::AnnotationMethod(d) => visitor.visit_annotation_method(d),
+        ClassBodyDeclaration::Class(d) => visitor.visit_class_declaration(d),
+        ClassBodyDeclaration::Interface(d) => visitor.visit_interface_declaration(d),
+        ClassBodyDeclaration::Enum(d) => visitor.visit_enum_declaration(d),
+        ClassBodyDeclaration::Record(d) => visitor.visit_record_declaration(d),
+        ClassBodyDeclaration::Annotation(d) => visitor.visit_annotation_declaration(d),
+        ClassBodyDeclaration::Error(e) => visitor.visit_error_node(e),
+    }
+}
+
+/// 依次访问字段声明的子节点。
+pub fn walk_field_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FieldDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    visitor.visit_type(&node.r#type);
+    for i in &node.declarators {
+        visitor.visit_variable_declarator(i);
+    }
+}
+
+/// 依次访问方法声明的子节点。
+pub fn walk_method_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast MethodDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    for i in &node.type_parameters {
+        visitor.visit_type_parameter(i);
+    }
+    if let Some(t) = &node.return_type {
+        visitor.visit_type(t);
+    }
+    for i in &node.parameters {
+        visitor.visit_formal_parameter(i);
+    }
+    for i in &node.throws {
+        visitor.visit_reference_type(i);
+    }
+    if let Some(b) = &node.body {
+        visitor.visit_block(b);
+    }
+}
+
+/// 依次访问构造函数声明的子节点。
+pub fn walk_constructor_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ConstructorDeclaration<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    for i in &node.type_parameters {
+        visitor.visit_type_parameter(i);
+    }
+    for i in node.parameters.iter().flatten() {
+        visitor.visit_formal_parameter(i);
+    }
+    for i in &node.throws {
+        visitor.visit_reference_type(i);
+    }
+    visitor.visit_constructor_body(&node.body);
+}
+
+/// 依次访问初始化块的子节点。
+pub fn walk_initializer<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Initializer<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    visitor.visit_block(&node.block);
+}
+
+/// 依次访问注解接口的元素声明的子节点。
+pub fn walk_annotation_method<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast AnnotationMethod<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    if let Some(d) = &node.documentation {
+        visitor.visit_documentation_comment(d);
+    }
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    visitor.visit_type(&node.return_type);
+    if let Some(v) = &node.default {
+        visitor.visit_element_value(v);
+    }
+}
+
+/// 依次访问形式参数的子节点。
+pub fn walk_formal_parameter<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast FormalParameter<'ast>,
+) {
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    visitor.visit_type(&node.r#type);
+}
+
+/// 依次访问变量声明符的子节点。
+pub fn walk_variable_declarator<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast VariableDeclarator<'ast>,
+) {
+    if let Some(i) = &node.initializer {
+        visitor.visit_variable_initializer(i);
+    }
+}
+
+/// 依次访问变量初始化器的子节点。
+pub fn walk_variable_initializer<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast VariableInitializer<'ast>,
+) {
+    match node {
+        VariableInitializer::Expression(e) => visitor.visit_expression(e),
+        VariableInitializer::Array(a) => visitor.visit_array_initializer(a),
+    }
+}
+
+/// 依次访问数组初始化器的子节点。
+pub fn walk_array_initializer<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ArrayInitializer<'ast>,
+) {
+    for i in &node.initializers {
+        visitor.visit_variable_initializer(i);
+    }
+}
+
+/// 依次访问代码块的子节点。
+pub fn walk_block<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Block<'ast>) {
+    for (i, statement) in node.statements.iter().enumerate() {
+        if let Some(comments) = node.comments.get(i) {
+            visitor.visit_comments(comments);
+        }
+        visitor.visit_statement(statement);
+    }
+    for i in &node.dangling {
+        visitor.visit_comment(i);
+    }
+}
+
+/// 依次访问构造函数体的子节点。
+pub fn walk_constructor_body<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ConstructorBody<'ast>,
+) {
+    let mut comments = node.comments.iter();
+    for i in &node.prologue {
+        if let Some(c) = comments.next() {
+            visitor.visit_comments(c);
+        }
+        visitor.visit_statement(i);
+    }
+    if let Some(i) = &node.invocation {
+        if let Some(c) = comments.next() {
+            visitor.visit_comments(c);
+        }
+        visitor.visit_explicit_constructor_invocation(i);
+    }
+    for i in &node.epilogue {
+        if let Some(c) = comments.next() {
+            visitor.visit_comments(c);
+        }
+        visitor.visit_statement(i);
+    }
+    for i in &node.dangling {
+        visitor.visit_comment(i);
+    }
+}
+
+/// 依次访问语句的子节点。
+pub fn walk_statement<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Statement<'ast>,
+) {
+    match node {
+        Statement::Block(b) => visitor.visit_block(b),
+        Statement::LocalVariable(d) => visitor.visit_local_variable_declaration(d),
+        Statement::LocalClass(d) => visitor.visit_class_body_declaration(d),
+        Statement::Empty | Statement::Break(_) | Statement::Continue(_) => {}
+        Statement::Labeled {
+            statement,
+            statement_comments,
+            ..
+        } => {
+            visitor.visit_comments(statement_comments);
+            visitor.visit_statement(statement);
+        }
+        Statement::Expression(e) | Statement::Throw(e) | Statement::Yield(e) => {
+            visitor.visit_expression(e)
+        }
+        Statement::If {
+            condition,
+            then_statement,
+            else_statement,
+            then_comments,
+            else_comments,
+        } => {
+            visitor.visit_expression(condition);
+            visitor.visit_comments(then_comments);
+            visitor.visit_statement(then_statement);
+            visitor.visit_comments(else_comments);
+            if let Some(s) = else_statement {
+                visitor.visit_statement(s);
+            }
+        }
+        Statement::While {
+            condition,
+            body,
+            body_comments,
+        } => {
+            visitor.visit_expression(condition);
+            visitor.visit_comments(body_comments);
+            visitor.visit_statement(body);
+        }
+        Statement::Do {
+            body,
+            condition,
+            body_comments,
+        } => {
+            visitor.visit_comments(body_comments);
+            visitor.visit_statement(body);
+            visitor.visit_expression(condition);
+        }
+        Statement::For {
+            control,
+            body,
+            body_comments,
+        } => {
+            visitor.visit_for_control(control);
+            visitor.visit_comments(body_comments);
+            visitor.visit_statement(body);
+        }
+        Statement::Return(e) => {
+            if let Some(e) = e {
+                visitor.visit_expression(e);
+            }
+        }
+        Statement::Assert { condition, message } => {
+            visitor.visit_expression(condition);
+            if let Some(m) = message {
+                visitor.visit_expression(m);
+            }
+        }
+        Statement::Synchronized { lock, block } => {
+            visitor.visit_expression(lock);
+            visitor.visit_block(block);
+        }
+        Statement::Switch(s) => visitor.visit_switch(s),
+        Statement::Try(t) => visitor.visit_try_statement(t),
+        Statement::Error(e) => visitor.visit_error_node(e),
+    }
+}
+
+/// 依次访问局部变量声明的子节点。
+pub fn walk_local_variable_declaration<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast LocalVariableDeclaration<'ast>,
+) {
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    visitor.visit_type(&node.r#type);
+    for i in &node.declarators {
+        visitor.visit_variable_declarator(i);
+    }
+}
+
+/// 依次访问`for`循环的控制部分的子节点。
+pub fn walk_for_control<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ForControl<'ast>,
+) {
+    match node {
+        ForControl::Basic {
+            init,
+            condition,
+            update,
+        } => {
+            visitor.visit_for_init(init);
+            if let Some(c) = condition {
+                visitor.visit_expression(c);
+            }
+            for i in update {
+                visitor.visit_expression(i);
+            }
+        }
+        ForControl::Enhanced { variable, iterable } => {
+            visitor.visit_local_variable_declaration(variable);
+            visitor.visit_expression(iterable);
+        }
+    }
+}
+
+/// 依次访问`for`循环的初始化部分的子节点。
+pub fn walk_for_init<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast ForInit<'ast>) {
+    match node {
+        ForInit::Declaration(d) => visitor.visit_local_variable_declaration(d),
+        ForInit::Expressions(e) => {
+            for i in e {
+                visitor.visit_expression(i);
+            }
+        }
+    }
+}
+
+/// 依次访问`try`语句的子节点。
+pub fn walk_try_statement<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TryStatement<'ast>,
+) {
+    for i in &node.resources {
+        visitor.visit_try_resource(i);
+    }
+    visitor.visit_block(&node.block);
+    for i in &node.catches {
+        visitor.visit_catch_clause(i);
+    }
+    if let Some(b) = &node.finally {
+        visitor.visit_block(b);
+    }
+}
+
+/// 依次访问`try`语句的资源的子节点。
+pub fn walk_try_resource<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TryResource<'ast>,
+) {
+    match node {
+        TryResource::Declaration {
+            annotations,
+            r#type,
+            value,
+            ..
+        } => {
+            for i in annotations {
+                visitor.visit_annotation(i);
+            }
+            visitor.visit_type(r#type);
+            visitor.visit_expression(value);
+        }
+        TryResource::Variable(e) => visitor.visit_expression(e),
+    }
+}
+
+/// 依次访问`catch`子句的子节点。
+pub fn walk_catch_clause<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast CatchClause<'ast>,
+) {
+    visitor.visit_catch_clause_parameter(&node.parameter);
+    visitor.visit_block(&node.block);
+}
+
+/// 依次访问`catch`子句的参数的子节点。
+pub fn walk_catch_clause_parameter<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast CatchClauseParameter<'ast>,
+) {
+    for i in &node.annotations {
+        visitor.visit_annotation(i);
+    }
+    for i in &node.types {
+        visitor.visit_reference_type(i);
+    }
+}
+
+/// 依次访问显式构造函数调用的子节点。
+pub fn walk_explicit_constructor_invocation<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ExplicitConstructorInvocation<'ast>,
+) {
+    if let Some(q) = &node.qualifier {
+        visitor.visit_expression(q);
+    }
+    for i in &node.type_arguments {
+        visitor.visit_type_argument(i);
+    }
+    for i in &node.arguments {
+        visitor.visit_expression(i);
+    }
+}
+
+/// 依次访问`switch`语句或表达式的子节点。
+pub fn walk_switch<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Switch<'ast>) {
+    visitor.visit_expression(&node.selector);
+    visitor.visit_switch_body(&node.body);
+    for i in &node.dangling {
+        visitor.visit_comment(i);
+    }
+}
+
+/// 依次访问`switch`块的子节点。
+pub fn walk_switch_body<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SwitchBody<'ast>,
+) {
+    match node {
+        SwitchBody::Groups(groups) => {
+            for i in groups {
+                visitor.visit_switch_group(i);
+            }
+        }
+        SwitchBody::Rules(rules) => {
+            for i in rules {
+                visitor.visit_switch_rule(i);
+            }
+        }
+    }
+}
+
+/// 依次访问`switch`块中的语句组的子节点。
+pub fn walk_switch_group<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SwitchGroup<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    for i in &node.labels {
+        visitor.visit_switch_label(i);
+    }
+    for (i, statement) in node.statements.iter().enumerate() {
+        if let Some(comments) = node.statement_comments.get(i) {
+            visitor.visit_comments(comments);
+        }
+        visitor.visit_statement(statement);
+    }
+}
+
+/// 依次访问`switch`规则的子节点。
+pub fn walk_switch_rule<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SwitchRule<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+    visitor.visit_switch_label(&node.label);
+    visitor.visit_switch_rule_body(&node.body);
+}
+
+/// 依次访问`switch`规则的主体的子节点。
+pub fn walk_switch_rule_body<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SwitchRuleBody<'ast>,
+) {
+    match node {
+        SwitchRuleBody::Expression(e) | SwitchRuleBody::Throw(e) => visitor.visit_expression(e),
+        SwitchRuleBody::Block(b) => visitor.visit_block(b),
+    }
+}
+
+/// 依次访问`switch`标签的子节点。
+pub fn walk_switch_label<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast SwitchLabel<'ast>,
+) {
+    match node {
+        SwitchLabel::Case(e) => {
+            for i in e {
+                visitor.visit_expression(i);
+            }
+        }
+        SwitchLabel::Pattern { patterns, guard } => {
+            for i in patterns {
+                visitor.visit_pattern(i);
+            }
+            if let Some(g) = guard {
+                visitor.visit_expression(g);
+            }
+        }
+        SwitchLabel::NullDefault | SwitchLabel::Default => {}
+    }
+}
+
+/// 依次访问表达式的子节点。
+pub fn walk_expression<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Expression<'ast>,
+) {
+    match node {
+        Expression::Literal(l) => visitor.visit_literal(l),
+        Expression::Name(_)
+        | Expression::This
+        | Expression::QualifiedThis(_)
+        | Expression::SuperFieldAccess { .. }
+        | Expression::VoidClassLiteral => {}
+        Expression::FieldAccess { target, .. } => visitor.visit_expression(target),
+        Expression::MethodInvocation {
+            target,
+            type_arguments,
+            arguments,
+            ..
+        } => {
+            if let Some(t) = target {
+                visitor.visit_expression(t);
+            }
+            for i in type_arguments {
+                visitor.visit_type_argument(i);
+            }
+            for i in arguments {
+                visitor.visit_expression(i);
+            }
+        }
+        Expression::SuperMethodInvocation {
+            type_arguments,
+            arguments,
+            ..
+        } => {
+            for i in type_arguments {
+                visitor.visit_type_argument(i);
+            }
+            for i in arguments {
+                visitor.visit_expression(i);
+            }
+        }
+        Expression::ArrayAccess { array, index } => {
+            visitor.visit_expression(array);
+            visitor.visit_expression(index);
+        }
+        Expression::ClassLiteral(t) => visitor.visit_type(t),
+        Expression::ClassCreation {
+            outer,
+            type_arguments,
+            r#type,
+            arguments,
+            body,
+        } => {
+            if let Some(o) = outer {
+                visitor.visit_expression(o);
+            }
+            for i in type_arguments {
+                visitor.visit_type_argument(i);
+            }
+            visitor.visit_reference_type(r#type);
+            for i in arguments {
+                visitor.visit_expression(i);
+            }
+            if let Some(b) = body {
+                visitor.visit_class_body(b);
+            }
+        }
+        Expression::ArrayCreation {
+            r#type,
+            dimensions,
+            initializer,
+        } => {
+            visitor.visit_type(r#type);
+            for i in dimensions {
+                visitor.visit_expression(i);
+            }
+            if let Some(i) = initializer {
+                visitor.visit_array_initializer(i);
+            }
+        }
+        Expression::Parenthesized(e) => visitor.visit_expression(e),
+        Expression::Assignment { target, value, .. } => {
+            visitor.visit_expression(target);
+            visitor.visit_expression(value);
+        }
+        Expression::Ternary {
+            condition,
+            if_true,
+            if_false,
+        } => {
+            visitor.visit_expression(condition);
+            visitor.visit_expression(if_true);
+            visitor.visit_expression(if_false);
+        }
+        Expression::Binary { left, right, .. } => {
+            visitor.visit_expression(left);
+            visitor.visit_expression(right);
+        }
+        Expression::Lambda { parameters, body } => {
+            visitor.visit_lambda_parameters(parameters);
+            visitor.visit_lambda_body(body);
+        }
+        Expression::MethodReference {
+            target,
+            type_arguments,
+            ..
+        } => {
+            visitor.visit_method_reference_target(target);
+            for i in type_arguments {
+                visitor.visit_type_argument(i);
+            }
+        }
+        Expression::Switch(s) => visitor.visit_switch(s),
+        Expression::InstanceOf { expression, target } => {
+            visitor.visit_expression(expression);
+            visitor.visit_instance_of_target(target);
+        }
+        Expression::Unary { operand, .. } | Expression::Postfix { operand, .. } => {
+            visitor.visit_expression(operand)
+        }
+        Expression::Cast {
+            r#type,
+            bounds,
+            expression,
+        } => {
+            visitor.visit_type(r#type);
+            for i in bounds {
+                visitor.visit_reference_type(i);
+            }
+            visitor.visit_expression(expression);
+        }
+    }
+}
+
+/// 依次访问Lambda表达式的参数列表的子节点。
+pub fn walk_lambda_parameters<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast LambdaParameters<'ast>,
+) {
+    match node {
+        LambdaParameters::Inferred(_) => {}
+        LambdaParameters::Formal(parameters) => {
+            for i in parameters {
+                visitor.visit_formal_parameter(i);
+            }
+        }
+    }
+}
+
+/// 依次访问Lambda体的子节点。
+pub fn walk_lambda_body<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast LambdaBody<'ast>,
+) {
+    match node {
+        LambdaBody::Expression(e) => visitor.visit_expression(e),
+        LambdaBody::Block(b) => visitor.visit_block(b),
+    }
+}
+
+/// 依次访问方法引用中`::`前面的部分的子节点。
+pub fn walk_method_reference_target<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast MethodReferenceTarget<'ast>,
+) {
+    match node {
+        MethodReferenceTarget::Expression(e) => visitor.visit_expression(e),
+        MethodReferenceTarget::Type(t) => visitor.visit_type(t),
+        MethodReferenceTarget::Super(_) => {}
+    }
+}
+
+/// 依次访问模式的子节点。
+pub fn walk_pattern<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Pattern<'ast>) {
+    match node {
+        Pattern::Type {
+            annotations,
+            r#type,
+            ..
+        } => {
+            for i in annotations {
+                visitor.visit_annotation(i);
+            }
+            visitor.visit_type(r#type);
+        }
+        Pattern::Record { r#type, patterns } => {
+            visitor.visit_reference_type(r#type);
+            for i in patterns {
+                visitor.visit_pattern(i);
+            }
+        }
+        Pattern::Unnamed => {}
+    }
+}
+
+/// 依次访问`instanceof`右边的类型或模式的子节点。
+pub fn walk_instance_of_target<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast InstanceOfTarget<'ast>,
+) {
+    match node {
+        InstanceOfTarget::Type(t) => visitor.visit_type(t),
+        InstanceOfTarget::Pattern(p) => visitor.visit_pattern(p),
+    }
+}
+
+/// 依次访问类型的子节点。
+pub fn walk_type<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Type<'ast>) {
+    match node {
+        Type::Basic(_) => {}
+        Type::Reference(r) => visitor.visit_reference_type(r),
+        Type::Array(t) => visitor.visit_type(t),
+    }
+}
+
+/// 依次访问引用类型的子节点。
+pub fn walk_reference_type<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ReferenceType<'ast>,
+) {
+    for i in node.arguments.iter().flatten() {
+        visitor.visit_type_argument(i);
+    }
+    if let Some(t) = &node.sub_type {
+        visitor.visit_reference_type(t);
+    }
+}
+
+/// 依次访问类型实参的子节点。
+pub fn walk_type_argument<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast TypeArgument<'ast>,
+) {
+    match node {
+        TypeArgument::Type(t) | TypeArgument::Extends(t) | TypeArgument::Super(t) => {
+            visitor.visit_type(t)
+        }
+        TypeArgument::Wildcard => {}
+    }
+}
+
+/// 依次访问注解的子节点。
+pub fn walk_annotation<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast Annotation<'ast>,
+) {
+    if let Some(e) = &node.element {
+        visitor.visit_annotation_element(e);
+    }
+}
+
+/// 依次访问注解括号中的内容的子节点。
+pub fn walk_annotation_element<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast AnnotationElement<'ast>,
+) {
+    match node {
+        AnnotationElement::Value(v) => visitor.visit_element_value(v),
+        AnnotationElement::Pairs(pairs) => {
+            for i in pairs {
+                visitor.visit_element_value_pair(i);
+            }
+        }
+    }
+}
+
+/// 依次访问注解的元素值对的子节点。
+pub fn walk_element_value_pair<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ElementValuePair<'ast>,
+) {
+    visitor.visit_element_value(&node.value);
+}
+
+/// 依次访问注解的元素值的子节点。
+pub fn walk_element_value<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ElementValue<'ast>,
+) {
+    match node {
+        ElementValue::Expression(e) => visitor.visit_expression(e),
+        ElementValue::Annotation(a) => visitor.visit_annotation(a),
+        ElementValue::Array(values) => {
+            for i in values {
+                visitor.visit_element_value(i);
+            }
+        }
+    }
+}
+
+/// 依次访问关联到节点的普通注释的子节点。
+pub fn walk_comments<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, node: &'ast Comments<'ast>) {
+    for i in &node.leading {
+        visitor.visit_comment(i);
+    }
+    for i in &node.trailing {
+        visitor.visit_comment(i);
+    }
+}
+
+/// 依次访问恢复解析时跳过的错误节点的子节点。
+pub fn walk_error_node<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    node: &'ast ErrorNode<'ast>,
+) {
+    visitor.visit_comments(&node.comments);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_str;
+
+    #[derive(Default)]
+    struct Collector<'ast> {
+        names: Vec<&'ast str>,
+        types: Vec<String>,
+        comments: Vec<&'ast str>,
+        skip_methods: bool,
+    }
+
+    impl<'ast> Visitor<'ast> for Collector<'ast> {
+        fn visit_method_declaration(&mut self, node: &'ast MethodDeclaration<'ast>) {
+            if !self.skip_methods {
+                walk_method_declaration(self, node)
+            }
+        }
+
+        fn visit_expression(&mut self, node: &'ast Expression<'ast>) {
+            if let Expression::Name(name) = node {
+                self.names.push(name);
+            }
+            walk_expression(self, node)
+        }
+
+        fn visit_reference_type(&mut self, node: &'ast ReferenceType<'ast>) {
+            self.types.push(node.to_string());
+        }
+
+        fn visit_comment(&mut self, node: &'ast Comment<'ast>) {
+            self.comments.push(&node.text);
+        }
+    }
+
+    #[test]
+    fn test_visitor() {
+        let unit = parse_str(
+            r#"
+// 类
+class A extends B<C> {
+    int a = x + y[z];
+
+    void f(List<String> list) {
+        // 循环
+        for (var i : list) {
+            switch (i) {
+                case String s when s.isEmpty() -> g(s);
+                default -> { throw new E(m); }
+            }
+        }
+        Runnable r = () -> h(n); // 尾随
+    }
+}
+"#,
+        )
+        .unwrap();
+        let mut collector = Collector::default();
+        collector.visit_compilation_unit_declaration(&unit);
+        assert_eq!(
+            collector.names,
+            ["x", "y", "z", "list", "i", "s", "s", "m", "n"]
+        );
+        assert_eq!(
+            collector.types,
+            ["B<C>", "List<String>", "var", "String", "E", "Runnable"]
+        );
+        assert_eq!(collector.comments, [" 类", " 循环", " 尾随"]);
+
+        let mut collector = Collector {
+            skip_methods: true,
+            ..Default::default()
+        };
+        collector.visit_compilation_unit_declaration(&unit);
+        assert_eq!(collector.names, ["x", "y", "z"]);
+        assert_eq!(collector.types, ["B<C>"]);
+    }
+}
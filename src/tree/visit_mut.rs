@@ -0,0 +1,1475 @@
+use super::{
+    Annotation, AnnotationDeclaration, AnnotationElement, AnnotationMethod, ArrayInitializer,
+    Block, CatchClause, CatchClauseParameter, ClassBody, ClassBodyDeclaration, ClassDeclaration,
+    Comment, Comments, CompilationUnitDeclaration, ConstructorBody, ConstructorDeclaration,
+    DocumentationComment, ElementValue, ElementValuePair, EnumConstantDeclaration, EnumDeclaration,
+    ErrorNode, ExplicitConstructorInvocation, Expression, FieldDeclaration, ForControl, ForInit,
+    FormalParameter, ImportDeclaration, Initializer, InstanceOfTarget, InterfaceDeclaration,
+    LambdaBody, LambdaParameters, Literal, LocalVariableDeclaration, MethodDeclaration,
+    MethodReferenceTarget, ModuleDeclaration, ModuleDirective, PackageDeclaration, Pattern,
+    RecordComponent, RecordDeclaration, ReferenceType, Statement, Switch, SwitchBody, SwitchGroup,
+    SwitchLabel, SwitchRule, SwitchRuleBody, TopLevelClassOrInterfaceDeclaration, TryResource,
+    TryStatement, Type, TypeArgument, TypeParameter, VariableDeclarator, VariableInitializer,
+};
+
+/// VisitorMut以可变的方式遍历语法树，可以就地修改节点，每种节点类型对应一个`visit_*_mut`方法。
+///
+/// 每个方法的默认实现调用对应的`walk_*_mut`函数，依次访问节点的子节点（包括关联的注释）。
+/// 实现者只需覆盖关心的方法；覆盖的方法中调用`walk_*_mut`函数会继续访问子节点，不调用则跳过整个子树。
+///
+/// # 示例
+/// ```
+/// use java_lang::{parse_str, walk_expression_mut, Expression, VisitorMut};
+///
+/// /// 把`println`调用改为`print`。
+/// struct Rename;
+///
+/// impl<'a> VisitorMut<'a> for Rename {
+///     fn visit_expression_mut(&mut self, node: &mut Expression<'a>) {
+///         if let Expression::MethodInvocation { name, .. } = node {
+///             if name == "println" {
+///                 *name = "print".into();
+///             }
+///         }
+///         walk_expression_mut(self, node)
+///     }
+/// }
+///
+/// let mut unit = parse_str("class A { void f() { System.out.println(1); } }").unwrap();
+/// Rename.visit_compilation_unit_declaration_mut(&mut unit);
+/// assert!(unit.to_string().contains("System.out.print(1);"));
+/// ```
+pub trait VisitorMut<'a> {
+    /// 访问编译单元。
+    fn visit_compilation_unit_declaration_mut(
+        &mut self,
+        node: &mut CompilationUnitDeclaration<'a>,
+    ) {
+        walk_compilation_unit_declaration_mut(self, node)
+    }
+
+    /// 访问包声明。
+    fn visit_package_declaration_mut(&mut self, node: &mut PackageDeclaration<'a>) {
+        walk_package_declaration_mut(self, node)
+    }
+
+    /// 访问导入声明。
+    fn visit_import_declaration_mut(&mut self, _node: &mut ImportDeclaration<'a>) {}
+
+    /// 访问模块声明。
+    fn visit_module_declaration_mut(&mut self, node: &mut ModuleDeclaration<'a>) {
+        walk_module_declaration_mut(self, node)
+    }
+
+    /// 访问模块指令。
+    fn visit_module_directive_mut(&mut self, _node: &mut ModuleDirective<'a>) {}
+
+    /// 访问顶层类或接口声明。
+    fn visit_top_level_class_or_interface_declaration_mut(
+        &mut self,
+        node: &mut TopLevelClassOrInterfaceDeclaration<'a>,
+    ) {
+        walk_top_level_class_or_interface_declaration_mut(self, node)
+    }
+
+    /// 访问类声明。
+    fn visit_class_declaration_mut(&mut self, node: &mut ClassDeclaration<'a>) {
+        walk_class_declaration_mut(self, node)
+    }
+
+    /// 访问接口声明。
+    fn visit_interface_declaration_mut(&mut self, node: &mut InterfaceDeclaration<'a>) {
+        walk_interface_declaration_mut(self, node)
+    }
+
+    /// 访问枚举声明。
+    fn visit_enum_declaration_mut(&mut self, node: &mut EnumDeclaration<'a>) {
+        walk_enum_declaration_mut(self, node)
+    }
+
+    /// 访问枚举常量声明。
+    fn visit_enum_constant_declaration_mut(&mut self, node: &mut EnumConstantDeclaration<'a>) {
+        walk_enum_constant_declaration_mut(self, node)
+    }
+
+    /// 访问记录声明。
+    fn visit_record_declaration_mut(&mut self, node: &mut RecordDeclaration<'a>) {
+        walk_record_declaration_mut(self, node)
+    }
+
+    /// 访问记录组件。
+    fn visit_record_component_mut(&mut self, node: &mut RecordComponent<'a>) {
+        walk_record_component_mut(self, node)
+    }
+
+    /// 访问注解接口声明。
+    fn visit_annotation_declaration_mut(&mut self, node: &mut AnnotationDeclaration<'a>) {
+        walk_annotation_declaration_mut(self, node)
+    }
+
+    /// 访问类型参数。
+    fn visit_type_parameter_mut(&mut self, node: &mut TypeParameter<'a>) {
+        walk_type_parameter_mut(self, node)
+    }
+
+    /// 访问类体。
+    fn visit_class_body_mut(&mut self, node: &mut ClassBody<'a>) {
+        walk_class_body_mut(self, node)
+    }
+
+    /// 访问类体中的声明。
+    fn visit_class_body_declaration_mut(&mut self, node: &mut ClassBodyDeclaration<'a>) {
+        walk_class_body_declaration_mut(self, node)
+    }
+
+    /// 访问字段声明。
+    fn visit_field_declaration_mut(&mut self, node: &mut FieldDeclaration<'a>) {
+        walk_field_declaration_mut(self, node)
+    }
+
+    /// 访问方法声明。
+    fn visit_method_declaration_mut(&mut self, node: &mut MethodDeclaration<'a>) {
+        walk_method_declaration_mut(self, node)
+    }
+
+    /// 访问构造函数声明。
+    fn visit_constructor_declaration_mut(&mut self, node: &mut ConstructorDeclaration<'a>) {
+        walk_constructor_declaration_mut(self, node)
+    }
+
+    /// 访问初始化块。
+    fn visit_initializer_mut(&mut self, node: &mut Initializer<'a>) {
+        walk_initializer_mut(self, node)
+    }
+
+    /// 访问注解接口的元素声明。
+    fn visit_annotation_method_mut(&mut self, node: &mut AnnotationMethod<'a>) {
+        walk_annotation_method_mut(self, node)
+    }
+
+    /// 访问形式参数。
+    fn visit_formal_parameter_mut(&mut self, node: &mut FormalParameter<'a>) {
+        walk_formal_parameter_mut(self, node)
+    }
+
+    /// 访问变量声明符。
+    fn visit_variable_declarator_mut(&mut self, node: &mut VariableDeclarator<'a>) {
+        walk_variable_declarator_mut(self, node)
+    }
+
+    /// 访问变量初始化器。
+    fn visit_variable_initializer_mut(&mut self, node: &mut VariableInitializer<'a>) {
+        walk_variable_initializer_mut(self, node)
+    }
+
+    /// 访问数组初始化器。
+    fn visit_array_initializer_mut(&mut self, node: &mut ArrayInitializer<'a>) {
+        walk_array_initializer_mut(self, node)
+    }
+
+    /// 访问代码块。
+    fn visit_block_mut(&mut self, node: &mut Block<'a>) {
+        walk_block_mut(self, node)
+    }
+
+    /// 访问构造函数体。
+    fn visit_constructor_body_mut(&mut self, node: &mut ConstructorBody<'a>) {
+        walk_constructor_body_mut(self, node)
+    }
+
+    /// 访问语句。
+    fn visit_statement_mut(&mut self, node: &mut Statement<'a>) {
+        walk_statement_mut(self, node)
+    }
+
+    /// 访问局部变量声明。
+    fn visit_local_variable_declaration_mut(&mut self, node: &mut LocalVariableDeclaration<'a>) {
+        walk_local_variable_declaration_mut(self, node)
+    }
+
+    /// 访问`for`循环的控制部分。
+    fn visit_for_control_mut(&mut self, node: &mut ForControl<'a>) {
+        walk_for_control_mut(self, node)
+    }
+
+    /// 访问`for`循环的初始化部分。
+    fn visit_for_init_mut(&mut self, node: &mut ForInit<'a>) {
+        walk_for_init_mut(self, node)
+    }
+
+    /// 访问`try`语句。
+    fn visit_try_statement_mut(&mut self, node: &mut TryStatement<'a>) {
+        walk_try_statement_mut(self, node)
+    }
+
+    /// 访问`try`语句的资源。
+    fn visit_try_resource_mut(&mut self, node: &mut TryResource<'a>) {
+        walk_try_resource_mut(self, node)
+    }
+
+    /// 访问`catch`子句。
+    fn visit_catch_clause_mut(&mut self, node: &mut CatchClause<'a>) {
+        walk_catch_clause_mut(self, node)
+    }
+
+    /// 访问`catch`子句的参数。
+    fn visit_catch_clause_parameter_mut(&mut self, node: &mut CatchClauseParameter<'a>) {
+        walk_catch_clause_parameter_mut(self, node)
+    }
+
+    /// 访问显式构造函数调用。
+    fn visit_explicit_constructor_invocation_mut(
+        &mut self,
+        node: &mut ExplicitConstructorInvocation<'a>,
+    ) {
+        walk_explicit_constructor_invocation_mut(self, node)
+    }
+
+    /// 访问`switch`语句或表达式。
+    fn visit_switch_mut(&mut self, node: &mut Switch<'a>) {
+        walk_switch_mut(self, node)
+    }
+
+    /// 访问`switch`块。
+    fn visit_switch_body_mut(&mut self, node: &mut SwitchBody<'a>) {
+        walk_switch_body_mut(self, node)
+    }
+
+    /// 访问`switch`块中的语句组。
+    fn visit_switch_group_mut(&mut self, node: &mut SwitchGroup<'a>) {
+        walk_switch_group_mut(self, node)
+    }
+
+    /// 访问`switch`规则。
+    fn visit_switch_rule_mut(&mut self, node: &mut SwitchRule<'a>) {
+        walk_switch_rule_mut(self, node)
+    }
+
+    /// 访问`switch`规则的主体。
+    fn visit_switch_rule_body_mut(&mut self, node: &mut SwitchRuleBody<'a>) {
+        walk_switch_rule_body_mut(self, node)
+    }
+
+    /// 访问`switch`标签。
+    fn visit_switch_label_mut(&mut self, node: &mut SwitchLabel<'a>) {
+        walk_switch_label_mut(self, node)
+    }
+
+    /// 访问表达式。
+    fn visit_expression_mut(&mut self, node: &mut Expression<'a>) {
+        walk_expression_mut(self, node)
+    }
+
+    /// 访问字面量。
+    fn visit_literal_mut(&mut self, _node: &mut Literal<'a>) {}
+
+    /// 访问Lambda表达式的参数列表。
+    fn visit_lambda_parameters_mut(&mut self, node: &mut LambdaParameters<'a>) {
+        walk_lambda_parameters_mut(self, node)
+    }
+
+    /// 访问Lambda体。
+    fn visit_lambda_body_mut(&mut self, node: &mut LambdaBody<'a>) {
+        walk_lambda_body_mut(self, node)
+    }
+
+    /// 访问方法引用中`::`前面的部分。
+    fn visit_method_reference_target_mut(&mut self, node: &mut MethodReferenceTarget<'a>) {
+        walk_method_reference_target_mut(self, node)
+    }
+
+    /// 访问模式。
+    fn visit_pattern_mut(&mut self, node: &mut Pattern<'a>) {
+        walk_pattern_mut(self, node)
+    }
+
+    /// 访问`instanceof`右边的类型或模式。
+    fn visit_instance_of_target_mut(&mut self, node: &mut InstanceOfTarget<'a>) {
+        walk_instance_of_target_mut(self, node)
+    }
+
+    /// 访问类型。
+    fn visit_type_mut(&mut self, node: &mut Type<'a>) {
+        walk_type_mut(self, node)
+    }
+
+    /// 访问引用类型。
+    fn visit_reference_type_mut(&mut self, node: &mut ReferenceType<'a>) {
+        walk_reference_type_mut(self, node)
+    }
+
+    /// 访问类型实参。
+    fn visit_type_argument_mut(&mut self, node: &mut TypeArgument<'a>) {
+        walk_type_argument_mut(self, node)
+    }
+
+    /// 访问注解。
+    fn visit_annotation_mut(&mut self, node: &mut Annotation<'a>) {
+        walk_annotation_mut(self, node)
+    }
+
+    /// 访问注解括号中的内容。
+    fn visit_annotation_element_mut(&mut self, node: &mut AnnotationElement<'a>) {
+        walk_annotation_element_mut(self, node)
+    }
+
+    /// 访问注解的元素值对。
+    fn visit_element_value_pair_mut(&mut self, node: &mut ElementValuePair<'a>) {
+        walk_element_value_pair_mut(self, node)
+    }
+
+    /// 访问注解的元素值。
+    fn visit_element_value_mut(&mut self, node: &mut ElementValue<'a>) {
+        walk_element_value_mut(self, node)
+    }
+
+    /// 访问文档注释。
+    fn visit_documentation_comment_mut(&mut self, _node: &mut DocumentationComment<'a>) {}
+
+    /// 访问关联到节点的普通注释。
+    fn visit_comments_mut(&mut self, node: &mut Comments<'a>) {
+        walk_comments_mut(self, node)
+    }
+
+    /// 访问普通注释。
+    fn visit_comment_mut(&mut self, _node: &mut Comment<'a>) {}
+
+    /// 访问恢复解析时跳过的错误节点。
+    fn visit_error_node_mut(&mut self, node: &mut ErrorNode<'a>) {
+        walk_error_node_mut(self, node)
+    }
+}
+
+/// 依次访问编译单元的子节点。
+pub fn walk_compilation_unit_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut CompilationUnitDeclaration<'a>,
+) {
+    match node {
+        CompilationUnitDeclaration::Ordinary {
+            package,
+            imports,
+            import_comments,
+            top_level_class_or_interfaces,
+        } => {
+            if let Some(package) = package {
+                visitor.visit_package_declaration_mut(package);
+            }
+            walk_imports(visitor, imports, import_comments);
+            for i in top_level_class_or_interfaces {
+                visitor.visit_top_level_class_or_interface_declaration_mut(i);
+            }
+        }
+        CompilationUnitDeclaration::Modular {
+            imports,
+            import_comments,
+            module,
+        } => {
+            walk_imports(visitor, imports, import_comments);
+            visitor.visit_module_declaration_mut(module);
+        }
+        CompilationUnitDeclaration::Implicit {
+            imports,
+            import_comments,
+            class,
+        } => {
+            walk_imports(visitor, imports, import_comments);
+            visitor.visit_class_declaration_mut(class);
+        }
+    }
+}
+
+/// 依次访问导入声明和它们关联的普通注释。
+fn walk_imports<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    imports: &mut [ImportDeclaration<'a>],
+    comments: &mut [Comments<'a>],
+) {
+    for (i, import) in imports.iter_mut().enumerate() {
+        if let Some(c) = comments.get_mut(i) {
+            visitor.visit_comments_mut(c);
+        }
+        visitor.visit_import_declaration_mut(import);
+    }
+}
+
+/// 依次访问包声明的子节点。
+pub fn walk_package_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut PackageDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.modifiers {
+        visitor.visit_annotation_mut(i);
+    }
+}
+
+/// 依次访问模块声明的子节点。
+pub fn walk_module_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ModuleDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    for i in &mut node.directives {
+        visitor.visit_module_directive_mut(i);
+    }
+}
+
+/// 依次访问顶层类或接口声明的子节点。
+pub fn walk_top_level_class_or_interface_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut TopLevelClassOrInterfaceDeclaration<'a>,
+) {
+    match node {
+        TopLevelClassOrInterfaceDeclaration::Class(d) => visitor.visit_class_declaration_mut(d),
+        TopLevelClassOrInterfaceDeclaration::Interface(d) => {
+            visitor.visit_interface_declaration_mut(d)
+        }
+        TopLevelClassOrInterfaceDeclaration::Enum(d) => visitor.visit_enum_declaration_mut(d),
+        TopLevelClassOrInterfaceDeclaration::Record(d) => visitor.visit_record_declaration_mut(d),
+        TopLevelClassOrInterfaceDeclaration::Annotation(d) => {
+            visitor.visit_annotation_declaration_mut(d)
+        }
+        TopLevelClassOrInterfaceDeclaration::Error(e) => visitor.visit_error_node_mut(e),
+    }
+}
+
+/// 依次访问类声明的子节点。
+pub fn walk_class_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    for i in &mut node.type_parameters {
+        visitor.visit_type_parameter_mut(i);
+    }
+    if let Some(t) = &mut node.extends {
+        visitor.visit_reference_type_mut(t);
+    }
+    for i in &mut node.implements {
+        visitor.visit_reference_type_mut(i);
+    }
+    for i in &mut node.permits {
+        visitor.visit_reference_type_mut(i);
+    }
+    visitor.visit_class_body_mut(&mut node.body);
+}
+
+/// 依次访问接口声明的子节点。
+pub fn walk_interface_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut InterfaceDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    for i in &mut node.type_parameters {
+        visitor.visit_type_parameter_mut(i);
+    }
+    for i in &mut node.extends {
+        visitor.visit_reference_type_mut(i);
+    }
+    for i in &mut node.permits {
+        visitor.visit_reference_type_mut(i);
+    }
+    visitor.visit_class_body_mut(&mut node.body);
+}
+
+/// 依次访问枚举声明的子节点。
+pub fn walk_enum_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut EnumDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    for i in &mut node.implements {
+        visitor.visit_reference_type_mut(i);
+    }
+    for i in &mut node.constants {
+        visitor.visit_enum_constant_declaration_mut(i);
+    }
+    visitor.visit_class_body_mut(&mut node.body);
+}
+
+/// 依次访问枚举常量声明的子节点。
+pub fn walk_enum_constant_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut EnumConstantDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    for i in node.arguments.iter_mut().flatten() {
+        visitor.visit_expression_mut(i);
+    }
+    if let Some(b) = &mut node.body {
+        visitor.visit_class_body_mut(b);
+    }
+}
+
+/// 依次访问记录声明的子节点。
+pub fn walk_record_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut RecordDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    for i in &mut node.type_parameters {
+        visitor.visit_type_parameter_mut(i);
+    }
+    for i in &mut node.components {
+        visitor.visit_record_component_mut(i);
+    }
+    for i in &mut node.implements {
+        visitor.visit_reference_type_mut(i);
+    }
+    visitor.visit_class_body_mut(&mut node.body);
+}
+
+/// 依次访问记录组件的子节点。
+pub fn walk_record_component_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut RecordComponent<'a>,
+) {
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    visitor.visit_type_mut(&mut node.r#type);
+}
+
+/// 依次访问注解接口声明的子节点。
+pub fn walk_annotation_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut AnnotationDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    visitor.visit_class_body_mut(&mut node.body);
+}
+
+/// 依次访问类型参数的子节点。
+pub fn walk_type_parameter_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut TypeParameter<'a>,
+) {
+    for i in &mut node.extends {
+        visitor.visit_reference_type_mut(i);
+    }
+}
+
+/// 依次访问类体的子节点。
+pub fn walk_class_body_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassBody<'a>,
+) {
+    for i in &mut node.declarations {
+        visitor.visit_class_body_declaration_mut(i);
+    }
+    for i in &mut node.dangling {
+        visitor.visit_comment_mut(i);
+    }
+}
+
+/// 依次访问类体中的声明的子节点。
+pub fn walk_class_body_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ClassBodyDeclaration<'a>,
+) {
+    match node {
+        ClassBodyDeclaration::Field(d) => visitor.visit_field_declaration_mut(d),
+        ClassBodyDeclaration::Method(d) => visitor.visit_method_declaration_mut(d),
+        ClassBodyDeclaration::Constructor(d) => visitor.visit_constructor_declaration_mut(d),
+        ClassBodyDeclaration::Initializer(d) => visitor.visit_initializer_mut(d),
+        ClassBodyDeclaration::AnnotationMethod(d) => visitor.visit_annotation_method_mut(d),
+        ClassBodyDeclaration::Class(d) => visitor.visit_class_declaration_mut(d),
+        ClassBodyDeclaration::Interface(d) => visitor.visit_interface_declaration_mut(d),
+        ClassBodyDeclaration::Enum(d) => visitor.visit_enum_declaration_mut(d),
+        ClassBodyDeclaration::Record(d) => visitor.visit_record_declaration_mut(d),
+        ClassBodyDeclaration::Annotation(d) => visitor.visit_annotation_declaration_mut(d),
+        ClassBodyDeclaration::Error(e) => visitor.visit_error_node_mut(e),
+    }
+}
+
+/// 依次访问字段声明的子节点。
+pub fn walk_field_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut FieldDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    visitor.visit_type_mut(&mut node.r#type);
+    for i in &mut node.declarators {
+        visitor.visit_variable_declarator_mut(i);
+    }
+}
+
+/// 依次访问方法声明的子节点。
+pub fn walk_method_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut MethodDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    for i in &mut node.type_parameters {
+        visitor.visit_type_parameter_mut(i);
+    }
+    if let Some(t) = &mut node.return_type {
+        visitor.visit_type_mut(t);
+    }
+    for i in &mut node.parameters {
+        visitor.visit_formal_parameter_mut(i);
+    }
+    for i in &mut node.throws {
+        visitor.visit_reference_type_mut(i);
+    }
+    if let Some(b) = &mut node.body {
+        visitor.visit_block_mut(b);
+    }
+}
+
+/// 依次访问构造函数声明的子节点。
+pub fn walk_constructor_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConstructorDeclaration<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    for i in &mut node.type_parameters {
+        visitor.visit_type_parameter_mut(i);
+    }
+    for i in node.parameters.iter_mut().flatten() {
+        visitor.visit_formal_parameter_mut(i);
+    }
+    for i in &mut node.throws {
+        visitor.visit_reference_type_mut(i);
+    }
+    visitor.visit_constructor_body_mut(&mut node.body);
+}
+
+/// 依次访问初始化块的子节点。
+pub fn walk_initializer_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut Initializer<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    visitor.visit_block_mut(&mut node.block);
+}
+
+/// 依次访问注解接口的元素声明的子节点。
+pub fn walk_annotation_method_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut AnnotationMethod<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    if let Some(d) = &mut node.documentation {
+        visitor.visit_documentation_comment_mut(d);
+    }
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    visitor.visit_type_mut(&mut node.return_type);
+    if let Some(v) = &mut node.default {
+        visitor.visit_element_value_mut(v);
+    }
+}
+
+/// 依次访问形式参数的子节点。
+pub fn walk_formal_parameter_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut FormalParameter<'a>,
+) {
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    visitor.visit_type_mut(&mut node.r#type);
+}
+
+/// 依次访问变量声明符的子节点。
+pub fn walk_variable_declarator_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut VariableDeclarator<'a>,
+) {
+    if let Some(i) = &mut node.initializer {
+        visitor.visit_variable_initializer_mut(i);
+    }
+}
+
+/// 依次访问变量初始化器的子节点。
+pub fn walk_variable_initializer_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut VariableInitializer<'a>,
+) {
+    match node {
+        VariableInitializer::Expression(e) => visitor.visit_expression_mut(e),
+        VariableInitializer::Array(a) => visitor.visit_array_initializer_mut(a),
+    }
+}
+
+/// 依次访问数组初始化器的子节点。
+pub fn walk_array_initializer_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ArrayInitializer<'a>,
+) {
+    for i in &mut node.initializers {
+        visitor.visit_variable_initializer_mut(i);
+    }
+}
+
+/// 依次访问代码块的子节点。
+pub fn walk_block_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, node: &mut Block<'a>) {
+    for (i, statement) in node.statements.iter_mut().enumerate() {
+        if let Some(comments) = node.comments.get_mut(i) {
+            visitor.visit_comments_mut(comments);
+        }
+        visitor.visit_statement_mut(statement);
+    }
+    for i in &mut node.dangling {
+        visitor.visit_comment_mut(i);
+    }
+}
+
+/// 依次访问构造函数体的子节点。
+pub fn walk_constructor_body_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ConstructorBody<'a>,
+) {
+    let mut comments = node.comments.iter_mut();
+    for i in &mut node.prologue {
+        if let Some(c) = comments.next() {
+            visitor.visit_comments_mut(c);
+        }
+        visitor.visit_statement_mut(i);
+    }
+    if let Some(i) = &mut node.invocation {
+        if let Some(c) = comments.next() {
+            visitor.visit_comments_mut(c);
+        }
+        visitor.visit_explicit_constructor_invocation_mut(i);
+    }
+    for i in &mut node.epilogue {
+        if let Some(c) = comments.next() {
+            visitor.visit_comments_mut(c);
+        }
+        visitor.visit_statement_mut(i);
+    }
+    for i in &mut node.dangling {
+        visitor.visit_comment_mut(i);
+    }
+}
+
+/// 依次访问语句的子节点。
+pub fn walk_statement_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut Statement<'a>,
+) {
+    match node {
+        Statement::Block(b) => visitor.visit_block_mut(b),
+        Statement::LocalVariable(d) => visitor.visit_local_variable_declaration_mut(d),
+        Statement::LocalClass(d) => visitor.visit_class_body_declaration_mut(d),
+        Statement::Empty | Statement::Break(_) | Statement::Continue(_) => {}
+        Statement::Labeled {
+            statement,
+            statement_comments,
+            ..
+        } => {
+            visitor.visit_comments_mut(statement_comments);
+            visitor.visit_statement_mut(statement);
+        }
+        Statement::Expression(e) | Statement::Throw(e) | Statement::Yield(e) => {
+            visitor.visit_expression_mut(e)
+        }
+        Statement::If {
+            condition,
+            then_statement,
+            else_statement,
+            then_comments,
+            else_comments,
+        } => {
+            visitor.visit_expression_mut(condition);
+            visitor.visit_comments_mut(then_comments);
+            visitor.visit_statement_mut(then_statement);
+            visitor.visit_comments_mut(else_comments);
+            if let Some(s) = else_statement {
+                visitor.visit_statement_mut(s);
+            }
+        }
+        Statement::While {
+            condition,
+            body,
+            body_comments,
+        } => {
+            visitor.visit_expression_mut(condition);
+            visitor.visit_comments_mut(body_comments);
+            visitor.visit_statement_mut(body);
+        }
+        Statement::Do {
+            body,
+            condition,
+            body_comments,
+        } => {
+            visitor.visit_comments_mut(body_comments);
+            visitor.visit_statement_mut(body);
+            visitor.visit_expression_mut(condition);
+        }
+        Statement::For {
+            control,
+            body,
+            body_comments,
+        } => {
+            visitor.visit_for_control_mut(control);
+            visitor.visit_comments_mut(body_comments);
+            visitor.visit_statement_mut(body);
+        }
+        Statement::Return(e) => {
+            if let Some(e) = e {
+                visitor.visit_expression_mut(e);
+            }
+        }
+        Statement::Assert { condition, message } => {
+            visitor.visit_expression_mut(condition);
+            if let Some(m) = message {
+                visitor.visit_expression_mut(m);
+            }
+        }
+        Statement::Synchronized { lock, block } => {
+            visitor.visit_expression_mut(lock);
+            visitor.visit_block_mut(block);
+        }
+        Statement::Switch(s) => visitor.visit_switch_mut(s),
+        Statement::Try(t) => visitor.visit_try_statement_mut(t),
+        Statement::Error(e) => visitor.visit_error_node_mut(e),
+    }
+}
+
+/// 依次访问局部变量声明的子节点。
+pub fn walk_local_variable_declaration_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut LocalVariableDeclaration<'a>,
+) {
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    visitor.visit_type_mut(&mut node.r#type);
+    for i in &mut node.declarators {
+        visitor.visit_variable_declarator_mut(i);
+    }
+}
+
+/// 依次访问`for`循环的控制部分的子节点。
+pub fn walk_for_control_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ForControl<'a>,
+) {
+    match node {
+        ForControl::Basic {
+            init,
+            condition,
+            update,
+        } => {
+            visitor.visit_for_init_mut(init);
+            if let Some(c) = condition {
+                visitor.visit_expression_mut(c);
+            }
+            for i in update {
+                visitor.visit_expression_mut(i);
+            }
+        }
+        ForControl::Enhanced { variable, iterable } => {
+            visitor.visit_local_variable_declaration_mut(variable);
+            visitor.visit_expression_mut(iterable);
+        }
+    }
+}
+
+/// 依次访问`for`循环的初始化部分的子节点。
+pub fn walk_for_init_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, node: &mut ForInit<'a>) {
+    match node {
+        ForInit::Declaration(d) => visitor.visit_local_variable_declaration_mut(d),
+        ForInit::Expressions(e) => {
+            for i in e {
+                visitor.visit_expression_mut(i);
+            }
+        }
+    }
+}
+
+/// 依次访问`try`语句的子节点。
+pub fn walk_try_statement_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut TryStatement<'a>,
+) {
+    for i in &mut node.resources {
+        visitor.visit_try_resource_mut(i);
+    }
+    visitor.visit_block_mut(&mut node.block);
+    for i in &mut node.catches {
+        visitor.visit_catch_clause_mut(i);
+    }
+    if let Some(b) = &mut node.finally {
+        visitor.visit_block_mut(b);
+    }
+}
+
+/// 依次访问`try`语句的资源的子节点。
+pub fn walk_try_resource_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut TryResource<'a>,
+) {
+    match node {
+        TryResource::Declaration {
+            annotations,
+            r#type,
+            value,
+            ..
+        } => {
+            for i in annotations {
+                visitor.visit_annotation_mut(i);
+            }
+            visitor.visit_type_mut(r#type);
+            visitor.visit_expression_mut(value);
+        }
+        TryResource::Variable(e) => visitor.visit_expression_mut(e),
+    }
+}
+
+/// 依次访问`catch`子句的子节点。
+pub fn walk_catch_clause_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut CatchClause<'a>,
+) {
+    visitor.visit_catch_clause_parameter_mut(&mut node.parameter);
+    visitor.visit_block_mut(&mut node.block);
+}
+
+/// 依次访问`catch`子句的参数的子节点。
+pub fn walk_catch_clause_parameter_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut CatchClauseParameter<'a>,
+) {
+    for i in &mut node.annotations {
+        visitor.visit_annotation_mut(i);
+    }
+    for i in &mut node.types {
+        visitor.visit_reference_type_mut(i);
+    }
+}
+
+/// 依次访问显式构造函数调用的子节点。
+pub fn walk_explicit_constructor_invocation_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ExplicitConstructorInvocation<'a>,
+) {
+    if let Some(q) = &mut node.qualifier {
+        visitor.visit_expression_mut(q);
+    }
+    for i in &mut node.type_arguments {
+        visitor.visit_type_argument_mut(i);
+    }
+    for i in &mut node.arguments {
+        visitor.visit_expression_mut(i);
+    }
+}
+
+/// 依次访问`switch`语句或表达式的子节点。
+pub fn walk_switch_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, node: &mut Switch<'a>) {
+    visitor.visit_expression_mut(&mut node.selector);
+    visitor.visit_switch_body_mut(&mut node.body);
+    for i in &mut node.dangling {
+        visitor.visit_comment_mut(i);
+    }
+}
+
+/// 依次访问`switch`块的子节点。
+pub fn walk_switch_body_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut SwitchBody<'a>,
+) {
+    match node {
+        SwitchBody::Groups(groups) => {
+            for i in groups {
+                visitor.visit_switch_group_mut(i);
+            }
+        }
+        SwitchBody::Rules(rules) => {
+            for i in rules {
+                visitor.visit_switch_rule_mut(i);
+            }
+        }
+    }
+}
+
+/// 依次访问`switch`块中的语句组的子节点。
+pub fn walk_switch_group_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut SwitchGroup<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    for i in &mut node.labels {
+        visitor.visit_switch_label_mut(i);
+    }
+    for (i, statement) in node.statements.iter_mut().enumerate() {
+        if let Some(comments) = node.statement_comments.get_mut(i) {
+            visitor.visit_comments_mut(comments);
+        }
+        visitor.visit_statement_mut(statement);
+    }
+}
+
+/// 依次访问`switch`规则的子节点。
+pub fn walk_switch_rule_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut SwitchRule<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+    visitor.visit_switch_label_mut(&mut node.label);
+    visitor.visit_switch_rule_body_mut(&mut node.body);
+}
+
+/// 依次访问`switch`规则的主体的子节点。
+pub fn walk_switch_rule_body_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut SwitchRuleBody<'a>,
+) {
+    match node {
+        SwitchRuleBody::Expression(e) | SwitchRuleBody::Throw(e) => visitor.visit_expression_mut(e),
+        SwitchRuleBody::Block(b) => visitor.visit_block_mut(b),
+    }
+}
+
+/// 依次访问`switch`标签的子节点。
+pub fn walk_switch_label_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut SwitchLabel<'a>,
+) {
+    match node {
+        SwitchLabel::Case(e) => {
+            for i in e {
+                visitor.visit_expression_mut(i);
+            }
+        }
+        SwitchLabel::Pattern { patterns, guard } => {
+            for i in patterns {
+                visitor.visit_pattern_mut(i);
+            }
+            if let Some(g) = guard {
+                visitor.visit_expression_mut(g);
+            }
+        }
+        SwitchLabel::NullDefault | SwitchLabel::Default => {}
+    }
+}
+
+/// 依次访问表达式的子节点。
+pub fn walk_expression_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut Expression<'a>,
+) {
+    match node {
+        Expression::Literal(l) => visitor.visit_literal_mut(l),
+        Expression::Name(_)
+        | Expression::This
+        | Expression::QualifiedThis(_)
+        | Expression::SuperFieldAccess { .. }
+        | Expression::VoidClassLiteral => {}
+        Expression::FieldAccess { target, .. } => visitor.visit_expression_mut(target),
+        Expression::MethodInvocation {
+            target,
+            type_arguments,
+            arguments,
+            ..
+        } => {
+            if let Some(t) = target {
+                visitor.visit_expression_mut(t);
+            }
+            for i in type_arguments {
+                visitor.visit_type_argument_mut(i);
+            }
+            for i in arguments {
+                visitor.visit_expression_mut(i);
+            }
+        }
+        Expression::SuperMethodInvocation {
+            type_arguments,
+            arguments,
+            ..
+        } => {
+            for i in type_arguments {
+                visitor.visit_type_argument_mut(i);
+            }
+            for i in arguments {
+                visitor.visit_expression_mut(i);
+            }
+        }
+        Expression::ArrayAccess { array, index } => {
+            visitor.visit_expression_mut(array);
+            visitor.visit_expression_mut(index);
+        }
+        Expression::ClassLiteral(t) => visitor.visit_type_mut(t),
+        Expression::ClassCreation {
+            outer,
+            type_arguments,
+            r#type,
+            arguments,
+            body,
+        } => {
+            if let Some(o) = outer {
+                visitor.visit_expression_mut(o);
+            }
+            for i in type_arguments {
+                visitor.visit_type_argument_mut(i);
+            }
+            visitor.visit_reference_type_mut(r#type);
+            for i in arguments {
+                visitor.visit_expression_mut(i);
+            }
+            if let Some(b) = body {
+                visitor.visit_class_body_mut(b);
+            }
+        }
+        Expression::ArrayCreation {
+            r#type,
+            dimensions,
+            initializer,
+        } => {
+            visitor.visit_type_mut(r#type);
+            for i in dimensions {
+                visitor.visit_expression_mut(i);
+            }
+            if let Some(i) = initializer {
+                visitor.visit_array_initializer_mut(i);
+            }
+        }
+        Expression::Parenthesized(e) => visitor.visit_expression_mut(e),
+        Expression::Assignment { target, value, .. } => {
+            visitor.visit_expression_mut(target);
+            visitor.visit_expression_mut(value);
+        }
+        Expression::Ternary {
+            condition,
+            if_true,
+            if_false,
+        } => {
+            visitor.visit_expression_mut(condition);
+            visitor.visit_expression_mut(if_true);
+            visitor.visit_expression_mut(if_false);
+        }
+        Expression::Binary { left, right, .. } => {
+            visitor.visit_expression_mut(left);
+            visitor.visit_expression_mut(right);
+        }
+        Expression::Lambda { parameters, body } => {
+            visitor.visit_lambda_parameters_mut(parameters);
+            visitor.visit_lambda_body_mut(body);
+        }
+        Expression::MethodReference {
+            target,
+            type_arguments,
+            ..
+        } => {
+            visitor.visit_method_reference_target_mut(target);
+            for i in type_arguments {
+                visitor.visit_type_argument_mut(i);
+            }
+        }
+        Expression::Switch(s) => visitor.visit_switch_mut(s),
+        Expression::InstanceOf { expression, target } => {
+            visitor.visit_expression_mut(expression);
+            visitor.visit_instance_of_target_mut(target);
+        }
+        Expression::Unary { operand, .. } | Expression::Postfix { operand, .. } => {
+            visitor.visit_expression_mut(operand)
+        }
+        Expression::Cast {
+            r#type,
+            bounds,
+            expression,
+        } => {
+            visitor.visit_type_mut(r#type);
+            for i in bounds {
+                visitor.visit_reference_type_mut(i);
+            }
+            visitor.visit_expression_mut(expression);
+        }
+    }
+}
+
+/// 依次访问Lambda表达式的参数列表的子节点。
+pub fn walk_lambda_parameters_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut LambdaParameters<'a>,
+) {
+    match node {
+        LambdaParameters::Inferred(_) => {}
+        LambdaParameters::Formal(parameters) => {
+            for i in parameters {
+                visitor.visit_formal_parameter_mut(i);
+            }
+        }
+    }
+}
+
+/// 依次访问Lambda体的子节点。
+pub fn walk_lambda_body_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut LambdaBody<'a>,
+) {
+    match node {
+        LambdaBody::Expression(e) => visitor.visit_expression_mut(e),
+        LambdaBody::Block(b) => visitor.visit_block_mut(b),
+    }
+}
+
+/// 依次访问方法引用中`::`前面的部分的子节点。
+pub fn walk_method_reference_target_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut MethodReferenceTarget<'a>,
+) {
+    match node {
+        MethodReferenceTarget::Expression(e) => visitor.visit_expression_mut(e),
+        MethodReferenceTarget::Type(t) => visitor.visit_type_mut(t),
+        MethodReferenceTarget::Super(_) => {}
+    }
+}
+
+/// 依次访问模式的子节点。
+pub fn walk_pattern_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, node: &mut Pattern<'a>) {
+    match node {
+        Pattern::Type {
+            annotations,
+            r#type,
+            ..
+        } => {
+            for i in annotations {
+                visitor.visit_annotation_mut(i);
+            }
+            visitor.visit_type_mut(r#type);
+        }
+        Pattern::Record { r#type, patterns } => {
+            visitor.visit_reference_type_mut(r#type);
+            for i in patterns {
+                visitor.visit_pattern_mut(i);
+            }
+        }
+        Pattern::Unnamed => {}
+    }
+}
+
+/// 依次访问`instanceof`右边的类型或模式的子节点。
+pub fn walk_instance_of_target_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut InstanceOfTarget<'a>,
+) {
+    match node {
+        InstanceOfTarget::Type(t) => visitor.visit_type_mut(t),
+        InstanceOfTarget::Pattern(p) => visitor.visit_pattern_mut(p),
+    }
+}
+
+/// 依次访问类型的子节点。
+pub fn walk_type_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, node: &mut Type<'a>) {
+    match node {
+        Type::Basic(_) => {}
+        Type::Reference(r) => visitor.visit_reference_type_mut(r),
+        Type::Array(t) => visitor.visit_type_mut(t),
+    }
+}
+
+/// 依次访问引用类型的子节点。
+pub fn walk_reference_type_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ReferenceType<'a>,
+) {
+    for i in node.arguments.iter_mut().flatten() {
+        visitor.visit_type_argument_mut(i);
+    }
+    if let Some(t) = &mut node.sub_type {
+        visitor.visit_reference_type_mut(t);
+    }
+}
+
+/// 依次访问类型实参的子节点。
+pub fn walk_type_argument_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut TypeArgument<'a>,
+) {
+    match node {
+        TypeArgument::Type(t) | TypeArgument::Extends(t) | TypeArgument::Super(t) => {
+            visitor.visit_type_mut(t)
+        }
+        TypeArgument::Wildcard => {}
+    }
+}
+
+/// 依次访问注解的子节点。
+pub fn walk_annotation_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut Annotation<'a>,
+) {
+    if let Some(e) = &mut node.element {
+        visitor.visit_annotation_element_mut(e);
+    }
+}
+
+/// 依次访问注解括号中的内容的子节点。
+pub fn walk_annotation_element_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut AnnotationElement<'a>,
+) {
+    match node {
+        AnnotationElement::Value(v) => visitor.visit_element_value_mut(v),
+        AnnotationElement::Pairs(pairs) => {
+            for i in pairs {
+                visitor.visit_element_value_pair_mut(i);
+            }
+        }
+    }
+}
+
+/// 依次访问注解的元素值对的子节点。
+pub fn walk_element_value_pair_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ElementValuePair<'a>,
+) {
+    visitor.visit_element_value_mut(&mut node.value);
+}
+
+/// 依次访问注解的元素值的子节点。
+pub fn walk_element_value_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ElementValue<'a>,
+) {
+    match node {
+        ElementValue::Expression(e) => visitor.visit_expression_mut(e),
+        ElementValue::Annotation(a) => visitor.visit_annotation_mut(a),
+        ElementValue::Array(values) => {
+            for i in values {
+                visitor.visit_element_value_mut(i);
+            }
+        }
+    }
+}
+
+/// 依次访问关联到节点的普通注释的子节点。
+pub fn walk_comments_mut<'a, V: VisitorMut<'a> + ?Sized>(visitor: &mut V, node: &mut Comments<'a>) {
+    for i in &mut node.leading {
+        visitor.visit_comment_mut(i);
+    }
+    for i in &mut node.trailing {
+        visitor.visit_comment_mut(i);
+    }
+}
+
+/// 依次访问恢复解析时跳过的错误节点的子节点。
+pub fn walk_error_node_mut<'a, V: VisitorMut<'a> + ?Sized>(
+    visitor: &mut V,
+    node: &mut ErrorNode<'a>,
+) {
+    visitor.visit_comments_mut(&mut node.comments);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_str, Expression};
+
+    /// 把名为`from`的变量改名为`to`，并在每个方法前添加注释。
+    struct Rename(&'static str, &'static str);
+
+    impl<'a> VisitorMut<'a> for Rename {
+        fn visit_method_declaration_mut(&mut self, node: &mut MethodDeclaration<'a>) {
+            node.comments.leading.push(Comment {
+                text: " 已改名".into(),
+                single_line: true,
+            });
+            walk_method_declaration_mut(self, node)
+        }
+
+        fn visit_expression_mut(&mut self, node: &mut Expression<'a>) {
+            if let Expression::Name(name) = node {
+                if name == self.0 {
+                    *name = self.1.into();
+                }
+            }
+            walk_expression_mut(self, node)
+        }
+
+        fn visit_variable_declarator_mut(&mut self, node: &mut VariableDeclarator<'a>) {
+            if node.name == self.0 {
+                node.name = self.1.into();
+            }
+            walk_variable_declarator_mut(self, node)
+        }
+    }
+
+    #[test]
+    fn test_visitor_mut() {
+        let mut unit = parse_str(
+            "class A { int a = 1; int f() { int b = a + 1; return switch (b) { case 1 -> a; default -> b * a; }; } }",
+        )
+        .unwrap();
+        Rename("a", "c").visit_compilation_unit_declaration_mut(&mut unit);
+        assert_eq!(
+            unit,
+            parse_str(
+                "class A { int c = 1;\n// 已改名\nint f() { int b = c + 1; return switch (b) { case 1 -> c; default -> b * c; }; } }",
+            )
+            .unwrap()
+        );
+    }
+}